@@ -1,7 +1,4 @@
-#[global_allocator]
-static GLOBAL: snmalloc_rs::SnMalloc = snmalloc_rs::SnMalloc;
-
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, Utc};
 use csv::Writer;
 use env_logger::Builder;
 use log::{info, warn};
@@ -90,7 +87,7 @@ impl Orderbook {
         }
     }
 
-    pub fn get_depth(&mut self, depth: usize) -> Option<Record> {
+    pub fn get_depth(&mut self, depth: usize) -> Option<Record<'_>> {
         // let asks: Vec<(Decimal, Decimal)> = self.asks.iter().take(depth).rev().collect();
         // let bids: Vec<(Decimal, Decimal)> = self.bids.iter().rev().take(depth).collect();
         let asks_price = self.asks.keys().cloned().take(depth).collect();
@@ -146,17 +143,17 @@ impl Orderbook {
     }
 
     pub fn verify(&mut self, pu_id: u64, check_bid_ask_overlapping: bool) -> bool {
-        if check_bid_ask_overlapping {
-            if self.bids.len() > 0 && self.asks.len() > 0 {
-                if self.best_bid().unwrap().0 >= self.best_ask().unwrap().0 {
-                    warn!(
-                        "best bid {} >= best ask {}",
-                        self.best_bid().unwrap().0,
-                        self.best_ask().unwrap().0
-                    );
-                    return false;
-                }
-            }
+        if check_bid_ask_overlapping
+            && !self.bids.is_empty()
+            && !self.asks.is_empty()
+            && self.best_bid().unwrap().0 >= self.best_ask().unwrap().0
+        {
+            warn!(
+                "best bid {} >= best ask {}",
+                self.best_bid().unwrap().0,
+                self.best_ask().unwrap().0
+            );
+            return false;
         }
 
         self.final_update_id == pu_id
@@ -164,7 +161,7 @@ impl Orderbook {
 
     /// Returns the price of the best bid
     pub fn bid_price(&self) -> Option<Decimal> {
-        self.bids.keys().rev().next().cloned()
+        self.bids.keys().next_back().cloned()
     }
 
     /// Returns the price of the best ask
@@ -181,7 +178,7 @@ impl Orderbook {
     /// Returns the price and quantity of the best bid
     /// (bid_price, bid_quantity)
     pub fn best_bid(&mut self) -> Option<(Decimal, Decimal)> {
-        let (price, qty) = self.bids.iter().rev().next()?;
+        let (price, qty) = self.bids.iter().next_back()?;
 
         Some((*price, *qty))
     }
@@ -215,7 +212,7 @@ impl WebSocketHandler {
     /// "az6","az7","az8","az9","az10","az11","az12","az13","az14","az15","az16","az17","az18","az19","az20",
     /// "bz6","bz7","bz8","bz9","bz10","bz11","bz12","bz13","bz14","bz15","bz16","bz17","bz18","bz19","bz20",
     pub fn write_depth_header(&mut self) -> Result<(), Box<dyn Error>> {
-        self.wrt.write_record(&[
+        self.wrt.write_record([
             "symbol",
             "timestamp",
             "ap1",
@@ -288,9 +285,13 @@ impl WebSocketHandler {
 }
 
 async fn run_partial_depth(file_url: String, symbol: String) {
-    let mut tmr_dt = Utc::today().and_hms(23, 59, 59);
+    let mut tmr_dt = Utc::now()
+        .date_naive()
+        .and_hms_opt(23, 59, 59)
+        .unwrap()
+        .and_utc();
 
-    let file_name = format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::today());
+    let file_name = format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::now().date_naive());
     let file_path = std::path::Path::new(&file_url).join(file_name);
     let local_wrt = csv::Writer::from_path(file_path).unwrap();
     let mut web_socket_handler = WebSocketHandler::new(local_wrt);
@@ -307,13 +308,15 @@ async fn run_partial_depth(file_url: String, symbol: String) {
         loop {
             let msg = rx.recv().await.unwrap();
 
-            if DateTime::<Utc>::from_utc(
-                NaiveDateTime::from_timestamp((msg.event_time / 1000) as i64, 0),
-                Utc,
-            ) > tmr_dt
+            if DateTime::<Utc>::from_timestamp((msg.event_time / 1000) as i64, 0).unwrap() > tmr_dt
             {
-                tmr_dt = Utc::today().and_hms(23, 59, 59);
-                let file_name = format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::today());
+                tmr_dt = Utc::now()
+                    .date_naive()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap()
+                    .and_utc();
+                let file_name =
+                    format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::now().date_naive());
                 let file_path = std::path::Path::new(&file_url).join(file_name);
                 let local_wrt = csv::Writer::from_path(file_path).unwrap();
                 web_socket_handler = WebSocketHandler::new(local_wrt);
@@ -342,9 +345,13 @@ async fn run_partial_depth(file_url: String, symbol: String) {
 }
 
 async fn run_depth(file_url: String, symbol: String) {
-    let mut tmr_dt = Utc::today().and_hms(23, 59, 59);
+    let mut tmr_dt = Utc::now()
+        .date_naive()
+        .and_hms_opt(23, 59, 59)
+        .unwrap()
+        .and_utc();
 
-    let file_name = format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::today());
+    let file_name = format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::now().date_naive());
     let file_path = std::path::Path::new(&file_url).join(file_name);
     let local_wrt = csv::Writer::from_path(file_path).unwrap();
     let mut web_socket_handler = WebSocketHandler::new(local_wrt);
@@ -387,13 +394,15 @@ async fn run_depth(file_url: String, symbol: String) {
 
             let event = orderbook.get_depth(5).unwrap();
 
-            if DateTime::<Utc>::from_utc(
-                NaiveDateTime::from_timestamp((msg.event_time / 1000) as i64, 0),
-                Utc,
-            ) > tmr_dt
+            if DateTime::<Utc>::from_timestamp((msg.event_time / 1000) as i64, 0).unwrap() > tmr_dt
             {
-                tmr_dt = Utc::today().and_hms(23, 59, 59);
-                let file_name = format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::today());
+                tmr_dt = Utc::now()
+                    .date_naive()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap()
+                    .and_utc();
+                let file_name =
+                    format!("{}-{}-{:?}.csv", symbol, "depth5", Utc::now().date_naive());
                 let file_path = std::path::Path::new(&file_url).join(file_name);
                 let local_wrt = csv::Writer::from_path(file_path).unwrap();
                 web_socket_handler = WebSocketHandler::new(local_wrt);
@@ -419,9 +428,13 @@ async fn run_depth(file_url: String, symbol: String) {
 }
 
 async fn run_trades(file_url: String, symbol: String) {
-    let mut tmr_dt = Utc::today().and_hms(23, 59, 59);
+    let mut tmr_dt = Utc::now()
+        .date_naive()
+        .and_hms_opt(23, 59, 59)
+        .unwrap()
+        .and_utc();
 
-    let file_name = format!("{}-{}-{:?}.csv", symbol, "trades", Utc::today());
+    let file_name = format!("{}-{}-{:?}.csv", symbol, "trades", Utc::now().date_naive());
     let file_path = std::path::Path::new(&file_url).join(file_name);
     let local_wrt = csv::Writer::from_path(file_path).unwrap();
     let mut web_socket_handler = WebSocketHandler::new(local_wrt);
@@ -437,13 +450,16 @@ async fn run_trades(file_url: String, symbol: String) {
         loop {
             let event = rx.recv().await.unwrap();
 
-            if DateTime::<Utc>::from_utc(
-                NaiveDateTime::from_timestamp((event.event_time / 1000) as i64, 0),
-                Utc,
-            ) > tmr_dt
+            if DateTime::<Utc>::from_timestamp((event.event_time / 1000) as i64, 0).unwrap()
+                > tmr_dt
             {
-                tmr_dt = Utc::today().and_hms(23, 59, 59);
-                let file_name = format!("{}-{}-{:?}.csv", symbol, "trades", Utc::today());
+                tmr_dt = Utc::now()
+                    .date_naive()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap()
+                    .and_utc();
+                let file_name =
+                    format!("{}-{}-{:?}.csv", symbol, "trades", Utc::now().date_naive());
                 let file_path = std::path::Path::new(&file_url).join(file_name);
                 let local_wrt = csv::Writer::from_path(file_path).unwrap();
                 web_socket_handler = WebSocketHandler::new(local_wrt);
@@ -1,9 +1,13 @@
+use super::client::handle_content_error;
 use super::client::*;
 use super::errors::*;
+use super::filters::{ReferencePrice, SymbolFilters};
 use super::rest_model::*;
 use super::util::*;
+use rust_decimal::Decimal;
 use serde_json::from_str;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
 static API_V3_ACCOUNT: &str = "/api/v3/account";
 static API_V3_OPEN_ORDERS: &str = "/api/v3/openOrders";
@@ -13,11 +17,23 @@ static API_V3_ORDER: &str = "/api/v3/order";
 /// Endpoint for test orders.
 /// Orders issued to this endpoint are validated, but not sent into the matching engine.
 static API_V3_ORDER_TEST: &str = "/api/v3/order/test";
+static API_V3_CANCEL_REPLACE: &str = "/api/v3/order/cancelReplace";
+static API_V3_AMEND_KEEP_PRIORITY: &str = "/api/v3/order/amend/keepPriority";
+static API_V3_ORDER_LIST_OCO: &str = "/api/v3/orderList/oco";
+static API_V3_ORDER_LIST_OTO: &str = "/api/v3/orderList/oto";
+static API_V3_ORDER_LIST_OTOCO: &str = "/api/v3/orderList/otoco";
+static API_V3_ORDER_LIST: &str = "/api/v3/orderList";
+static API_V3_ALL_ORDER_LIST: &str = "/api/v3/allOrderList";
+static API_V3_OPEN_ORDER_LIST: &str = "/api/v3/openOrderList";
 
 #[derive(Clone)]
 pub struct Account {
     pub client: Client,
     pub recv_window: u64,
+    /// Filters checked by `place_order` when set, see `set_filters`
+    pub filters: Option<Arc<HashMap<String, SymbolFilters>>>,
+    /// Reference prices of the filter checks, see `set_reference_price`
+    pub reference_price: Option<ReferencePrice>,
 }
 
 /// Order Request
@@ -30,15 +46,15 @@ pub struct OrderRequest {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
     /// A unique id for the order, automatically generated if not sent.
     pub new_client_order_id: Option<String>,
     /// Used with stop loss, stop loss limit, take profit and take profit limit order types.
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     /// Used with limit, stop loss limit and take profit limit to create an iceberg order.
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Decimal>,
     /// Set the response json, market and limit default to full others to ack.
     pub new_order_resp_type: Option<OrderResponse>,
     /// Cannot be greater than 60000
@@ -56,6 +72,341 @@ impl OrderRequest {
     }
 }
 
+/// Cancel an order and place a new one in a single request,
+/// either cancel_order_id or cancel_orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub cancel_new_client_order_id: Option<String>,
+    pub cancel_orig_client_order_id: Option<String>,
+    pub cancel_order_id: Option<u64>,
+    pub new_client_order_id: Option<String>,
+    pub stop_price: Option<Decimal>,
+    pub iceberg_qty: Option<Decimal>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    pub cancel_restrictions: Option<CancelRestrictions>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl CancelReplaceRequest {
+    fn valid(&self) -> Result<()> {
+        if self.cancel_order_id.is_none() && self.cancel_orig_client_order_id.is_none() {
+            return Err(Error::InvalidOrderError {
+                msg: "Either cancel_order_id or cancel_orig_client_order_id has to be set"
+                    .to_string(),
+            });
+        }
+        if self.iceberg_qty.is_some() && self.time_in_force != Some(TimeInForce::GTC) {
+            return Err(Error::InvalidOrderError {
+                msg: "Time in force has to be GTC for iceberg orders".to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Outcomes of both halves of a cancel-replace
+#[derive(Debug)]
+pub struct CancelReplace {
+    pub cancel_result: CancelReplaceResult,
+    pub new_order_result: CancelReplaceResult,
+    pub cancel_response: Result<OrderCanceled>,
+    /// None when the new order was not attempted
+    pub new_order_response: Option<Result<Transaction>>,
+}
+
+/// Each half of a cancel-replace response is either the order or the error that rejected it
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Outcome<T> {
+    Err(BinanceContentError),
+    Ok(T),
+}
+
+impl<T> From<Outcome<T>> for Result<T> {
+    fn from(o: Outcome<T>) -> Self {
+        match o {
+            Outcome::Err(e) => Err(handle_content_error(e)),
+            Outcome::Ok(t) => Ok(t),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelReplaceResponse {
+    cancel_result: CancelReplaceResult,
+    new_order_result: CancelReplaceResult,
+    cancel_response: Outcome<OrderCanceled>,
+    new_order_response: Option<Outcome<Transaction>>,
+}
+
+impl From<CancelReplaceResponse> for CancelReplace {
+    fn from(r: CancelReplaceResponse) -> Self {
+        CancelReplace {
+            cancel_result: r.cancel_result,
+            new_order_result: r.new_order_result,
+            cancel_response: r.cancel_response.into(),
+            new_order_response: r.new_order_response.map(Into::into),
+        }
+    }
+}
+
+/// Failed and partially failed cancel-replace, the outcomes are in the `data` of the error
+const CANCEL_REPLACE_PARTIALLY_FAILED: i16 = -2021;
+const CANCEL_REPLACE_FAILED: i16 = -2022;
+
+/// Decrease the quantity of an order without losing its place in the queue,
+/// either order_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    /// Client order id after the amendment, kept by default
+    pub new_client_order_id: Option<String>,
+    /// Has to be lower than the current quantity
+    pub new_qty: Decimal,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// One-Cancels-the-Other order list, the above leg is priced above the last price
+/// and the below leg below it
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OCOOrderList {
+    pub symbol: String,
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: Decimal,
+    /// STOP_LOSS, STOP_LOSS_LIMIT, TAKE_PROFIT, TAKE_PROFIT_LIMIT or LIMIT_MAKER
+    pub above_type: OrderType,
+    pub above_client_order_id: Option<String>,
+    pub above_iceberg_qty: Option<Decimal>,
+    pub above_price: Option<Decimal>,
+    pub above_stop_price: Option<Decimal>,
+    pub above_trailing_delta: Option<u32>,
+    pub above_time_in_force: Option<TimeInForce>,
+    /// STOP_LOSS, STOP_LOSS_LIMIT, TAKE_PROFIT, TAKE_PROFIT_LIMIT or LIMIT_MAKER
+    pub below_type: OrderType,
+    pub below_client_order_id: Option<String>,
+    pub below_iceberg_qty: Option<Decimal>,
+    pub below_price: Option<Decimal>,
+    pub below_stop_price: Option<Decimal>,
+    pub below_trailing_delta: Option<u32>,
+    pub below_time_in_force: Option<TimeInForce>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl OCOOrderList {
+    fn valid(&self) -> Result<()> {
+        valid_leg(
+            "above",
+            &self.above_type,
+            self.above_price,
+            self.above_stop_price,
+            self.above_trailing_delta,
+        )?;
+        valid_leg(
+            "below",
+            &self.below_type,
+            self.below_price,
+            self.below_stop_price,
+            self.below_trailing_delta,
+        )
+    }
+}
+
+/// One-Triggers-the-Other order list, the pending order is placed once the working order fills
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OTOOrderList {
+    pub symbol: String,
+    pub list_client_order_id: Option<String>,
+    /// LIMIT or LIMIT_MAKER
+    pub working_type: OrderType,
+    pub working_side: OrderSide,
+    pub working_client_order_id: Option<String>,
+    pub working_price: Decimal,
+    pub working_quantity: Decimal,
+    pub working_iceberg_qty: Option<Decimal>,
+    pub working_time_in_force: Option<TimeInForce>,
+    pub pending_type: OrderType,
+    pub pending_side: OrderSide,
+    pub pending_client_order_id: Option<String>,
+    pub pending_price: Option<Decimal>,
+    pub pending_stop_price: Option<Decimal>,
+    pub pending_trailing_delta: Option<u32>,
+    pub pending_quantity: Decimal,
+    pub pending_iceberg_qty: Option<Decimal>,
+    pub pending_time_in_force: Option<TimeInForce>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl OTOOrderList {
+    fn valid(&self) -> Result<()> {
+        valid_working_leg(&self.working_type)?;
+        valid_leg(
+            "pending",
+            &self.pending_type,
+            self.pending_price,
+            self.pending_stop_price,
+            self.pending_trailing_delta,
+        )
+    }
+}
+
+/// One-Triggers-a-One-Cancels-the-Other order list, an OCO pair is placed once the working order fills
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OTOCOOrderList {
+    pub symbol: String,
+    pub list_client_order_id: Option<String>,
+    /// LIMIT or LIMIT_MAKER
+    pub working_type: OrderType,
+    pub working_side: OrderSide,
+    pub working_client_order_id: Option<String>,
+    pub working_price: Decimal,
+    pub working_quantity: Decimal,
+    pub working_iceberg_qty: Option<Decimal>,
+    pub working_time_in_force: Option<TimeInForce>,
+    pub pending_side: OrderSide,
+    pub pending_quantity: Decimal,
+    pub pending_above_type: OrderType,
+    pub pending_above_client_order_id: Option<String>,
+    pub pending_above_price: Option<Decimal>,
+    pub pending_above_stop_price: Option<Decimal>,
+    pub pending_above_trailing_delta: Option<u32>,
+    pub pending_above_iceberg_qty: Option<Decimal>,
+    pub pending_above_time_in_force: Option<TimeInForce>,
+    pub pending_below_type: Option<OrderType>,
+    pub pending_below_client_order_id: Option<String>,
+    pub pending_below_price: Option<Decimal>,
+    pub pending_below_stop_price: Option<Decimal>,
+    pub pending_below_trailing_delta: Option<u32>,
+    pub pending_below_iceberg_qty: Option<Decimal>,
+    pub pending_below_time_in_force: Option<TimeInForce>,
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl OTOCOOrderList {
+    fn valid(&self) -> Result<()> {
+        valid_working_leg(&self.working_type)?;
+        valid_leg(
+            "pending above",
+            &self.pending_above_type,
+            self.pending_above_price,
+            self.pending_above_stop_price,
+            self.pending_above_trailing_delta,
+        )?;
+        match &self.pending_below_type {
+            Some(below_type) => valid_leg(
+                "pending below",
+                below_type,
+                self.pending_below_price,
+                self.pending_below_stop_price,
+                self.pending_below_trailing_delta,
+            ),
+            None => Ok(()),
+        }
+    }
+}
+
+fn valid_working_leg(order_type: &OrderType) -> Result<()> {
+    match order_type {
+        OrderType::Limit | OrderType::LimitMaker => Ok(()),
+        t => Err(Error::InvalidOrderError {
+            msg: format!(
+                "working order type has to be LIMIT or LIMIT_MAKER, not {:?}",
+                t
+            ),
+        }),
+    }
+}
+
+/// Limit legs need a price, stop legs a stop price or a trailing delta
+fn valid_leg(
+    leg: &str,
+    order_type: &OrderType,
+    price: Option<Decimal>,
+    stop_price: Option<Decimal>,
+    trailing_delta: Option<u32>,
+) -> Result<()> {
+    let (limit, stop) = match order_type {
+        OrderType::Limit | OrderType::LimitMaker => (true, false),
+        OrderType::StopLoss | OrderType::TakeProfit => (false, true),
+        OrderType::StopLossLimit | OrderType::TakeProfitLimit => (true, true),
+        t => {
+            return Err(Error::InvalidOrderError {
+                msg: format!("{} order type {:?} is not allowed in an order list", leg, t),
+            })
+        }
+    };
+    if limit && price.is_none() {
+        return Err(Error::InvalidOrderError {
+            msg: format!("{} order of type {:?} needs a price", leg, order_type),
+        });
+    }
+    if stop && stop_price.is_none() && trailing_delta.is_none() {
+        return Err(Error::InvalidOrderError {
+            msg: format!(
+                "{} order of type {:?} needs a stop price or a trailing delta",
+                leg, order_type
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Order list query, either order_list_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListQuery {
+    pub order_list_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+}
+
+/// Order lists query, `from_id` cannot be combined with `start_time` or `end_time`
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListsQuery {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500, max 1000
+    pub limit: Option<u16>,
+}
+
+/// Order list cancellation, either order_list_id or list_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListCancellation {
+    pub symbol: String,
+    pub order_list_id: Option<u64>,
+    pub list_client_order_id: Option<String>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub new_client_order_id: Option<String>,
+}
+
 /// Order Cancellation Request
 /// perform an order cancellation for the account
 /// only works if the parameters match an active order
@@ -99,16 +450,60 @@ pub struct OrdersQuery {
     pub recv_window: Option<u64>,
 }
 
-/// todo: BatchOrder
+// todo: BatchOrder
 // struct BatchOrdersRequest {
 //     pub batch_orders: Vec<OrderRequest>,
 // }
 
 impl Account {
+    /// Check orders against the filters of `symbols` before sending them,
+    /// orders of other symbols are rejected with `Error::UnknownSymbol`
+    pub fn set_filters(mut self, symbols: &[Symbol]) -> Result<Self> {
+        let filters = symbols
+            .iter()
+            .map(|symbol| Ok((symbol.symbol.clone(), SymbolFilters::try_from(symbol)?)))
+            .collect::<Result<_>>()?;
+        self.filters = Some(Arc::new(filters));
+        Ok(self)
+    }
+
+    /// Check market order notionals and percent price bands against the price returned
+    /// for the symbol, such as the last or the mark price, they are skipped on None
+    pub fn set_reference_price(
+        mut self,
+        reference_price: impl Fn(&str) -> Option<Decimal> + Send + Sync + 'static,
+    ) -> Self {
+        self.reference_price = Some(Arc::new(reference_price));
+        self
+    }
+
+    fn check_filters(&self, order: &OrderRequest) -> Result<()> {
+        let (Some(filters), Some(qty)) = (&self.filters, order.quantity) else {
+            return Ok(());
+        };
+        let filters = filters
+            .get(&order.symbol)
+            .ok_or_else(|| Error::UnknownSymbol(order.symbol.clone()))?;
+        let buy = matches!(order.side, OrderSide::Buy);
+        let price = match order.order_type {
+            OrderType::Market | OrderType::StopLoss | OrderType::TakeProfit => None,
+            _ => order.price,
+        };
+        let reference_price = self
+            .reference_price
+            .as_ref()
+            .and_then(|reference_price| reference_price(&order.symbol));
+        filters.check(buy, price, qty, reference_price)?;
+        if let Some(stop_price) = order.stop_price {
+            filters.check_price(stop_price)?;
+        }
+        Ok(())
+    }
+
     /// General account information
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let account = tokio_test::block_on(account.get_account());
     /// assert!(account.is_ok(), "{:?}", account);
@@ -126,7 +521,7 @@ impl Account {
     /// Account balance for a single asset
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let balance = tokio_test::block_on(account.get_balance("BTC"));
     /// assert!(balance.is_ok(), "{:?}", balance);
@@ -152,7 +547,7 @@ impl Account {
     /// All currently open orders for a single symbol
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let orders = tokio_test::block_on(account.get_open_orders("BTCUSDT"));
     /// assert!(orders.is_ok(), "{:?}", orders);
@@ -174,7 +569,7 @@ impl Account {
     /// All orders for the account
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let query = OrdersQuery {
     ///     symbol: "BTCUSDT".to_string(),
@@ -199,7 +594,7 @@ impl Account {
     /// All currently open orders for the account
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let orders = tokio_test::block_on(account.get_all_open_orders());
     /// assert!(orders.is_ok(), "{:?}", orders);
@@ -215,9 +610,9 @@ impl Account {
     /// Cancels all currently open orders of specified symbol for the account
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
-    /// let canceled_orders = tokio_test::block_on(account.cancel_all_open_orders());
+    /// let canceled_orders = tokio_test::block_on(account.cancel_all_open_orders("BTCUSDT"));
     /// assert!(canceled_orders.is_ok(), "{:?}", canceled_orders);
     /// ```
     pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<Vec<OrderCanceled>>
@@ -243,7 +638,7 @@ impl Account {
     /// Check an order's status
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let query = OrderStatusRequest {
     ///     symbol: "BTCUSDT".to_string(),
@@ -268,7 +663,7 @@ impl Account {
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let query = OrderStatusRequest {
     ///     symbol: "BTCUSDT".to_string(),
@@ -293,12 +688,13 @@ impl Account {
     /// This methods validates the order request before sending, making sure it complies with Binance rules
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, account::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let limit_buy = OrderRequest {
     ///         symbol: "BTCUSDT".to_string(),
-    ///         quantity: Some(10.0),
-    ///         price: Some(0.014000),
+    ///         quantity: Some(dec!(10)),
+    ///         price: Some(dec!(0.014)),
     ///         order_type: OrderType::Limit,
     ///         side: OrderSide::Buy,
     ///         time_in_force: Some(TimeInForce::FOK),
@@ -308,7 +704,8 @@ impl Account {
     /// assert!(transaction.is_ok(), "{:?}", transaction);
     /// ```
     pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
-        let _ = order.valid()?;
+        order.valid()?;
+        self.check_filters(&order)?;
         let recv_window = order.recv_window.unwrap_or(self.recv_window);
         let request = build_signed_request_p(order, recv_window)?;
         let data = self.client.post_signed(API_V3_ORDER, &request).await?;
@@ -321,8 +718,8 @@ impl Account {
         &self,
         new_client_order_id: impl Into<String>,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: impl Into<f64>,
+        qty: impl Into<Decimal>,
+        price: impl Into<Decimal>,
         time_in_force: Option<TimeInForce>,
         order_type: OrderType,
     ) -> Result<Transaction> {
@@ -330,10 +727,10 @@ impl Account {
             new_client_order_id: Some(new_client_order_id.into()),
             symbol: symbol.into(),
             side: OrderSide::Buy,
-            order_type: order_type,
+            order_type,
             quantity: Some(qty.into()),
             price: Some(price.into()),
-            time_in_force: time_in_force,
+            time_in_force,
             ..OrderRequest::default()
         };
         self.place_order(order).await
@@ -343,8 +740,8 @@ impl Account {
         &self,
         new_client_order_id: impl Into<String>,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: impl Into<f64>,
+        qty: impl Into<Decimal>,
+        price: impl Into<Decimal>,
         time_in_force: Option<TimeInForce>,
         order_type: OrderType,
     ) -> Result<Transaction> {
@@ -352,10 +749,10 @@ impl Account {
             new_client_order_id: Some(new_client_order_id.into()),
             symbol: symbol.into(),
             side: OrderSide::Sell,
-            order_type: order_type,
+            order_type,
             quantity: Some(qty.into()),
             price: Some(price.into()),
-            time_in_force: time_in_force,
+            time_in_force,
             ..OrderRequest::default()
         };
         self.place_order(order).await
@@ -364,7 +761,7 @@ impl Account {
     pub async fn market_buy(
         &self,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
+        qty: impl Into<Decimal>,
     ) -> Result<Transaction> {
         let order = OrderRequest {
             symbol: symbol.into(),
@@ -379,7 +776,7 @@ impl Account {
     pub async fn market_sell(
         &self,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
+        qty: impl Into<Decimal>,
     ) -> Result<Transaction> {
         let order = OrderRequest {
             symbol: symbol.into(),
@@ -397,12 +794,13 @@ impl Account {
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, account::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let limit_buy = OrderRequest {
     ///         symbol: "BTCUSDT".to_string(),
-    ///         quantity: Some(10.0),
-    ///         price: Some(0.014000),
+    ///         quantity: Some(dec!(10)),
+    ///         price: Some(dec!(0.014)),
     ///         order_type: OrderType::Limit,
     ///         side: OrderSide::Buy,
     ///         time_in_force: Some(TimeInForce::FOK),
@@ -412,7 +810,8 @@ impl Account {
     /// assert!(resp.is_ok(), "{:?}", resp);
     /// ```
     pub async fn place_test_order(&self, order: OrderRequest) -> Result<TestResponse> {
-        let _ = order.valid()?;
+        order.valid()?;
+        self.check_filters(&order)?;
         let recv_window = order.recv_window.unwrap_or(self.recv_window);
         let request = build_signed_request_p(order, recv_window)?;
         let data = self.client.post_signed(API_V3_ORDER_TEST, &request).await?;
@@ -423,7 +822,7 @@ impl Account {
     /// Place a cancellation order
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let query = OrderCancellation {
     ///     symbol: "BTCUSDT".to_string(),
@@ -449,7 +848,7 @@ impl Account {
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let query = OrderCancellation {
     ///     symbol: "BTCUSDT".to_string(),
@@ -473,10 +872,184 @@ impl Account {
         Ok(tr)
     }
 
+    /// Cancel an order and place a new one in one request.
+    /// Failures of either half are returned in the `CancelReplace`, not as an error
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, account::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let replace = CancelReplaceRequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Buy,
+    ///     order_type: OrderType::Limit,
+    ///     cancel_replace_mode: CancelReplaceMode::StopOnFailure,
+    ///     cancel_restrictions: Some(CancelRestrictions::OnlyNew),
+    ///     cancel_order_id: Some(1),
+    ///     quantity: Some(dec!(0.01)),
+    ///     price: Some(dec!(60000)),
+    ///     time_in_force: Some(TimeInForce::GTC),
+    ///     ..CancelReplaceRequest::default()
+    /// };
+    /// let result = tokio_test::block_on(account.cancel_replace(replace));
+    /// assert!(result.is_ok(), "{:?}", result);
+    /// ```
+    pub async fn cancel_replace(&self, order: CancelReplaceRequest) -> Result<CancelReplace> {
+        order.valid()?;
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        let response: CancelReplaceResponse = match self
+            .client
+            .post_signed(API_V3_CANCEL_REPLACE, &request)
+            .await
+        {
+            Ok(data) => from_str(data.as_str())?,
+            Err(Error::BinanceError { response })
+                if response.code == CANCEL_REPLACE_PARTIALLY_FAILED
+                    || response.code == CANCEL_REPLACE_FAILED =>
+            {
+                match response.extra().get("data").cloned() {
+                    Some(data) => serde_json::from_value(data)?,
+                    None => return Err(Error::BinanceError { response }),
+                }
+            }
+            Err(e) => return Err(e),
+        };
+
+        Ok(response.into())
+    }
+
+    /// Decrease the quantity of an open order, keeping its priority
+    pub async fn amend_order_keep_priority(
+        &self,
+        order: AmendOrderRequest,
+    ) -> Result<AmendKeepPriority> {
+        if order.order_id.is_none() && order.orig_client_order_id.is_none() {
+            return Err(Error::InvalidOrderError {
+                msg: "Either order_id or orig_client_order_id has to be set".to_string(),
+            });
+        }
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        let data = self
+            .client
+            .put_signed(API_V3_AMEND_KEEP_PRIORITY, &request)
+            .await?;
+        let amended: AmendKeepPriority = from_str(data.as_str())?;
+
+        Ok(amended)
+    }
+
+    /// Place an OCO order list
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, account::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let oco = OCOOrderList {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Sell,
+    ///     quantity: dec!(0.01),
+    ///     above_type: OrderType::LimitMaker,
+    ///     above_price: Some(dec!(70000)),
+    ///     below_type: OrderType::StopLossLimit,
+    ///     below_price: Some(dec!(59000)),
+    ///     below_stop_price: Some(dec!(60000)),
+    ///     below_time_in_force: Some(TimeInForce::GTC),
+    ///     ..OCOOrderList::default()
+    /// };
+    /// let list = tokio_test::block_on(account.place_oco_order_list(oco));
+    /// assert!(list.is_ok(), "{:?}", list);
+    /// ```
+    pub async fn place_oco_order_list(&self, order: OCOOrderList) -> Result<OrderList> {
+        order.valid()?;
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        let data = self
+            .client
+            .post_signed(API_V3_ORDER_LIST_OCO, &request)
+            .await?;
+        let order_list: OrderList = from_str(data.as_str())?;
+
+        Ok(order_list)
+    }
+
+    /// Place an OTO order list
+    pub async fn place_oto_order_list(&self, order: OTOOrderList) -> Result<OrderList> {
+        order.valid()?;
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        let data = self
+            .client
+            .post_signed(API_V3_ORDER_LIST_OTO, &request)
+            .await?;
+        let order_list: OrderList = from_str(data.as_str())?;
+
+        Ok(order_list)
+    }
+
+    /// Place an OTOCO order list
+    pub async fn place_otoco_order_list(&self, order: OTOCOOrderList) -> Result<OrderList> {
+        order.valid()?;
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        let data = self
+            .client
+            .post_signed(API_V3_ORDER_LIST_OTOCO, &request)
+            .await?;
+        let order_list: OrderList = from_str(data.as_str())?;
+
+        Ok(order_list)
+    }
+
+    /// Order list by id or client id
+    pub async fn order_list(&self, query: OrderListQuery) -> Result<OrderList> {
+        let request = build_signed_request_p(query, self.recv_window)?;
+        let data = self.client.get_signed(API_V3_ORDER_LIST, &request).await?;
+        let order_list: OrderList = from_str(data.as_str())?;
+
+        Ok(order_list)
+    }
+
+    /// Order lists of the account
+    pub async fn all_order_lists(&self, query: OrderListsQuery) -> Result<Vec<OrderList>> {
+        let request = build_signed_request_p(query, self.recv_window)?;
+        let data = self
+            .client
+            .get_signed(API_V3_ALL_ORDER_LIST, &request)
+            .await?;
+        let order_lists: Vec<OrderList> = from_str(data.as_str())?;
+
+        Ok(order_lists)
+    }
+
+    pub async fn open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        let data = self
+            .client
+            .get_signed(API_V3_OPEN_ORDER_LIST, &request)
+            .await?;
+        let order_lists: Vec<OrderList> = from_str(data.as_str())?;
+
+        Ok(order_lists)
+    }
+
+    /// Cancel every order of an order list
+    pub async fn cancel_order_list(&self, o: OrderListCancellation) -> Result<OrderList> {
+        let request = build_signed_request_p(o, self.recv_window)?;
+        let data = self
+            .client
+            .delete_signed(API_V3_ORDER_LIST, &request)
+            .await?;
+        let order_list: OrderList = from_str(data.as_str())?;
+
+        Ok(order_list)
+    }
+
     /// Trade history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, account::*, config::*};
+    /// use exrs::binance::{api::*, account::*, config::*};
     /// let account: Account = Binance::new_with_env(&Config::testnet());
     /// let trade_history = tokio_test::block_on(account.trade_history("BTCUSDT"));
     /// assert!(trade_history.is_ok(), "{:?}", trade_history);
@@ -495,3 +1068,42 @@ impl Account {
         Ok(trade_history)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn order_lists_query_has_no_margin_parameters() {
+        let query = OrderListsQuery {
+            start_time: Some(1),
+            limit: Some(1000),
+            ..Default::default()
+        };
+        assert_eq!(qs::to_string(&query).unwrap(), "startTime=1&limit=1000");
+    }
+
+    #[test]
+    fn cancel_replace_outcomes() {
+        let error: BinanceContentError = serde_json::from_str(
+            r#"{"code":-2021,"msg":"Order cancel-replace partially failed.",
+            "data":{"cancelResult":"SUCCESS","newOrderResult":"FAILURE",
+            "cancelResponse":{"symbol":"BTCUSDT","origClientOrderId":"old","orderId":9,
+            "orderListId":-1,"clientOrderId":"cancel","price":"60000","origQty":"0.01",
+            "executedQty":"0","cummulativeQuoteQty":"0","status":"CANCELED",
+            "timeInForce":"GTC","type":"LIMIT","side":"BUY"},
+            "newOrderResponse":{"code":-2010,"msg":"Account has insufficient balance for requested action."}}}"#,
+        )
+        .unwrap();
+        let response: CancelReplaceResponse =
+            serde_json::from_value(error.extra()["data"].clone()).unwrap();
+        let result: CancelReplace = response.into();
+        assert_eq!(result.cancel_result, CancelReplaceResult::Success);
+        assert_eq!(result.new_order_result, CancelReplaceResult::Failure);
+        assert_eq!(result.cancel_response.unwrap().order_id, 9);
+        assert!(matches!(
+            result.new_order_response,
+            Some(Err(Error::BinanceError { response })) if response.code == -2010
+        ));
+    }
+}
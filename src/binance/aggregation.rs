@@ -0,0 +1,483 @@
+/*!
+Kline resampling and candles built from trades
+
+`resample` merges a kline series into bars of a longer period, `CandleBuilder` builds
+bars incrementally from a trade stream and hands out each bar once it is closed.
+Periods are either a `KlineInterval` or any duration, such as 10s bars that Binance does not serve.
+
+# Examples
+```rust
+use exrs::binance::aggregation::*;
+use std::time::Duration;
+
+let mut builder = CandleBuilder::new(Duration::from_secs(10)).set_forward_fill(true);
+// feed `TradesEvent`s from an aggTrade stream
+// for closed in builder.push(&trade) { ... }
+let closed = builder.tick(1_700_000_000_000);
+assert!(closed.is_empty());
+```
+*/
+
+use super::errors::*;
+use super::rest_model::{AggTrade, KlineInterval, KlineSummary, Trade};
+use super::ws_model::{TradeEvent, TradesEvent};
+use crate::binance_f::ws_model::AggrTradesEvent;
+use rust_decimal::Decimal;
+
+use std::time::Duration;
+
+/// Length and alignment of a bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BarPeriod {
+    /// Aligned like Binance klines, weeks open on monday and months on the 1st
+    Interval(KlineInterval),
+    /// Aligned on multiples of the duration since the epoch, at least 1ms
+    Custom(Duration),
+}
+
+impl BarPeriod {
+    /// Open time of the bar containing `time`, both in ms
+    pub fn align(&self, time: i64) -> i64 {
+        match self {
+            BarPeriod::Interval(interval) => interval.align(time),
+            BarPeriod::Custom(_) => time.div_euclid(self.millis()) * self.millis(),
+        }
+    }
+
+    /// Open time of the bar following the one containing `time`, both in ms
+    pub fn next_open(&self, time: i64) -> i64 {
+        match self {
+            BarPeriod::Interval(interval) => interval.next_open(time),
+            BarPeriod::Custom(_) => self.align(time) + self.millis(),
+        }
+    }
+
+    fn millis(&self) -> i64 {
+        match self {
+            BarPeriod::Interval(interval) => interval.duration().as_millis() as i64,
+            BarPeriod::Custom(duration) => (duration.as_millis() as i64).max(1),
+        }
+    }
+}
+
+impl From<KlineInterval> for BarPeriod {
+    fn from(interval: KlineInterval) -> Self {
+        BarPeriod::Interval(interval)
+    }
+}
+
+impl From<Duration> for BarPeriod {
+    fn from(duration: Duration) -> Self {
+        BarPeriod::Custom(duration)
+    }
+}
+
+/// Merge consecutive klines into bars of `period`.
+/// Every kline must fall in a single bar, so `period` has to be a multiple of the
+/// kline interval, otherwise `Error::InvalidPeriod` is returned.
+/// The last bar is partial when the series stops before its end.
+pub fn resample<P>(klines: &[KlineSummary], period: P) -> Result<Vec<KlineSummary>>
+where
+    P: Into<BarPeriod>,
+{
+    let period = period.into();
+    let mut bars: Vec<KlineSummary> = Vec::new();
+    for kline in klines {
+        let open_time = period.align(kline.open_time);
+        if period.align(kline.close_time) != open_time {
+            return Err(Error::InvalidPeriod(format!("{:?}", period)));
+        }
+        match bars.last_mut() {
+            Some(bar) if bar.open_time == open_time => {
+                bar.high = bar.high.max(kline.high);
+                bar.low = bar.low.min(kline.low);
+                bar.close = kline.close;
+                bar.volume += kline.volume;
+                bar.quote_asset_volume += kline.quote_asset_volume;
+                bar.number_of_trades += kline.number_of_trades;
+                bar.taker_buy_base_asset_volume += kline.taker_buy_base_asset_volume;
+                bar.taker_buy_quote_asset_volume += kline.taker_buy_quote_asset_volume;
+            }
+            _ => bars.push(KlineSummary {
+                open_time,
+                close_time: period.next_open(open_time) - 1,
+                ..kline.clone()
+            }),
+        }
+    }
+    Ok(bars)
+}
+
+/// A trade that can be aggregated into candles
+pub trait TradeTick {
+    /// Trade time in ms
+    fn time(&self) -> u64;
+    fn price(&self) -> Decimal;
+    fn qty(&self) -> Decimal;
+    fn is_buyer_maker(&self) -> bool;
+    /// Number of trades, more than one for aggregate trades
+    fn trade_count(&self) -> i64 {
+        1
+    }
+}
+
+impl TradeTick for TradesEvent {
+    fn time(&self) -> u64 {
+        self.trade_order_time
+    }
+
+    fn price(&self) -> Decimal {
+        self.price
+    }
+
+    fn qty(&self) -> Decimal {
+        self.qty
+    }
+
+    fn is_buyer_maker(&self) -> bool {
+        self.is_buyer_maker
+    }
+
+    fn trade_count(&self) -> i64 {
+        (self.last_break_trade_id + 1).saturating_sub(self.first_break_trade_id) as i64
+    }
+}
+
+impl TradeTick for AggrTradesEvent {
+    fn time(&self) -> u64 {
+        self.trade_order_time
+    }
+
+    fn price(&self) -> Decimal {
+        self.price
+    }
+
+    fn qty(&self) -> Decimal {
+        self.qty
+    }
+
+    fn is_buyer_maker(&self) -> bool {
+        self.is_buyer_maker
+    }
+
+    fn trade_count(&self) -> i64 {
+        (self.last_break_trade_id + 1).saturating_sub(self.first_break_trade_id) as i64
+    }
+}
+
+impl TradeTick for TradeEvent {
+    fn time(&self) -> u64 {
+        self.trade_order_time
+    }
+
+    fn price(&self) -> Decimal {
+        self.price
+    }
+
+    fn qty(&self) -> Decimal {
+        self.qty
+    }
+
+    fn is_buyer_maker(&self) -> bool {
+        self.is_buyer_maker
+    }
+}
+
+impl TradeTick for Trade {
+    fn time(&self) -> u64 {
+        self.time
+    }
+
+    fn price(&self) -> Decimal {
+        self.price
+    }
+
+    fn qty(&self) -> Decimal {
+        self.qty
+    }
+
+    fn is_buyer_maker(&self) -> bool {
+        self.is_buyer_maker
+    }
+}
+
+impl TradeTick for AggTrade {
+    fn time(&self) -> u64 {
+        self.time
+    }
+
+    fn price(&self) -> Decimal {
+        self.price
+    }
+
+    fn qty(&self) -> Decimal {
+        self.qty
+    }
+
+    fn is_buyer_maker(&self) -> bool {
+        self.maker
+    }
+
+    fn trade_count(&self) -> i64 {
+        (self.last_id + 1).saturating_sub(self.first_id) as i64
+    }
+}
+
+/// A candle that will not change anymore
+#[derive(Debug, Clone)]
+pub struct ClosedCandle {
+    pub kline: KlineSummary,
+    /// No trade happened in the period, the candle repeats the previous close
+    pub filled: bool,
+}
+
+/// Builds candles of a period from a time ordered trade stream
+pub struct CandleBuilder {
+    period: BarPeriod,
+    forward_fill: bool,
+    current: Option<KlineSummary>,
+    /// Open time of the first period not closed yet and the last close price
+    closed_until: Option<(i64, Decimal)>,
+}
+
+impl CandleBuilder {
+    pub fn new<P>(period: P) -> Self
+    where
+        P: Into<BarPeriod>,
+    {
+        CandleBuilder {
+            period: period.into(),
+            forward_fill: false,
+            current: None,
+            closed_until: None,
+        }
+    }
+
+    /// Emit a flat candle at the previous close for periods without trades
+    pub fn set_forward_fill(mut self, forward_fill: bool) -> Self {
+        self.forward_fill = forward_fill;
+        self
+    }
+
+    /// The candle being built
+    pub fn current(&self) -> Option<&KlineSummary> {
+        self.current.as_ref()
+    }
+
+    /// Add a trade and return the candles it closed, oldest first.
+    /// Trades of a period already closed are ignored.
+    pub fn push<T: TradeTick>(&mut self, trade: &T) -> Vec<ClosedCandle> {
+        let time = trade.time() as i64;
+        let open_time = self.period.align(time);
+        let closed_until = match (&self.current, self.closed_until) {
+            (Some(current), _) => Some(current.open_time),
+            (None, closed_until) => closed_until.map(|(open_time, _)| open_time),
+        };
+        if closed_until.is_some_and(|closed_until| open_time < closed_until) {
+            return Vec::new();
+        }
+
+        let closed = self.tick(time as u64);
+        let (price, qty) = (trade.price(), trade.qty());
+        let (taker_base, taker_quote) = match trade.is_buyer_maker() {
+            true => (Decimal::ZERO, Decimal::ZERO),
+            false => (qty, qty * price),
+        };
+        match self.current.as_mut() {
+            Some(candle) => {
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.close = price;
+                candle.volume += qty;
+                candle.quote_asset_volume += qty * price;
+                candle.number_of_trades += trade.trade_count();
+                candle.taker_buy_base_asset_volume += taker_base;
+                candle.taker_buy_quote_asset_volume += taker_quote;
+            }
+            None => {
+                self.current = Some(KlineSummary {
+                    open_time,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: qty,
+                    close_time: self.period.next_open(open_time) - 1,
+                    quote_asset_volume: qty * price,
+                    number_of_trades: trade.trade_count(),
+                    taker_buy_base_asset_volume: taker_base,
+                    taker_buy_quote_asset_volume: taker_quote,
+                })
+            }
+        }
+        closed
+    }
+
+    /// Close the current candle, and fill the empty periods after it, once `time` in ms
+    /// is past their end. Call it on a timer to close candles of quiet markets.
+    pub fn tick(&mut self, time: u64) -> Vec<ClosedCandle> {
+        let time = time as i64;
+        let mut closed = Vec::new();
+        if let Some(candle) = self.current.take_if(|candle| time > candle.close_time) {
+            self.closed_until = Some((candle.close_time + 1, candle.close));
+            closed.push(ClosedCandle {
+                kline: candle,
+                filled: false,
+            });
+        }
+        if !self.forward_fill || self.current.is_some() {
+            return closed;
+        }
+        while let Some((open_time, close)) = self.closed_until {
+            let close_time = self.period.next_open(open_time) - 1;
+            if time <= close_time {
+                break;
+            }
+            closed.push(ClosedCandle {
+                kline: flat_candle(open_time, close_time, close),
+                filled: true,
+            });
+            self.closed_until = Some((close_time + 1, close));
+        }
+        closed
+    }
+}
+
+fn flat_candle(open_time: i64, close_time: i64, price: Decimal) -> KlineSummary {
+    KlineSummary {
+        open_time,
+        open: price,
+        high: price,
+        low: price,
+        close: price,
+        volume: Decimal::ZERO,
+        close_time,
+        quote_asset_volume: Decimal::ZERO,
+        number_of_trades: 0,
+        taker_buy_base_asset_volume: Decimal::ZERO,
+        taker_buy_quote_asset_volume: Decimal::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn kline(open_time: i64, open: Decimal, close: Decimal, volume: Decimal) -> KlineSummary {
+        KlineSummary {
+            open_time,
+            open,
+            high: open.max(close),
+            low: open.min(close),
+            close,
+            volume,
+            close_time: open_time + 59_999,
+            quote_asset_volume: volume * close,
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: volume / dec!(2),
+            taker_buy_quote_asset_volume: volume * close / dec!(2),
+        }
+    }
+
+    fn trade(time: u64, price: Decimal, qty: Decimal, is_buyer_maker: bool) -> Trade {
+        Trade {
+            id: time,
+            price,
+            qty,
+            quote_qty: price * qty,
+            time,
+            is_buyer_maker,
+            is_best_match: true,
+        }
+    }
+
+    #[test]
+    fn resample_into_multiples() {
+        let klines: Vec<KlineSummary> = (0..4)
+            .map(|i| {
+                kline(
+                    i * 60_000,
+                    dec!(10) + Decimal::from(i),
+                    dec!(11) + Decimal::from(i),
+                    dec!(1),
+                )
+            })
+            .collect();
+        let bars = resample(&klines, KlineInterval::Minute3).unwrap();
+        assert_eq!(bars.len(), 2);
+        assert_eq!(
+            (bars[0].open, bars[0].high, bars[0].close, bars[0].volume),
+            (dec!(10), dec!(13), dec!(13), dec!(3))
+        );
+        assert_eq!(bars[0].close_time, 179_999);
+        assert_eq!(bars[1].open_time, 180_000);
+
+        assert!(matches!(
+            resample(&klines, Duration::from_secs(90)),
+            Err(Error::InvalidPeriod(_))
+        ));
+    }
+
+    #[test]
+    fn candle_builder_closes_and_fills() {
+        let mut builder = CandleBuilder::new(Duration::from_secs(10)).set_forward_fill(true);
+        assert!(builder
+            .push(&trade(1_000, dec!(100), dec!(1), false))
+            .is_empty());
+        assert!(builder
+            .push(&trade(9_000, dec!(102), dec!(2), true))
+            .is_empty());
+
+        let closed = builder.push(&trade(31_000, dec!(101), dec!(1), false));
+        assert_eq!(closed.len(), 3);
+        let candle = &closed[0].kline;
+        assert_eq!(
+            (candle.open, candle.high, candle.close),
+            (dec!(100), dec!(102), dec!(102))
+        );
+        assert_eq!(candle.volume, dec!(3));
+        assert_eq!(candle.taker_buy_base_asset_volume, dec!(1));
+        assert_eq!(candle.number_of_trades, 2);
+        assert!(!closed[0].filled);
+        assert!(closed[1].filled && closed[2].filled);
+        assert_eq!(closed[2].kline.open_time, 20_000);
+        assert_eq!(closed[2].kline.close, dec!(102));
+
+        // a late trade of a closed period is ignored
+        assert!(builder
+            .push(&trade(5_000, dec!(1), dec!(1), false))
+            .is_empty());
+        assert_eq!(builder.current().unwrap().open, dec!(101));
+
+        // quiet market: the timer closes the candle and fills what is over
+        let closed = builder.tick(55_000);
+        assert_eq!(
+            closed.iter().map(|c| c.filled).collect::<Vec<_>>(),
+            vec![false, true]
+        );
+        assert!(builder.current().is_none());
+    }
+    #[test]
+    fn candles_from_aggregate_trade_events() {
+        let spot: TradesEvent = serde_json::from_str(
+            r#"{"e":"aggTrade","E":1000,"s":"BTCUSDT","a":1,"p":"100.5","q":"2","f":10,"l":12,
+            "T":1000,"m":true,"M":true}"#,
+        )
+        .unwrap();
+        let futures: AggrTradesEvent = serde_json::from_str(
+            r#"{"e":"aggTrade","E":2000,"s":"BTCUSDT","a":2,"p":"101","q":"1","f":13,"l":13,
+            "T":2000,"m":false}"#,
+        )
+        .unwrap();
+        let mut builder = CandleBuilder::new(Duration::from_secs(10));
+        assert!(builder.push(&spot).is_empty());
+        assert!(builder.push(&futures).is_empty());
+        let candle = builder.current().unwrap();
+        assert_eq!(
+            (candle.open, candle.close, candle.volume),
+            (dec!(100.5), dec!(101), dec!(3))
+        );
+        assert_eq!(candle.number_of_trades, 4);
+    }
+}
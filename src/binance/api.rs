@@ -49,6 +49,8 @@ impl Binance for Account {
         Account {
             client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
             recv_window: config.recv_window,
+            filters: None,
+            reference_price: None,
         }
     }
 }
@@ -75,6 +77,7 @@ impl Binance for Market {
         Market {
             client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
             recv_window: config.recv_window,
+            weight_limiter: None,
         }
     }
 }
@@ -0,0 +1,253 @@
+/*!
+Reader for the public data archive of https://data.binance.vision
+
+Archives are zipped CSV files, daily or monthly, with a sibling `.CHECKSUM` file
+holding the SHA-256 of the zip. Older files have no header line, newer ones do,
+both layouts are read the same way.
+Klines, trades and aggTrades are parsed into the same models as the REST API,
+other files (bookTicker, bookDepth, metrics...) are available as raw records.
+
+# Examples
+```rust,no_run
+use exrs::binance::archive::*;
+let mut archive = ArchiveFile::open_verified("BTCUSDT-1m-2024-01.zip").unwrap();
+for kline in archive.klines().unwrap() {
+    println!("{:?}", kline.unwrap());
+}
+```
+*/
+
+use super::errors::*;
+use super::rest_model::{AggTrade, KlineSummary, Trade};
+
+use csv::StringRecord;
+use hmac_sha256::Hash;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+use std::str::FromStr;
+use zip::ZipArchive;
+
+/// Spot archives switched to microsecond timestamps in 2025, anything above is not in ms
+const MAX_MILLIS: i64 = 100_000_000_000_000;
+
+/// A zipped CSV archive file
+pub struct ArchiveFile {
+    archive: ZipArchive<BufReader<File>>,
+}
+
+impl ArchiveFile {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<ArchiveFile> {
+        let file = File::open(path)?;
+        Ok(ArchiveFile {
+            archive: ZipArchive::new(BufReader::new(file))?,
+        })
+    }
+
+    /// Open after checking the file against its `.CHECKSUM` sibling
+    pub fn open_verified<P: AsRef<Path>>(path: P) -> Result<ArchiveFile> {
+        verify_checksum(&path)?;
+        Self::open(path)
+    }
+
+    /// Rows of the CSV file, without the header line if any
+    pub fn records(&mut self) -> Result<impl Iterator<Item = Result<StringRecord>> + '_> {
+        let entry = self.archive.by_index(0)?;
+        let mut records = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(entry)
+            .into_records()
+            .peekable();
+        if let Some(Ok(first)) = records.peek() {
+            if is_header(first) {
+                records.next();
+            }
+        }
+        Ok(records.map(|record| record.map_err(Error::from)))
+    }
+
+    /// Rows of a `klines`, `markPriceKlines`, `indexPriceKlines` or `premiumIndexKlines` file
+    pub fn klines(&mut self) -> Result<impl Iterator<Item = Result<KlineSummary>> + '_> {
+        Ok(self
+            .records()?
+            .map(|record| record.and_then(|record| kline_summary(&record))))
+    }
+
+    /// Rows of a spot or futures `trades` file, futures trades are all best matches
+    pub fn trades(&mut self) -> Result<impl Iterator<Item = Result<Trade>> + '_> {
+        Ok(self
+            .records()?
+            .map(|record| record.and_then(|record| trade(&record))))
+    }
+
+    /// Rows of a spot or futures `aggTrades` file, futures trades are all best matches
+    pub fn agg_trades(&mut self) -> Result<impl Iterator<Item = Result<AggTrade>> + '_> {
+        Ok(self
+            .records()?
+            .map(|record| record.and_then(|record| agg_trade(&record))))
+    }
+}
+
+/// Check `path` against the SHA-256 in `<path>.CHECKSUM`
+pub fn verify_checksum<P: AsRef<Path>>(path: P) -> Result<()> {
+    let path = path.as_ref();
+    let mut checksum_path = path.as_os_str().to_owned();
+    checksum_path.push(".CHECKSUM");
+    let checksum = std::fs::read_to_string(checksum_path)?;
+    let expected = checksum.split_whitespace().next().unwrap_or_default();
+
+    let mut file = File::open(path)?;
+    let mut hash = Hash::new();
+    let mut buf = vec![0; 1 << 16];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hash.update(&buf[..read]);
+    }
+
+    if !hex::encode(hash.finalize()).eq_ignore_ascii_case(expected) {
+        return Err(Error::ChecksumMismatch(path.display().to_string()));
+    }
+    Ok(())
+}
+
+fn is_header(record: &StringRecord) -> bool {
+    record
+        .get(0)
+        .is_some_and(|field| field.parse::<f64>().is_err())
+}
+
+fn field<T: FromStr>(record: &StringRecord, index: usize) -> Result<T> {
+    record
+        .get(index)
+        .and_then(|field| field.trim().parse().ok())
+        .ok_or_else(|| Error::InvalidArchiveRow(format!("{:?}", record)))
+}
+
+fn flag(record: &StringRecord, index: usize) -> Result<bool> {
+    match record.get(index).map(str::trim) {
+        Some(field) if field.eq_ignore_ascii_case("true") => Ok(true),
+        Some(field) if field.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(Error::InvalidArchiveRow(format!("{:?}", record))),
+    }
+}
+
+fn millis(time: i64) -> i64 {
+    if time > MAX_MILLIS {
+        time / 1000
+    } else {
+        time
+    }
+}
+
+fn kline_summary(record: &StringRecord) -> Result<KlineSummary> {
+    Ok(KlineSummary {
+        open_time: millis(field(record, 0)?),
+        open: field(record, 1)?,
+        high: field(record, 2)?,
+        low: field(record, 3)?,
+        close: field(record, 4)?,
+        volume: field(record, 5)?,
+        close_time: millis(field(record, 6)?),
+        quote_asset_volume: field(record, 7)?,
+        number_of_trades: field(record, 8)?,
+        taker_buy_base_asset_volume: field(record, 9)?,
+        taker_buy_quote_asset_volume: field(record, 10)?,
+    })
+}
+
+fn trade(record: &StringRecord) -> Result<Trade> {
+    Ok(Trade {
+        id: field(record, 0)?,
+        price: field(record, 1)?,
+        qty: field(record, 2)?,
+        quote_qty: field(record, 3)?,
+        time: millis(field(record, 4)?) as u64,
+        is_buyer_maker: flag(record, 5)?,
+        is_best_match: record.get(6).is_none() || flag(record, 6)?,
+    })
+}
+
+fn agg_trade(record: &StringRecord) -> Result<AggTrade> {
+    Ok(AggTrade {
+        agg_id: field(record, 0)?,
+        price: field(record, 1)?,
+        qty: field(record, 2)?,
+        first_id: field(record, 3)?,
+        last_id: field(record, 4)?,
+        time: millis(field(record, 5)?) as u64,
+        maker: flag(record, 6)?,
+        best_match: record.get(7).is_none() || flag(record, 7)?,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::io::Write;
+    use std::path::PathBuf;
+    use zip::write::{FileOptions, ZipWriter};
+
+    fn write_archive(name: &str, csv: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("exrs-{}-{}.zip", std::process::id(), name));
+        let mut zip = ZipWriter::new(File::create(&path).unwrap());
+        zip.start_file(name.replace(".zip", ".csv"), FileOptions::default())
+            .unwrap();
+        zip.write_all(csv.as_bytes()).unwrap();
+        zip.finish().unwrap();
+
+        let digest = Hash::hash(&std::fs::read(&path).unwrap());
+        let mut checksum_path = path.clone().into_os_string();
+        checksum_path.push(".CHECKSUM");
+        std::fs::write(
+            checksum_path,
+            format!("{}  {}\n", hex::encode(digest), name),
+        )
+        .unwrap();
+        path
+    }
+
+    #[test]
+    fn reads_both_layouts_and_checks_checksum() {
+        let headerless = write_archive(
+            "BTCUSDT-aggTrades-2024-01-01",
+            "1,42283.58,0.001,1,1,1704067200000,True,True\n2,42283.59,0.5,2,4,1704067200001,False,True\n",
+        );
+        let mut archive = ArchiveFile::open_verified(&headerless).unwrap();
+        let trades: Vec<AggTrade> = archive.agg_trades().unwrap().map(Result::unwrap).collect();
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[1].last_id, 4);
+        assert!(!trades[1].maker);
+
+        let header = write_archive(
+            "BTCUSDT-trades-2025-02-01",
+            "id,price,qty,quote_qty,time,is_buyer_maker\n7,100.5,2,201,1738368000000123,true\n",
+        );
+        let mut archive = ArchiveFile::open_verified(&header).unwrap();
+        let trades: Vec<Trade> = archive.trades().unwrap().map(Result::unwrap).collect();
+        assert_eq!(trades.len(), 1);
+        assert_eq!(trades[0].time, 1738368000000);
+        assert!(trades[0].is_best_match);
+
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&header)
+            .unwrap()
+            .write_all(b"tampered")
+            .unwrap();
+        assert!(matches!(
+            ArchiveFile::open_verified(&header),
+            Err(Error::ChecksumMismatch(_))
+        ));
+
+        for path in [headerless, header] {
+            let mut checksum_path = path.clone().into_os_string();
+            checksum_path.push(".CHECKSUM");
+            let _ = std::fs::remove_file(checksum_path);
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
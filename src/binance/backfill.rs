@@ -0,0 +1,383 @@
+use super::errors::*;
+use super::market::Market;
+use super::rest_model::{AggTrade, Trade};
+use super::util::{get_timestamp, WeightLimiter};
+
+use futures::stream::{self, Stream, TryStreamExt};
+use std::future::Future;
+
+/// Request weight of `/api/v3/historicalTrades`
+const HISTORICAL_TRADES_WEIGHT: u32 = 25;
+/// Request weight of `/api/v3/aggTrades`
+const AGG_TRADES_WEIGHT: u32 = 2;
+const TRADES_MAX_LIMIT: u16 = 1000;
+/// aggTrades accepts time windows of at most one hour
+const LOCATE_WINDOW: u64 = 3_600_000;
+
+/// An item of a trade backfill
+#[derive(Debug, Clone)]
+pub enum Backfilled<T> {
+    Trade(T),
+    /// Ids `first_missing..=last_missing` were skipped by the exchange
+    Gap {
+        first_missing: u64,
+        last_missing: u64,
+    },
+}
+
+impl<T: Sequenced> Backfilled<T> {
+    /// Highest id covered by this item, pass it to `TradeBackfill::advance`
+    pub fn last_id(&self) -> u64 {
+        match self {
+            Backfilled::Trade(trade) => trade.id(),
+            Backfilled::Gap { last_missing, .. } => *last_missing,
+        }
+    }
+}
+
+/// Trades identified by a contiguous id
+pub trait Sequenced {
+    fn id(&self) -> u64;
+    fn time(&self) -> u64;
+}
+
+/// Error type of the market a backfill walks
+pub(crate) trait BackfillError {
+    fn msg(msg: String) -> Self;
+}
+
+impl BackfillError for Error {
+    fn msg(msg: String) -> Self {
+        Error::Msg(msg)
+    }
+}
+
+impl Sequenced for Trade {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Sequenced for AggTrade {
+    fn id(&self) -> u64 {
+        self.agg_id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+/// Progress of a trade or aggregate trade backfill, serializable so that it can be
+/// persisted and passed back to `trades_backfill` or `agg_trades_backfill` after an interruption
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeBackfill {
+    pub symbol: String,
+    /// Id of the next trade, located from `start_time` when missing
+    pub next_id: Option<u64>,
+    /// Time in ms to start from when `next_id` is missing
+    pub start_time: Option<u64>,
+    /// Stop after the last trade at or before this time in ms
+    pub end_time: Option<u64>,
+    /// Stop after this id
+    pub end_id: Option<u64>,
+    /// Page size, at most 1000
+    pub limit: u16,
+    /// Request weight budget of the backfill
+    pub weight_per_minute: u32,
+}
+
+impl TradeBackfill {
+    /// Walk forward from trade (or aggregate trade) id `from_id`
+    pub fn from_id<S>(symbol: S, from_id: u64) -> Self
+    where
+        S: Into<String>,
+    {
+        TradeBackfill {
+            symbol: symbol.into(),
+            next_id: Some(from_id),
+            start_time: None,
+            end_time: None,
+            end_id: None,
+            limit: TRADES_MAX_LIMIT,
+            weight_per_minute: 1200,
+        }
+    }
+
+    /// Walk forward over the trades between `start_time` and `end_time` in ms
+    pub fn from_time<S>(symbol: S, start_time: u64, end_time: u64) -> Self
+    where
+        S: Into<String>,
+    {
+        TradeBackfill {
+            next_id: None,
+            start_time: Some(start_time),
+            end_time: Some(end_time),
+            ..Self::from_id(symbol, 0)
+        }
+    }
+
+    pub fn set_end_id(mut self, end_id: u64) -> Self {
+        self.end_id = Some(end_id);
+        self
+    }
+
+    pub fn set_end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = limit.clamp(1, TRADES_MAX_LIMIT);
+        self
+    }
+
+    pub fn set_weight_per_minute(mut self, weight_per_minute: u32) -> Self {
+        self.weight_per_minute = weight_per_minute;
+        self
+    }
+
+    /// Record every id up to `last_id` as received
+    pub fn advance(&mut self, last_id: u64) {
+        if self.next_id.is_none_or(|next_id| last_id >= next_id) {
+            self.next_id = Some(last_id + 1);
+        }
+    }
+
+    /// Check a page fetched from `next_id` for gaps and drop what lies past the end.
+    /// Returns the items and whether the backfill is over.
+    fn take_page<T: Sequenced>(&mut self, page: Vec<T>) -> (Vec<Backfilled<T>>, bool) {
+        let caught_up = page.len() < self.limit as usize;
+        let mut items = Vec::with_capacity(page.len());
+        for trade in page {
+            let id = trade.id();
+            let next_id = self.next_id.unwrap_or(id);
+            if id < next_id {
+                continue;
+            }
+            if let Some(end_id) = self.end_id.filter(|end_id| id > *end_id) {
+                if next_id <= end_id {
+                    items.push(Backfilled::Gap {
+                        first_missing: next_id,
+                        last_missing: end_id,
+                    });
+                }
+                return (items, true);
+            }
+            if self
+                .end_time
+                .is_some_and(|end_time| trade.time() > end_time)
+            {
+                return (items, true);
+            }
+            if id > next_id {
+                items.push(Backfilled::Gap {
+                    first_missing: next_id,
+                    last_missing: id - 1,
+                });
+            }
+            self.next_id = Some(id + 1);
+            items.push(Backfilled::Trade(trade));
+        }
+        (items, caught_up)
+    }
+}
+
+impl Market {
+    /// Stream every trade of a backfill in id order, with a `Backfilled::Gap` for each
+    /// range of ids missing from the exchange.
+    /// A backfill started from a time is located with aggregate trades.
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, backfill::*, market::*, config::*};
+    /// use futures::TryStreamExt;
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let backfill = TradeBackfill::from_time("BTCUSDT", 1_640_995_200_000, 1_640_998_800_000);
+    /// let trades: Vec<_> = tokio_test::block_on(market.trades_backfill(backfill).try_collect()).unwrap();
+    /// ```
+    pub fn trades_backfill(
+        &self,
+        backfill: TradeBackfill,
+    ) -> impl Stream<Item = Result<Backfilled<Trade>>> {
+        let fetch_market = self.clone();
+        let locate_market = self.clone();
+        walk(
+            backfill,
+            HISTORICAL_TRADES_WEIGHT,
+            AGG_TRADES_WEIGHT,
+            move |backfill| {
+                let market = fetch_market.clone();
+                async move {
+                    market
+                        .get_historical_trades(backfill.symbol, backfill.next_id, backfill.limit)
+                        .await
+                }
+            },
+            move |symbol, start_time, end_time| {
+                let market = locate_market.clone();
+                async move {
+                    let trades = market
+                        .get_agg_trades(symbol, None, start_time, end_time, 1)
+                        .await?;
+                    Ok(trades.first().map(|trade| trade.first_id))
+                }
+            },
+        )
+    }
+
+    /// Stream every aggregate trade of a backfill in id order, with a `Backfilled::Gap`
+    /// for each range of aggregate ids missing from the exchange
+    pub fn agg_trades_backfill(
+        &self,
+        backfill: TradeBackfill,
+    ) -> impl Stream<Item = Result<Backfilled<AggTrade>>> {
+        let fetch_market = self.clone();
+        let locate_market = self.clone();
+        walk(
+            backfill,
+            AGG_TRADES_WEIGHT,
+            AGG_TRADES_WEIGHT,
+            move |backfill| {
+                let market = fetch_market.clone();
+                async move {
+                    market
+                        .get_agg_trades(
+                            backfill.symbol,
+                            backfill.next_id,
+                            None,
+                            None,
+                            backfill.limit,
+                        )
+                        .await
+                }
+            },
+            move |symbol, start_time, end_time| {
+                let market = locate_market.clone();
+                async move {
+                    let trades = market
+                        .get_agg_trades(symbol, None, start_time, end_time, 1)
+                        .await?;
+                    Ok(trades.first().map(|trade| trade.agg_id))
+                }
+            },
+        )
+    }
+}
+
+/// Page through `fetch` from `next_id`, first locating it with `locate`
+/// over one hour windows when the backfill starts from a time.
+/// Shared by the spot and futures markets, `weight` and `locate_weight` are
+/// the request weights of `fetch` and `locate`.
+pub(crate) fn walk<T, E, F, FF, L, LF>(
+    backfill: TradeBackfill,
+    weight: u32,
+    locate_weight: u32,
+    fetch: F,
+    locate: L,
+) -> impl Stream<Item = std::result::Result<Backfilled<T>, E>>
+where
+    T: Sequenced,
+    E: BackfillError,
+    F: Fn(TradeBackfill) -> FF,
+    FF: Future<Output = std::result::Result<Vec<T>, E>>,
+    L: Fn(String, u64, u64) -> LF,
+    LF: Future<Output = std::result::Result<Option<u64>, E>>,
+{
+    let limiter = WeightLimiter::new(backfill.weight_per_minute);
+    stream::try_unfold(
+        (backfill, limiter, false, fetch, locate),
+        move |(mut backfill, limiter, done, fetch, locate)| async move {
+            if done {
+                return Ok(None);
+            }
+            if backfill.next_id.is_none() {
+                let Some(mut start_time) = backfill.start_time else {
+                    return Err(E::msg(
+                        "trade backfill needs a from id or a start time".to_string(),
+                    ));
+                };
+                let end_time = match backfill.end_time {
+                    Some(end_time) => end_time,
+                    None => get_timestamp().map_err(|e| E::msg(e.to_string()))?,
+                };
+                while backfill.next_id.is_none() {
+                    if start_time > end_time {
+                        return Ok(None);
+                    }
+                    let window_end = end_time.min(start_time + LOCATE_WINDOW - 1);
+                    limiter.acquire(locate_weight).await;
+                    backfill.next_id =
+                        locate(backfill.symbol.clone(), start_time, window_end).await?;
+                    start_time = window_end + 1;
+                }
+            }
+            limiter.acquire(weight).await;
+            let page = fetch(backfill.clone()).await?;
+            let (items, done) = backfill.take_page(page);
+            Ok(Some((items, (backfill, limiter, done, fetch, locate))))
+        },
+    )
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Tick(u64, u64);
+
+    impl Sequenced for Tick {
+        fn id(&self) -> u64 {
+            self.0
+        }
+
+        fn time(&self) -> u64 {
+            self.1
+        }
+    }
+
+    fn ids(items: &[Backfilled<Tick>]) -> Vec<(u64, u64)> {
+        items
+            .iter()
+            .map(|item| match item {
+                Backfilled::Trade(tick) => (tick.0, tick.0),
+                Backfilled::Gap {
+                    first_missing,
+                    last_missing,
+                } => (*first_missing, *last_missing),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn take_page_marks_gaps_and_stops_at_the_end() {
+        let mut backfill = TradeBackfill::from_id("BTCUSDT", 10).set_limit(4);
+        let (items, done) =
+            backfill.take_page(vec![Tick(10, 1), Tick(11, 1), Tick(14, 2), Tick(15, 2)]);
+        assert_eq!(
+            ids(&items),
+            vec![(10, 10), (11, 11), (12, 13), (14, 14), (15, 15)]
+        );
+        assert!(!done);
+        assert_eq!(backfill.next_id, Some(16));
+
+        // a restored checkpoint may refetch what was already received
+        let mut backfill = backfill.set_end_id(20);
+        backfill.next_id = Some(15);
+        let (items, done) =
+            backfill.take_page(vec![Tick(14, 2), Tick(15, 2), Tick(16, 3), Tick(22, 4)]);
+        assert_eq!(ids(&items), vec![(15, 15), (16, 16), (17, 20)]);
+        assert!(done);
+
+        let mut backfill = TradeBackfill::from_id("BTCUSDT", 1).set_end_time(2);
+        let (items, done) = backfill.take_page(vec![Tick(1, 1), Tick(2, 2), Tick(3, 3)]);
+        assert_eq!(ids(&items), vec![(1, 1), (2, 2)]);
+        assert!(done);
+    }
+}
@@ -140,6 +140,19 @@ impl Client {
         self.handler(response).await
     }
 
+    pub async fn put_signed(&self, endpoint: &str, request: &str) -> Result<String> {
+        let url = self.sign_request(endpoint, request);
+        let response = self
+            .inner
+            .clone()
+            .put(url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
     pub async fn get(&self, endpoint: &str, request: &str) -> Result<String> {
         let mut url: String = format!("{}{}", self.host, endpoint);
         if !request.is_empty() {
@@ -264,7 +277,7 @@ impl Client {
 }
 
 // todo! need to match the doc
-fn handle_content_error(error: BinanceContentError) -> Error {
+pub(crate) fn handle_content_error(error: BinanceContentError) -> Error {
     match (error.code, error.msg.as_ref()) {
         (-1013, error_messages::INVALID_PRICE) => Error::InvalidPrice,
         (-1125, msg) => Error::InvalidListenKey(msg.to_string()),
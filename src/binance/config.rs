@@ -1,29 +1,39 @@
+use std::time::Duration;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Config {
     pub rest_api_endpoint: String,
     pub ws_endpoint: String,
     pub recv_window: u64,
+    /// Interval at which the websocket client sends its own ping frames
+    pub ws_ping_interval: Option<Duration>,
+    /// How long a stream may go without a message before it is considered stale
+    pub ws_idle_timeouts: IdleTimeouts,
 }
 
-impl Config {
+impl Default for Config {
     /// Configure binance with default production endpoints
     /// # Examples
     /// ```
-    /// use binance::config::Config;
+    /// use exrs::binance::config::Config;
     /// let config = Config::default();
     /// ```
-    pub fn default() -> Config {
+    fn default() -> Config {
         Config {
             rest_api_endpoint: "https://api.binance.com".into(),
             ws_endpoint: "wss://stream.binance.com:9443".into(),
             recv_window: 5000,
+            ws_ping_interval: Some(Duration::from_secs(30)),
+            ws_idle_timeouts: IdleTimeouts::default(),
         }
     }
+}
 
+impl Config {
     /// Configure binance with all testnet endpoints
     /// # Examples
     /// ```
-    /// use binance::config::Config;
+    /// use exrs::binance::config::Config;
     /// let config = Config::testnet();
     /// ```
     pub fn testnet() -> Config {
@@ -46,4 +56,39 @@ impl Config {
         self.recv_window = recv_window;
         self
     }
+
+    /// Set the client ping interval, `None` only answers server pings
+    pub fn set_ws_ping_interval(mut self, ws_ping_interval: Option<Duration>) -> Self {
+        self.ws_ping_interval = ws_ping_interval;
+        self
+    }
+
+    pub fn set_ws_idle_timeouts(mut self, ws_idle_timeouts: IdleTimeouts) -> Self {
+        self.ws_idle_timeouts = ws_idle_timeouts;
+        self
+    }
+}
+
+/// Idle thresholds per stream type, `None` disables stale detection for that type
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdleTimeouts {
+    /// Diff and partial depth streams
+    pub depth: Option<Duration>,
+    /// Trade and aggTrade streams, which can be quiet on illiquid symbols
+    pub trade: Option<Duration>,
+    /// Kline, ticker, bookTicker and other periodic streams
+    pub ticker: Option<Duration>,
+    /// User data streams, which only push on account activity
+    pub user_data: Option<Duration>,
+}
+
+impl Default for IdleTimeouts {
+    fn default() -> Self {
+        IdleTimeouts {
+            depth: Some(Duration::from_secs(30)),
+            trade: Some(Duration::from_secs(120)),
+            ticker: Some(Duration::from_secs(30)),
+            user_data: None,
+        }
+    }
 }
@@ -12,6 +12,13 @@ pub struct BinanceContentError {
     extra: HashMap<String, Value>,
 }
 
+impl BinanceContentError {
+    /// Fields besides code and msg, such as the `data` of a failed cancel-replace
+    pub fn extra(&self) -> &HashMap<String, Value> {
+        &self.extra
+    }
+}
+
 /// First errors are technical errors
 /// All unhandled binance content errors are BinanceError
 /// The rest are binance content errors that are properly handled
@@ -38,6 +45,10 @@ pub enum Error {
     TimestampError(#[from] std::time::SystemTimeError),
     #[error(transparent)]
     UTF8Err(#[from] std::str::Utf8Error),
+    #[error(transparent)]
+    Zip(#[from] zip::result::ZipError),
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
     #[error("{response}")]
     BinanceError {
         #[from]
@@ -47,6 +58,10 @@ pub enum Error {
     InvalidListenKey(String),
     #[error("unknown symbol {0}")]
     UnknownSymbol(String),
+    #[error(transparent)]
+    FilterViolation(#[from] crate::binance::filters::FilterViolation),
+    #[error(transparent)]
+    InvalidFilter(#[from] crate::binance::filters::InvalidFilter),
     #[error("{msg}")]
     InvalidOrderError { msg: String },
     #[error("invalid price")]
@@ -59,6 +74,12 @@ pub enum Error {
     ServiceUnavailable,
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("checksum mismatch for {0}")]
+    ChecksumMismatch(String),
+    #[error("invalid archive row {0}")]
+    InvalidArchiveRow(String),
+    #[error("stream stale, no message for {0:?}")]
+    Stale(std::time::Duration),
     #[error("{0}")]
     Msg(String),
 }
@@ -0,0 +1,473 @@
+//! Pre-trade checks against the filters of a symbol, shared by spot and futures.
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::str::FromStr;
+use std::sync::Arc;
+use thiserror::Error;
+
+use super::rest_model::{Filters, Symbol};
+use crate::binance_f::rest_model as futures;
+
+/// Direction in which prices and quantities are moved to a valid increment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+    Nearest,
+}
+
+impl Rounding {
+    fn strategy(self) -> RoundingStrategy {
+        match self {
+            Rounding::Down => RoundingStrategy::ToNegativeInfinity,
+            Rounding::Up => RoundingStrategy::ToPositiveInfinity,
+            Rounding::Nearest => RoundingStrategy::MidpointAwayFromZero,
+        }
+    }
+}
+
+/// Order rejected by a symbol filter before being sent
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum FilterViolation {
+    #[error("price {price} outside of [{min}, {max}]")]
+    PriceOutOfRange {
+        price: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("price {price} is not a multiple of the tick size {tick_size}")]
+    PriceNotOnTick { price: Decimal, tick_size: Decimal },
+    #[error("quantity {qty} outside of [{min}, {max}]")]
+    QtyOutOfRange {
+        qty: Decimal,
+        min: Decimal,
+        max: Decimal,
+    },
+    #[error("quantity {qty} is not a multiple of the step size {step_size}")]
+    QtyNotOnStep { qty: Decimal, step_size: Decimal },
+    #[error("notional {notional} below the minimum {min}")]
+    NotionalTooLow { notional: Decimal, min: Decimal },
+    #[error("notional {notional} above the maximum {max}")]
+    NotionalTooHigh { notional: Decimal, max: Decimal },
+    #[error("price {price} outside of the band [{low}, {high}] around the reference price")]
+    PriceOutOfBand {
+        price: Decimal,
+        low: Decimal,
+        high: Decimal,
+    },
+}
+
+/// Filter value of a symbol that is not a decimal
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("invalid filter value {value:?} of {symbol}: {source}")]
+pub struct InvalidFilter {
+    pub symbol: String,
+    pub value: String,
+    pub source: rust_decimal::Error,
+}
+
+/// Price of a symbol, such as the last or the mark price, that market order notionals
+/// and percent price bands are checked against
+pub type ReferencePrice = Arc<dyn Fn(&str) -> Option<Decimal> + Send + Sync>;
+
+/// Increment and bounds of a price or a quantity, a zero bound or increment is not checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Increment {
+    pub min: Decimal,
+    pub max: Decimal,
+    pub step: Decimal,
+}
+
+impl Increment {
+    fn new(min: Decimal, max: Decimal, step: Decimal) -> Self {
+        Increment { min, max, step }
+    }
+
+    /// Round `value` to `min` plus a multiple of `step`
+    fn round(&self, value: Decimal, rounding: Rounding) -> Decimal {
+        if self.step.is_zero() {
+            return value;
+        }
+        let steps = ((value - self.min) / self.step).round_dp_with_strategy(0, rounding.strategy());
+        (self.min + steps * self.step).normalize()
+    }
+
+    fn is_on_step(&self, value: Decimal) -> bool {
+        self.step.is_zero() || ((value - self.min) % self.step).is_zero()
+    }
+
+    fn in_range(&self, value: Decimal) -> bool {
+        value >= self.min && (self.max.is_zero() || value <= self.max)
+    }
+}
+
+/// Multipliers of the reference price bounding the order price, by side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PriceBand {
+    pub bid_up: Decimal,
+    pub bid_down: Decimal,
+    pub ask_up: Decimal,
+    pub ask_down: Decimal,
+}
+
+/// The filters of one symbol that can be checked before sending an order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolFilters {
+    pub price: Increment,
+    pub qty: Increment,
+    /// Market order quantities, the limit quantity filter when missing
+    pub market_qty: Option<Increment>,
+    pub min_notional: Decimal,
+    /// Zero when there is no maximum
+    pub max_notional: Decimal,
+    pub apply_min_to_market: bool,
+    pub apply_max_to_market: bool,
+    pub price_band: Option<PriceBand>,
+}
+
+fn parse_value(symbol: &str, s: &str) -> Result<Decimal, InvalidFilter> {
+    Decimal::from_str(s)
+        .map(|d| d.normalize())
+        .map_err(|source| InvalidFilter {
+            symbol: symbol.to_string(),
+            value: s.to_string(),
+            source,
+        })
+}
+
+impl TryFrom<&Symbol> for SymbolFilters {
+    type Error = InvalidFilter;
+
+    fn try_from(symbol: &Symbol) -> Result<Self, InvalidFilter> {
+        let parse = |s: &str| parse_value(&symbol.symbol, s);
+        let mut filters = SymbolFilters::default();
+        for filter in &symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    filters.price = Increment::new(
+                        min_price.normalize(),
+                        max_price.normalize(),
+                        parse(tick_size)?,
+                    )
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.qty =
+                        Increment::new(min_qty.normalize(), max_qty.normalize(), parse(step_size)?)
+                }
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.market_qty = Some(Increment::new(
+                        parse(min_qty)?,
+                        parse(max_qty)?,
+                        parse(step_size)?,
+                    ))
+                }
+                Filters::Notional {
+                    min_notional,
+                    apply_min_to_market,
+                    max_notional,
+                    apply_max_to_market,
+                    ..
+                } => {
+                    filters.min_notional = min_notional.normalize();
+                    filters.max_notional = max_notional.normalize();
+                    filters.apply_min_to_market = *apply_min_to_market;
+                    filters.apply_max_to_market = *apply_max_to_market;
+                }
+                Filters::PercentPriceBySide {
+                    bid_multiplier_up,
+                    bid_multiplier_down,
+                    ask_multiplier_up,
+                    ask_multiplier_down,
+                    ..
+                } => {
+                    filters.price_band = Some(PriceBand {
+                        bid_up: *bid_multiplier_up,
+                        bid_down: *bid_multiplier_down,
+                        ask_up: *ask_multiplier_up,
+                        ask_down: *ask_multiplier_down,
+                    })
+                }
+                _ => {}
+            }
+        }
+        Ok(filters)
+    }
+}
+
+impl TryFrom<&futures::Symbol> for SymbolFilters {
+    type Error = InvalidFilter;
+
+    fn try_from(symbol: &futures::Symbol) -> Result<Self, InvalidFilter> {
+        let parse = |s: &str| parse_value(&symbol.symbol, s);
+        let mut filters = SymbolFilters {
+            apply_min_to_market: true,
+            ..Default::default()
+        };
+        for filter in &symbol.filters {
+            match filter {
+                futures::Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    filters.price =
+                        Increment::new(parse(min_price)?, parse(max_price)?, parse(tick_size)?)
+                }
+                futures::Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.qty =
+                        Increment::new(parse(min_qty)?, parse(max_qty)?, parse(step_size)?)
+                }
+                futures::Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    filters.market_qty = Some(Increment::new(
+                        parse(min_qty)?,
+                        parse(max_qty)?,
+                        parse(step_size)?,
+                    ))
+                }
+                futures::Filters::MinNotional { notional } => {
+                    filters.min_notional = notional
+                        .as_deref()
+                        .map(parse)
+                        .transpose()?
+                        .unwrap_or_default()
+                }
+                futures::Filters::PercentPrice {
+                    multiplier_up,
+                    multiplier_down,
+                    ..
+                } => {
+                    let (up, down) = (parse(multiplier_up)?, parse(multiplier_down)?);
+                    filters.price_band = Some(PriceBand {
+                        bid_up: up,
+                        bid_down: down,
+                        ask_up: up,
+                        ask_down: down,
+                    })
+                }
+                _ => {}
+            }
+        }
+        Ok(filters)
+    }
+}
+
+impl SymbolFilters {
+    /// Round `price` to the tick size
+    pub fn round_price(&self, price: Decimal, rounding: Rounding) -> Decimal {
+        self.price.round(price, rounding)
+    }
+
+    /// Round `qty` to the step size of limit orders, or of market orders when `market`
+    pub fn round_qty(&self, qty: Decimal, market: bool, rounding: Rounding) -> Decimal {
+        self.qty_filter(market).round(qty, rounding)
+    }
+
+    fn qty_filter(&self, market: bool) -> &Increment {
+        match self.market_qty.as_ref() {
+            Some(market_qty) if market && !market_qty.step.is_zero() => market_qty,
+            _ => &self.qty,
+        }
+    }
+
+    /// Check that `price`, such as a stop price, is within bounds and on a tick
+    pub fn check_price(&self, price: Decimal) -> Result<(), FilterViolation> {
+        if !self.price.in_range(price) {
+            return Err(FilterViolation::PriceOutOfRange {
+                price,
+                min: self.price.min,
+                max: self.price.max,
+            });
+        }
+        if !self.price.is_on_step(price) {
+            return Err(FilterViolation::PriceNotOnTick {
+                price,
+                tick_size: self.price.step,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check an order of `qty` at `price`, or a market order when `price` is None.
+    /// The notional of market orders and the percent price band are only checked
+    /// against a `reference_price`, such as the last or the mark price.
+    pub fn check(
+        &self,
+        buy: bool,
+        price: Option<Decimal>,
+        qty: Decimal,
+        reference_price: Option<Decimal>,
+    ) -> Result<(), FilterViolation> {
+        if let Some(price) = price {
+            self.check_price(price)?;
+        }
+
+        let qty_filter = self.qty_filter(price.is_none());
+        if !qty_filter.in_range(qty) {
+            return Err(FilterViolation::QtyOutOfRange {
+                qty,
+                min: qty_filter.min,
+                max: qty_filter.max,
+            });
+        }
+        if !qty_filter.is_on_step(qty) {
+            return Err(FilterViolation::QtyNotOnStep {
+                qty,
+                step_size: qty_filter.step,
+            });
+        }
+
+        let market = price.is_none();
+        if let Some(notional) = price.or(reference_price).map(|p| p * qty) {
+            if (!market || self.apply_min_to_market) && notional < self.min_notional {
+                return Err(FilterViolation::NotionalTooLow {
+                    notional,
+                    min: self.min_notional,
+                });
+            }
+            if (!market || self.apply_max_to_market)
+                && !self.max_notional.is_zero()
+                && notional > self.max_notional
+            {
+                return Err(FilterViolation::NotionalTooHigh {
+                    notional,
+                    max: self.max_notional,
+                });
+            }
+        }
+
+        if let (Some(price), Some(reference), Some(band)) =
+            (price, reference_price, self.price_band)
+        {
+            let (up, down) = if buy {
+                (band.bid_up, band.bid_down)
+            } else {
+                (band.ask_up, band.ask_down)
+            };
+            let (low, high) = (reference * down, reference * up);
+            if price < low || price > high {
+                return Err(FilterViolation::PriceOutOfBand { price, low, high });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::rest_model::ExchangeInformation;
+    use rust_decimal_macros::dec;
+    use std::path::PathBuf;
+
+    fn symbol(name: &str) -> Symbol {
+        let mut d = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        d.push("test_data/exchangeInfo.json");
+        let info: ExchangeInformation =
+            serde_json::from_str(&std::fs::read_to_string(d).unwrap()).unwrap();
+        info.symbols.into_iter().find(|s| s.symbol == name).unwrap()
+    }
+
+    fn symbol_filters(name: &str) -> SymbolFilters {
+        SymbolFilters::try_from(&symbol(name)).unwrap()
+    }
+
+    #[test]
+    fn invalid_filter_values_are_errors() {
+        let mut symbol = symbol("BTCUSDT");
+        for filter in &mut symbol.filters {
+            if let Filters::PriceFilter { tick_size, .. } = filter {
+                *tick_size = "0.01x".into();
+            }
+        }
+        let e = SymbolFilters::try_from(&symbol).unwrap_err();
+        assert_eq!(e.symbol, "BTCUSDT");
+        assert_eq!(e.value, "0.01x");
+    }
+
+    #[test]
+    fn round_and_check_spot_orders() {
+        let filters = symbol_filters("BTCUSDT");
+        let tick = filters.price.step;
+        let step = filters.qty.step;
+        assert!(!tick.is_zero() && !step.is_zero());
+
+        let price = dec!(30000) + tick / dec!(3);
+        let down = filters.round_price(price, Rounding::Down);
+        let up = filters.round_price(price, Rounding::Up);
+        assert_eq!(up - down, tick);
+        assert!(filters
+            .check(true, Some(down), step * dec!(1000), None)
+            .is_ok());
+        assert!(matches!(
+            filters.check(true, Some(price), step * dec!(1000), None),
+            Err(FilterViolation::PriceNotOnTick { .. })
+        ));
+
+        let qty = filters.round_qty(step * dec!(1000.5), false, Rounding::Down);
+        assert!(filters.check(false, Some(down), qty, None).is_ok());
+        assert!(matches!(
+            filters.check(false, Some(down), qty + step / dec!(2), None),
+            Err(FilterViolation::QtyNotOnStep { .. })
+        ));
+    }
+
+    #[test]
+    fn notional_and_price_band() {
+        let filters = SymbolFilters {
+            price: Increment::new(dec!(0.01), dec!(1000000), dec!(0.01)),
+            qty: Increment::new(dec!(0.00001), dec!(9000), dec!(0.00001)),
+            min_notional: dec!(5),
+            max_notional: dec!(9000000),
+            apply_min_to_market: true,
+            price_band: Some(PriceBand {
+                bid_up: dec!(5),
+                bid_down: dec!(0.2),
+                ask_up: dec!(5),
+                ask_down: dec!(0.2),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            filters.check(true, Some(dec!(100)), dec!(0.01), None),
+            Err(FilterViolation::NotionalTooLow {
+                notional: dec!(1),
+                min: dec!(5)
+            })
+        );
+        assert!(filters.check(true, None, dec!(0.01), None).is_ok());
+        assert!(filters
+            .check(true, None, dec!(0.01), Some(dec!(100)))
+            .is_err());
+        assert!(matches!(
+            filters.check(false, Some(dec!(10)), dec!(1), Some(dec!(100))),
+            Err(FilterViolation::PriceOutOfBand { .. })
+        ));
+        assert!(filters
+            .check(false, Some(dec!(25)), dec!(1), Some(dec!(100)))
+            .is_ok());
+        assert_eq!(
+            filters.round_price(dec!(0.304), Rounding::Nearest),
+            dec!(0.3)
+        );
+    }
+}
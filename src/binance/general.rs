@@ -12,8 +12,8 @@ pub struct General {
 impl General {
     /// Test connectivity
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, general::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, general::*, config::*};
     /// let general: General = Binance::new_with_env(&Config::default());
     /// let pong = tokio_test::block_on(general.ping());
     /// assert!(pong.is_ok(), "{:?}", pong);
@@ -27,8 +27,8 @@ impl General {
 
     /// Check server time
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, general::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, general::*, config::*};
     /// let general: General = Binance::new_with_env(&Config::default());
     /// let server_time = tokio_test::block_on(general.get_server_time());
     /// assert!(server_time.is_ok(), "{:?}", server_time);
@@ -43,8 +43,8 @@ impl General {
 
     /// Obtain exchange information (rate limits, symbol metadata etc)
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, general::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, general::*, config::*};
     /// let general: General = Binance::new_with_env(&Config::default());
     /// let excyahge_info = tokio_test::block_on(general.exchange_info());
     /// assert!(excyahge_info.is_ok(), "{:?}", excyahge_info);
@@ -1,3 +1,4 @@
+use rust_decimal::Decimal;
 use serde_json::from_str;
 
 use super::client::*;
@@ -45,9 +46,10 @@ impl Margin {
     /// Execute transfer between spot account and margin account.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.transfer("BTCUSDT", 0.001, MarginTransferType::FromMainToMargin));
+    /// let transaction_id = tokio_test::block_on(margin.transfer("BTCUSDT", dec!(0.001), MarginTransferType::FromMainToMargin));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn transfer<S, F>(
@@ -58,7 +60,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let transfer: Transfer = Transfer {
             asset: symbol.into(),
@@ -73,9 +75,10 @@ impl Margin {
     /// Execute transfer between spot account and isolated margin account.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.isolated_transfer("BTC", "BTC", 0.001, IsolatedMarginTransferType::Spot, IsolatedMarginTransferType::IsolatedMargin));
+    /// let transaction_id = tokio_test::block_on(margin.isolated_transfer("BTC", "BTC", dec!(0.001), IsolatedMarginTransferType::Spot, IsolatedMarginTransferType::IsolatedMargin));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn isolated_transfer<S, F>(
@@ -88,7 +91,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let transfer = IsolatedTransfer {
             asset: asset_symbol.into(),
@@ -105,15 +108,16 @@ impl Margin {
     /// Apply for a loan.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.loan("BTCUSDT", 0.001));
+    /// let transaction_id = tokio_test::block_on(margin.loan("BTCUSDT", dec!(0.001)));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn loan<S, F>(&self, symbol: S, qty: F) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         self.loan_with_isolation(symbol, qty, None, None).await
     }
@@ -121,9 +125,10 @@ impl Margin {
     /// Apply for an isolated loan.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.loan_with_isolation("BTCUSDT", 0.001, Some(true), Some("BNB".to_string())));
+    /// let transaction_id = tokio_test::block_on(margin.loan_with_isolation("BTCUSDT", dec!(0.001), Some(true), Some("BNB".to_string())));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn loan_with_isolation<S, F>(
@@ -135,7 +140,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let loan: Loan = Loan {
             asset: symbol.into(),
@@ -150,15 +155,16 @@ impl Margin {
 
     /// Repay loan for margin account.
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.repay("BTCUSDT", 0.001));
+    /// let transaction_id = tokio_test::block_on(margin.repay("BTCUSDT", dec!(0.001)));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn repay<S, F>(&self, symbol: S, qty: F) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         self.repay_with_isolation(symbol, qty, None, None).await
     }
@@ -166,9 +172,10 @@ impl Margin {
     /// Apply for an isolated loan.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
-    /// let transaction_id = tokio_test::block_on(margin.repay_with_isolation("BTCUSDT", 0.001, Some(true), Some("BNB".to_string())));
+    /// let transaction_id = tokio_test::block_on(margin.repay_with_isolation("BTCUSDT", dec!(0.001), Some(true), Some("BNB".to_string())));
     /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
     /// ```
     pub async fn repay_with_isolation<S, F>(
@@ -180,7 +187,7 @@ impl Margin {
     ) -> Result<TransactionId>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
         let loan: Loan = Loan {
             asset: symbol.into(),
@@ -196,20 +203,21 @@ impl Margin {
     /// Post a new order for margin account.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let margin_order = MarginOrder {
     ///     symbol: "BTCUSDT".to_string(),
     ///     side: OrderSide::Sell,
     ///     order_type: OrderType::Limit,
-    ///     quantity: Some(0.001),
+    ///     quantity: Some(dec!(0.001)),
     ///     quote_order_qty: None,
-    ///     price: Some(10.0),
-    ///     stop_price: Some(10.0),
+    ///     price: Some(dec!(10)),
+    ///     stop_price: Some(dec!(10)),
     ///     new_client_order_id: Some("my_id".to_string()),
-    ///     iceberg_qty: Some(10.0),
+    ///     iceberg_qty: Some(dec!(10)),
     ///     new_order_resp_type: OrderResponse::Ack,
-    ///     time_in_force: TimeInForce::FOK,
+    ///     time_in_force: Some(TimeInForce::FOK),
     ///     side_effect_type: SideEffectType::NoSideEffect,
     ///     is_isolated: None,
     /// };
@@ -225,20 +233,21 @@ impl Margin {
     /// Post a new order for margin account.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let margin_order = MarginOrder {
     ///     symbol: "BTCUSDT".to_string(),
     ///     side: OrderSide::Sell,
     ///     order_type: OrderType::Limit,
-    ///     quantity: Some(0.001),
+    ///     quantity: Some(dec!(0.001)),
     ///     quote_order_qty: None,
-    ///     price: Some(10.0),
-    ///     stop_price: Some(10.0),
+    ///     price: Some(dec!(10)),
+    ///     stop_price: Some(dec!(10)),
     ///     new_client_order_id: Some("my_id".to_string()),
-    ///     iceberg_qty: Some(10.0),
+    ///     iceberg_qty: Some(dec!(10)),
     ///     new_order_resp_type: OrderResponse::Ack,
-    ///     time_in_force: TimeInForce::FOK,
+    ///     time_in_force: Some(TimeInForce::FOK),
     ///     side_effect_type: SideEffectType::NoSideEffect,
     ///     is_isolated: None,
     /// };
@@ -252,14 +261,15 @@ impl Margin {
     /// Post a new order for margin account.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use rust_decimal_macros::dec;
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let margin_order = MarginOCOOrder {
     ///     symbol: "BTCUSDT".to_string(),
     ///     side: OrderSide::Sell,
-    ///     quantity: 10.0,
-    ///     price: 10.0,
-    ///     stop_price: 1.0,
+    ///     quantity: dec!(10),
+    ///     price: dec!(10),
+    ///     stop_price: dec!(1),
     ///     ..MarginOCOOrder::default()
     /// };
     /// let transaction_id = tokio_test::block_on(margin.new_oco_order(margin_order));
@@ -277,7 +287,7 @@ impl Margin {
     /// Cancel an existing order
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let result = tokio_test::block_on(margin.cancel_trade("BTCUSDT", 1_u64, "my_id".to_string(), "my_next_id".to_string(), None));
     /// assert!(result.is_ok(), "{:?}", result);
@@ -313,7 +323,7 @@ impl Margin {
     /// Cancel an existing order
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let result = tokio_test::block_on(margin.cancel_oco_order("BTCUSDT", 1_u64, "my_id".to_string(), "my_next_id".to_string(), None));
     /// assert!(result.is_ok(), "{:?}", result);
@@ -349,7 +359,7 @@ impl Margin {
     /// Cancel all existing orders for a symbol
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let result = tokio_test::block_on(margin.cancel_all_orders("BTCUSDT", None));
     /// assert!(result.is_ok(), "{:?}", result);
@@ -378,7 +388,7 @@ impl Margin {
     /// Get existing loan records
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let loan_query = RecordsQuery {
     ///    asset: "BTC".to_string(),
@@ -397,7 +407,7 @@ impl Margin {
     /// Get existing repay records history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = RecordsQuery {
     ///    asset: "BTC".to_string(),
@@ -419,7 +429,7 @@ impl Margin {
     /// Get margin account details
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let account_details = tokio_test::block_on(margin.details());
     /// assert!(account_details.is_ok(), "{:?}", account_details);
@@ -434,7 +444,7 @@ impl Margin {
     /// Get isolated margin account details
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let account_details = tokio_test::block_on(margin.isolated_details(None));
     /// assert!(account_details.is_ok(), "{:?}", account_details);
@@ -454,7 +464,7 @@ impl Margin {
     /// Disable isolated margin account
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let account_details = tokio_test::block_on(margin.disable_isolated("BTCUSDT".to_string()));
     /// assert!(account_details.is_ok(), "{:?}", account_details);
@@ -469,7 +479,7 @@ impl Margin {
     /// Enable isolated margin account
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let account_details = tokio_test::block_on(margin.enable_isolated("BTCUSDT".to_string()));
     /// assert!(account_details.is_ok(), "{:?}", account_details);
@@ -484,7 +494,7 @@ impl Margin {
     /// Get margin pair market data
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let pair_details = tokio_test::block_on(margin.isolated_pair("BTCUSDT"));
     /// assert!(pair_details.is_ok(), "{:?}", pair_details);
@@ -507,7 +517,7 @@ impl Margin {
     /// Get all isolated pair details
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let all_pairs = tokio_test::block_on(margin.all_pairs());
     /// assert!(all_pairs.is_ok(), "{:?}", all_pairs);
@@ -522,7 +532,7 @@ impl Margin {
     /// Toggle BNB Burn on Spot Trade and Margin Interest
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let all_pairs = tokio_test::block_on(margin.toggle_bnb_burn(BnbBurnQuery::default()));
     /// assert!(all_pairs.is_ok(), "{:?}", all_pairs);
@@ -536,7 +546,7 @@ impl Margin {
     /// Query BNB Burn on Spot Trade and Margin Interest
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let all_pairs = tokio_test::block_on(margin.bnb_burn_status());
     /// assert!(all_pairs.is_ok(), "{:?}", all_pairs);
@@ -551,7 +561,7 @@ impl Margin {
     /// Query Interest rate history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let all_pairs = tokio_test::block_on(margin.interest_rate_history(InterestRateHistoryQuery::default()));
     /// assert!(all_pairs.is_ok(), "{:?}", all_pairs);
@@ -572,7 +582,7 @@ impl Margin {
     /// Get asset details
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let asset_detail = tokio_test::block_on(margin.asset("BTC"));
     /// assert!(asset_detail.is_ok(), "{:?}", asset_detail);
@@ -595,7 +605,7 @@ impl Margin {
     /// Get margin pair market data
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let pair_details = tokio_test::block_on(margin.pair("BTCUSDT"));
     /// assert!(pair_details.is_ok(), "{:?}", pair_details);
@@ -618,7 +628,7 @@ impl Margin {
     /// Get all assets details
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let all_assets = tokio_test::block_on(margin.all_assets());
     /// assert!(all_assets.is_ok(), "{:?}", all_assets);
@@ -633,7 +643,7 @@ impl Margin {
     /// Get all pair details
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let all_pairs = tokio_test::block_on(margin.all_pairs());
     /// assert!(all_pairs.is_ok(), "{:?}", all_pairs);
@@ -648,7 +658,7 @@ impl Margin {
     /// Get price index
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let price_index = tokio_test::block_on(margin.price_index("BTCUSDT"));
     /// assert!(price_index.is_ok(), "{:?}", price_index);
@@ -671,7 +681,7 @@ impl Margin {
     /// Get transfer history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = RecordsQuery {
     ///    asset: "BTC".to_string(),
@@ -697,7 +707,7 @@ impl Margin {
     /// Get isolated transfer history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = IsolatedTransfersQuery {
     ///    symbol: "BTC".to_string(),
@@ -722,7 +732,7 @@ impl Margin {
     /// Get interest history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = RecordsQuery {
     ///    asset: "BTC".to_string(),
@@ -748,7 +758,7 @@ impl Margin {
     /// Get forced liquidation history
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = RecordsQuery {
     ///    asset: "BTC".to_string(),
@@ -774,7 +784,7 @@ impl Margin {
     /// Get an existing order state
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = MarginOrderQuery {
     ///     symbol: "BTCUSDT".to_string(),
@@ -794,7 +804,7 @@ impl Margin {
     /// Get open orders
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let order_state = tokio_test::block_on(margin.open_orders("BTCUSDT", None));
     /// assert!(order_state.is_ok(), "{:?}", order_state);
@@ -822,7 +832,7 @@ impl Margin {
     /// Get all orders
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = MarginOrdersQuery {
     ///    symbol: "BTCUSDT".to_string(),
@@ -847,7 +857,7 @@ impl Margin {
     /// Get all trades
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = MarginOwnTradesQuery {
     ///    symbol: "BTCUSDT".to_string(),
@@ -872,7 +882,7 @@ impl Margin {
     /// Get an existing oco order state
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = MarginOCOOrderQuery {
     ///     symbol: Some("BTCUSDT".to_string()),
@@ -890,7 +900,7 @@ impl Margin {
     /// Query all OCO Orders
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = OCORecordsQuery {
     ///     symbol: Some("BTCUSDT".to_string()),
@@ -915,7 +925,7 @@ impl Margin {
     /// Query open OCO Orders
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let records_query = MarginPairQuery {
     ///     symbol: "BTCUSDT".to_string(),
@@ -940,7 +950,7 @@ impl Margin {
     /// Get max borrowable
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let max = tokio_test::block_on(margin.max_borrowable("BTC", None));
     /// assert!(max.is_ok(), "{:?}", max);
@@ -968,7 +978,7 @@ impl Margin {
     /// Get max transferable
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*, rest_model::*};
+    /// use exrs::binance::{api::*, margin::*, config::*, rest_model::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let max = tokio_test::block_on(margin.max_transferable("BTC", None));
     /// assert!(max.is_ok(), "{:?}", max);
@@ -996,7 +1006,7 @@ impl Margin {
     /// Start user data stream
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*};
+    /// use exrs::binance::{api::*, margin::*, config::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let start = tokio_test::block_on(margin.start());
     /// assert!(start.is_ok(), "{:?}", start);
@@ -1012,7 +1022,7 @@ impl Margin {
     /// Current open orders on a symbol
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*};
+    /// use exrs::binance::{api::*, margin::*, config::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let start = tokio_test::block_on(margin.start());
     /// assert!(start.is_ok(), "{:?}", start);
@@ -1030,7 +1040,7 @@ impl Margin {
     /// Close the user stream
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, margin::*, config::*};
+    /// use exrs::binance::{api::*, margin::*, config::*};
     /// let margin: Margin = Binance::new_with_env(&Config::testnet());
     /// let start = tokio_test::block_on(margin.start());
     /// assert!(start.is_ok(), "{:?}", start);
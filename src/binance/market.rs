@@ -2,24 +2,72 @@ use super::client::*;
 use super::errors::*;
 use super::rest_model::*;
 use super::util::*;
+use futures::stream::{self, Stream, TryStreamExt};
 use serde_json::{from_str, Value};
 use std::collections::BTreeMap;
+use std::sync::Arc;
 
 static API_V3_DEPTH: &str = "/api/v3/depth";
+static API_V3_TRADES: &str = "/api/v3/trades";
+static API_V3_HISTORICAL_TRADES: &str = "/api/v3/historicalTrades";
+static API_V3_AGG_TRADES: &str = "/api/v3/aggTrades";
 static API_V3_TICKER_PRICE: &str = "/api/v3/ticker/price";
 static API_V3_AVG_PRICE: &str = "/api/v3/avgPrice";
 static API_V3_BOOK_TICKER: &str = "/api/v3/ticker/bookTicker";
 static API_V3_24H_TICKER: &str = "/api/v3/ticker/24hr";
+static API_V3_ROLLING_TICKER: &str = "/api/v3/ticker";
+static API_V3_TRADING_DAY_TICKER: &str = "/api/v3/ticker/tradingDay";
 static API_V3_KLINES: &str = "/api/v3/klines";
+static API_V3_UI_KLINES: &str = "/api/v3/uiKlines";
+
+/// Request weight of `/api/v3/klines`
+const KLINES_WEIGHT: u32 = 2;
+const KLINES_MAX_LIMIT: u16 = 1000;
+/// Most symbols accepted by the rolling window and trading day tickers
+const TICKER_MAX_SYMBOLS: usize = 100;
+/// Request weight of `/api/v3/ticker/price` for several symbols, 2 for one
+const PRICES_WEIGHT: u32 = 4;
+/// Request weight of `/api/v3/ticker/bookTicker` for several symbols, 2 for one
+const BOOK_TICKERS_WEIGHT: u32 = 4;
+
+/// Request weight of `/api/v3/ticker/24hr` for a number of symbols, 0 for all symbols
+pub fn price_stats_weight(symbols: usize) -> u32 {
+    match symbols {
+        1..=20 => 2,
+        21..=100 => 40,
+        _ => 80,
+    }
+}
+
+/// Request weight of `/api/v3/ticker` and `/api/v3/ticker/tradingDay` for a number of symbols
+pub fn rolling_ticker_weight(symbols: usize) -> u32 {
+    (4 * symbols as u32).min(200)
+}
 
 #[derive(Clone)]
 pub struct Market {
     pub client: Client,
     pub recv_window: u64,
+    /// Request weight budget of the ticker statistics, shared by the clones of this market
+    pub weight_limiter: Option<Arc<WeightLimiter>>,
 }
 
 // Market Data endpoints
 impl Market {
+    /// Pace the 24hr, rolling window and trading day tickers, and the prices and book tickers
+    /// of several symbols, so that their request weight, see `price_stats_weight` and
+    /// `rolling_ticker_weight`, stays under `weight_per_minute`
+    pub fn set_weight_per_minute(mut self, weight_per_minute: u32) -> Self {
+        self.weight_limiter = Some(Arc::new(WeightLimiter::new(weight_per_minute)));
+        self
+    }
+
+    async fn acquire(&self, weight: u32) {
+        if let Some(limiter) = &self.weight_limiter {
+            limiter.acquire(weight).await;
+        }
+    }
+
     fn symbol_request<S>(&self, symbol: S) -> String
     where
         S: Into<String>,
@@ -30,10 +78,26 @@ impl Market {
         build_request(&parameters)
     }
 
+    /// `symbols=["A","B"]`, url encoded
+    fn symbols_request<S>(&self, symbols: &[S], mut parameters: BTreeMap<String, String>) -> String
+    where
+        S: AsRef<str>,
+    {
+        let symbols = symbols
+            .iter()
+            .map(|symbol| format!("\"{}\"", symbol.as_ref()))
+            .collect::<Vec<String>>()
+            .join(",");
+        let symbols: String =
+            url::form_urlencoded::byte_serialize(format!("[{}]", symbols).as_bytes()).collect();
+        parameters.insert("symbols".into(), symbols);
+        build_request(&parameters)
+    }
+
     /// Order book (Default 100; max 5000)
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let orderbook = tokio_test::block_on(market.get_depth("BTCUSDT".to_string()));
     /// assert!(orderbook.is_ok(), "{:?}", orderbook);
@@ -52,8 +116,8 @@ impl Market {
     /// Order book with a custom depth limit
     /// Supported limits are: 5, 10, 20, 50, 100, 500, 1000, 5000
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let orderbook = tokio_test::block_on(market.get_custom_depth("BTCUSDT".to_string(), 50));
     /// assert!(orderbook.is_ok(), "{:?}", orderbook);
@@ -75,10 +139,117 @@ impl Market {
         Ok(order_book)
     }
 
+    /// Recent trades, up to 'limit' (default 500; max 1000)
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = tokio_test::block_on(market.get_trades("BTCUSDT", None));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(&parameters);
+        let data = self.client.get(API_V3_TRADES, &request).await?;
+        let trades: Vec<Trade> = from_str(data.as_str())?;
+
+        Ok(trades)
+    }
+
+    /// Older trades, up to 'limit' (default 500; max 1000) starting at trade id 'from_id'
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = tokio_test::block_on(market.get_historical_trades("BTCUSDT", 1_000_000, 1000));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), fi.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(&parameters);
+        let data = self.client.get(API_V3_HISTORICAL_TRADES, &request).await?;
+        let trades: Vec<Trade> = from_str(data.as_str())?;
+
+        Ok(trades)
+    }
+
+    /// Compressed/Aggregate trades, up to 'limit' (default 500; max 1000)
+    /// starting at aggregate id 'from_id' or within a time window of at most one hour
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = tokio_test::block_on(market.get_agg_trades("BTCUSDT", None, None, None, 500));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        start_time: S3,
+        end_time: S4,
+        limit: S5,
+    ) -> Result<Vec<AggTrade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), fi.to_string());
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), st.to_string());
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), et.to_string());
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), lt.to_string());
+        }
+
+        let request = build_request(&parameters);
+        let data = self.client.get(API_V3_AGG_TRADES, &request).await?;
+        let trades: Vec<AggTrade> = from_str(data.as_str())?;
+
+        Ok(trades)
+    }
+
     /// Latest price for ALL symbols.
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let prices = tokio_test::block_on(market.get_all_prices());
     /// assert!(prices.is_ok(), "{:?}", prices);
@@ -93,8 +264,8 @@ impl Market {
 
     /// Latest price for ONE symbol.
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let price = tokio_test::block_on(market.get_price("BTCUSDT"));
     /// assert!(price.is_ok(), "{:?}", price);
@@ -112,8 +283,8 @@ impl Market {
 
     /// Average price for ONE symbol.
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let avg_price = tokio_test::block_on(market.get_average_price("BTCUSDT"));
     /// assert!(avg_price.is_ok(), "{:?}", avg_price);
@@ -132,8 +303,8 @@ impl Market {
     /// Symbols order book ticker
     /// -> Best price/qty on the order book for ALL symbols.
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let tickers = tokio_test::block_on(market.get_all_book_tickers());
     /// assert!(tickers.is_ok(), "{:?}", tickers);
@@ -148,8 +319,8 @@ impl Market {
 
     /// -> Best price/qty on the order book for ONE symbol
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let tickers = tokio_test::block_on(market.get_book_ticker("BTCUSDT"));
     /// assert!(tickers.is_ok(), "{:?}", tickers);
@@ -167,8 +338,8 @@ impl Market {
 
     /// 24hr ticker price change statistics
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
     /// let market: Market = Binance::new_with_env(&Config::default());
     /// let price_stats = tokio_test::block_on(market.get_24h_price_stats("BTCUSDT"));
     /// assert!(price_stats.is_ok(), "{:?}", price_stats);
@@ -178,6 +349,7 @@ impl Market {
         S: Into<String>,
     {
         let request = self.symbol_request(symbol);
+        self.acquire(price_stats_weight(1)).await;
         let data = self.client.get(API_V3_24H_TICKER, &request).await?;
 
         let stats: PriceStats = from_str(data.as_str())?;
@@ -185,34 +357,294 @@ impl Market {
         Ok(stats)
     }
 
-    /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    /// 24hr ticker price change statistics for ALL symbols
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let price_stats = tokio_test::block_on(market.get_all_24h_price_stats());
+    /// assert!(price_stats.is_ok(), "{:?}", price_stats);
+    /// ```
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.acquire(price_stats_weight(0)).await;
+        let data = self.client.get(API_V3_24H_TICKER, "").await?;
+        let stats: Vec<PriceStats> = from_str(data.as_str())?;
+
+        Ok(stats)
+    }
+
+    /// 24hr ticker price change statistics for several symbols.
+    /// Past 100 symbols fetching all symbols costs the same weight, so it does that and filters.
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let price_stats = tokio_test::block_on(market.get_24h_price_stats_for(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(price_stats.is_ok(), "{:?}", price_stats);
+    /// ```
+    pub async fn get_24h_price_stats_for<S>(&self, symbols: &[S]) -> Result<Vec<PriceStats>>
+    where
+        S: AsRef<str>,
+    {
+        if symbols.is_empty() {
+            return Ok(Vec::new());
+        }
+        if symbols.len() > TICKER_MAX_SYMBOLS {
+            let all = self.get_all_24h_price_stats().await?;
+            return Ok(all
+                .into_iter()
+                .filter(|stats| symbols.iter().any(|symbol| symbol.as_ref() == stats.symbol))
+                .collect());
+        }
+        let request = self.symbols_request(symbols, BTreeMap::new());
+        self.acquire(price_stats_weight(symbols.len())).await;
+        let data = self.client.get(API_V3_24H_TICKER, &request).await?;
+        let stats: Vec<PriceStats> = from_str(data.as_str())?;
+
+        Ok(stats)
+    }
+
+    /// Latest price for several symbols
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let prices = tokio_test::block_on(market.get_prices_for(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(prices.is_ok(), "{:?}", prices);
+    /// ```
+    pub async fn get_prices_for<S>(&self, symbols: &[S]) -> Result<Vec<SymbolPrice>>
+    where
+        S: AsRef<str>,
+    {
+        if symbols.is_empty() {
+            return Ok(Vec::new());
+        }
+        let request = self.symbols_request(symbols, BTreeMap::new());
+        self.acquire(PRICES_WEIGHT).await;
+        let data = self.client.get(API_V3_TICKER_PRICE, &request).await?;
+        let prices: Vec<SymbolPrice> = from_str(data.as_str())?;
+
+        Ok(prices)
+    }
+
+    /// Best price/qty on the order book for several symbols
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let tickers = tokio_test::block_on(market.get_book_tickers_for(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(tickers.is_ok(), "{:?}", tickers);
+    /// ```
+    pub async fn get_book_tickers_for<S>(&self, symbols: &[S]) -> Result<Vec<Tickers>>
+    where
+        S: AsRef<str>,
+    {
+        if symbols.is_empty() {
+            return Ok(Vec::new());
+        }
+        let request = self.symbols_request(symbols, BTreeMap::new());
+        self.acquire(BOOK_TICKERS_WEIGHT).await;
+        let data = self.client.get(API_V3_BOOK_TICKER, &request).await?;
+        let tickers: Vec<Tickers> = from_str(data.as_str())?;
+
+        Ok(tickers)
+    }
+
+    /// Price change statistics over a rolling window ending now
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*, rest_model::TickerWindow};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let ticker = tokio_test::block_on(market.get_rolling_ticker("BTCUSDT", TickerWindow::Hours(4)));
+    /// assert!(ticker.is_ok(), "{:?}", ticker);
+    /// ```
+    pub async fn get_rolling_ticker<S>(
+        &self,
+        symbol: S,
+        window: TickerWindow,
+    ) -> Result<TickerStatistics>
+    where
+        S: Into<String>,
+    {
+        window.validate()?;
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("windowSize".into(), window.to_string());
+
+        let request = build_request(&parameters);
+        self.acquire(rolling_ticker_weight(1)).await;
+        let data = self.client.get(API_V3_ROLLING_TICKER, &request).await?;
+        let ticker: TickerStatistics = from_str(data.as_str())?;
+
+        Ok(ticker)
+    }
+
+    /// Rolling window statistics for several symbols, 100 symbols per request,
+    /// see `rolling_ticker_weight` for the cost
+    pub async fn get_rolling_tickers<S>(
+        &self,
+        symbols: &[S],
+        window: TickerWindow,
+    ) -> Result<Vec<TickerStatistics>>
+    where
+        S: AsRef<str>,
+    {
+        window.validate()?;
+        let mut tickers = Vec::with_capacity(symbols.len());
+        for chunk in symbols.chunks(TICKER_MAX_SYMBOLS) {
+            let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+            parameters.insert("windowSize".into(), window.to_string());
+
+            let request = self.symbols_request(chunk, parameters);
+            self.acquire(rolling_ticker_weight(chunk.len())).await;
+            let data = self.client.get(API_V3_ROLLING_TICKER, &request).await?;
+            let chunk: Vec<TickerStatistics> = from_str(data.as_str())?;
+            tickers.extend(chunk);
+        }
+
+        Ok(tickers)
+    }
+
+    /// Price change statistics of the current trading day.
+    /// `time_zone` is an offset such as "-1:00" or "8", UTC by default
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let ticker = tokio_test::block_on(market.get_trading_day_ticker("BTCUSDT", None));
+    /// assert!(ticker.is_ok(), "{:?}", ticker);
+    /// ```
+    pub async fn get_trading_day_ticker<S1, S2>(
+        &self,
+        symbol: S1,
+        time_zone: S2,
+    ) -> Result<TickerStatistics>
+    where
+        S1: Into<String>,
+        S2: Into<Option<String>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(tz) = time_zone.into() {
+            parameters.insert("timeZone".into(), tz);
+        }
+
+        let request = build_request(&parameters);
+        self.acquire(rolling_ticker_weight(1)).await;
+        let data = self.client.get(API_V3_TRADING_DAY_TICKER, &request).await?;
+        let ticker: TickerStatistics = from_str(data.as_str())?;
+
+        Ok(ticker)
+    }
+
+    /// Trading day statistics for several symbols, 100 symbols per request,
+    /// see `rolling_ticker_weight` for the cost
+    pub async fn get_trading_day_tickers<S1, S2>(
+        &self,
+        symbols: &[S1],
+        time_zone: S2,
+    ) -> Result<Vec<TickerStatistics>>
+    where
+        S1: AsRef<str>,
+        S2: Into<Option<String>>,
+    {
+        let time_zone: Option<String> = time_zone.into();
+        let mut tickers = Vec::with_capacity(symbols.len());
+        for chunk in symbols.chunks(TICKER_MAX_SYMBOLS) {
+            let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+            if let Some(tz) = &time_zone {
+                parameters.insert("timeZone".into(), tz.clone());
+            }
+
+            let request = self.symbols_request(chunk, parameters);
+            self.acquire(rolling_ticker_weight(chunk.len())).await;
+            let data = self.client.get(API_V3_TRADING_DAY_TICKER, &request).await?;
+            let chunk: Vec<TickerStatistics> = from_str(data.as_str())?;
+            tickers.extend(chunk);
+        }
+
+        Ok(tickers)
+    }
+
+    /// Returns up to 'limit' klines for given symbol and interval
     /// https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
     /// # Examples
-    /// ```rust
-    /// use binance::{api::*, market::*, config::*};
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*, rest_model::KlineInterval};
     /// let market: Market = Binance::new_with_env(&Config::default());
-    /// let klines = tokio_test::block_on(market.get_klines("BTCUSDT", "1m", None, None, None));
+    /// let klines = tokio_test::block_on(market.get_klines("BTCUSDT", KlineInterval::Minute1, None, None, None));
     /// assert!(klines.is_ok(), "{:?}", klines);
     /// ```
-    pub async fn get_klines<S1, S2, S3, S4, S5>(
+    pub async fn get_klines<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        interval: S2,
-        limit: S3,
-        start_time: S4,
-        end_time: S5,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
     ) -> Result<KlineSummaries>
     where
         S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<Option<u16>>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+    {
+        self.klines(API_V3_KLINES, symbol, interval, limit, start_time, end_time)
+            .await
+    }
+
+    /// Klines of `get_klines` modified for the presentation of candlestick charts
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*, rest_model::KlineInterval};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let klines = tokio_test::block_on(market.get_ui_klines("BTCUSDT", KlineInterval::Minute1, None, None, None));
+    /// assert!(klines.is_ok(), "{:?}", klines);
+    /// ```
+    pub async fn get_ui_klines<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+    {
+        self.klines(
+            API_V3_UI_KLINES,
+            symbol,
+            interval,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    async fn klines<S1, S2, S3, S4>(
+        &self,
+        endpoint: &str,
+        symbol: S1,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
-        S5: Into<Option<u64>>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
 
         parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("interval".into(), interval.into());
+        parameters.insert("interval".into(), interval.to_string());
 
         // Add three optional parameters
         if let Some(lt) = limit.into() {
@@ -227,27 +659,298 @@ impl Market {
 
         let request = build_request(&parameters);
 
-        let data = self.client.get(API_V3_KLINES, &request).await?;
+        let data = self.client.get(endpoint, &request).await?;
         let parsed_data: Vec<Vec<Value>> = from_str(data.as_str())?;
 
         let klines = KlineSummaries::AllKlineSummaries(
-            parsed_data
+            parsed_data.iter().map(|row| kline_summary(row)).collect(),
+        );
+        Ok(klines)
+    }
+
+    /// Stream every kline of `symbol` opened between `start_time` and `end_time` (ms, inclusive),
+    /// fetching as many pages as needed within the request weight budget of `KlineRange`.
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, market::*, config::*, rest_model::KlineInterval};
+    /// use futures::TryStreamExt;
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let klines = market.klines_range("BTCUSDT", KlineInterval::Minute1, 1_640_995_200_000, 1_643_673_600_000);
+    /// let klines: Vec<_> = tokio_test::block_on(klines.try_collect()).unwrap();
+    /// ```
+    pub fn klines_range<S>(
+        &self,
+        symbol: S,
+        interval: KlineInterval,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<KlineSummary>>
+    where
+        S: Into<String>,
+    {
+        self.klines_range_from(KlineRange::new(symbol, interval, start_time, end_time))
+    }
+
+    /// Stream the klines left in `range`.
+    /// Keep the range up to date with `KlineRange::advance` for each kline received
+    /// to resume an interrupted download from where it stopped.
+    pub fn klines_range_from(&self, range: KlineRange) -> impl Stream<Item = Result<KlineSummary>> {
+        let market = self.clone();
+        let limiter = WeightLimiter::new(range.weight_per_minute);
+        stream::try_unfold(
+            (range, limiter, false),
+            move |(mut range, limiter, done)| {
+                let market = market.clone();
+                async move {
+                    if done || range.is_done() {
+                        return Ok::<_, Error>(None);
+                    }
+                    limiter.acquire(KLINES_WEIGHT).await;
+                    let KlineSummaries::AllKlineSummaries(page) = market
+                        .get_klines(
+                            range.symbol.clone(),
+                            range.interval,
+                            range.limit,
+                            range.pages.start_time,
+                            range.pages.end_time,
+                        )
+                        .await?;
+                    let done = page.len() < range.limit as usize;
+                    let page = range.pages.take_page(page, |kline| kline.open_time);
+                    if page.is_empty() {
+                        return Ok(None);
+                    }
+                    Ok(Some((page, (range, limiter, done))))
+                }
+            },
+        )
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+/// Progress of a `klines_range` download, serializable so that it can be persisted
+/// and passed back to `klines_range_from` after an interruption
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineRange {
+    pub symbol: String,
+    pub interval: KlineInterval,
+    #[serde(flatten)]
+    pub pages: KlinePages,
+    /// Page size, at most 1000
+    pub limit: u16,
+    /// Request weight budget of the download
+    pub weight_per_minute: u32,
+}
+
+impl KlineRange {
+    pub fn new<S>(symbol: S, interval: KlineInterval, start_time: u64, end_time: u64) -> Self
+    where
+        S: Into<String>,
+    {
+        KlineRange {
+            symbol: symbol.into(),
+            interval,
+            pages: KlinePages::new(start_time, end_time),
+            limit: KLINES_MAX_LIMIT,
+            weight_per_minute: 1200,
+        }
+    }
+
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = limit.clamp(1, KLINES_MAX_LIMIT);
+        self
+    }
+
+    pub fn set_weight_per_minute(mut self, weight_per_minute: u32) -> Self {
+        self.weight_per_minute = weight_per_minute;
+        self
+    }
+
+    /// Record the kline opened at `open_time` as received
+    pub fn advance(&mut self, open_time: i64) {
+        self.pages.advance(open_time);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pages.is_done()
+    }
+}
+
+/// Open times left to fetch of a kline download, shared by the spot and futures `KlineRange`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlinePages {
+    /// Earliest open time left to fetch, in ms
+    pub start_time: u64,
+    /// Latest open time to fetch, in ms
+    pub end_time: u64,
+    /// Open time of the last kline received, klines up to it are skipped
+    pub last_open_time: Option<i64>,
+}
+
+impl KlinePages {
+    pub fn new(start_time: u64, end_time: u64) -> Self {
+        KlinePages {
+            start_time,
+            end_time,
+            last_open_time: None,
+        }
+    }
+
+    /// Record the kline opened at `open_time` as received
+    pub fn advance(&mut self, open_time: i64) {
+        if self.last_open_time.is_none_or(|last| open_time > last) {
+            self.last_open_time = Some(open_time);
+            self.start_time = self.start_time.max(open_time as u64 + 1);
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.start_time > self.end_time
+    }
+
+    /// Drop the klines already received from a page and advance past the rest
+    pub(crate) fn take_page<K, F>(&mut self, page: Vec<K>, open_time: F) -> Vec<K>
+    where
+        F: Fn(&K) -> i64,
+    {
+        let last_open_time = self.last_open_time;
+        let page: Vec<K> = page
+            .into_iter()
+            .filter(|kline| last_open_time.is_none_or(|last| open_time(kline) > last))
+            .collect();
+        if let Some(last) = page.last() {
+            self.advance(open_time(last));
+        }
+        page
+    }
+}
+
+fn kline_summary(row: &[Value]) -> KlineSummary {
+    KlineSummary {
+        open_time: to_i64(&row[0]),
+        open: to_decimal(&row[1]),
+        high: to_decimal(&row[2]),
+        low: to_decimal(&row[3]),
+        close: to_decimal(&row[4]),
+        volume: to_decimal(&row[5]),
+        close_time: to_i64(&row[6]),
+        quote_asset_volume: to_decimal(&row[7]),
+        number_of_trades: to_i64(&row[8]),
+        taker_buy_base_asset_volume: to_decimal(&row[9]),
+        taker_buy_quote_asset_volume: to_decimal(&row[10]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::api::Binance;
+    use serde_json::json;
+
+    fn kline(open_time: i64) -> Vec<Value> {
+        json!([
+            open_time,
+            "1",
+            "2",
+            "0.5",
+            "1.5",
+            "10",
+            open_time + 59_999,
+            "15",
+            3,
+            "4",
+            "6"
+        ])
+        .as_array()
+        .unwrap()
+        .clone()
+    }
+
+    #[test]
+    fn kline_range_skips_received_klines() {
+        let mut range = KlineRange::new("BTCUSDT", KlineInterval::Minute1, 0, 240_000);
+        let page = range.pages.take_page(
+            [0, 60_000]
                 .iter()
-                .map(|row| KlineSummary {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
+                .map(|t| kline_summary(&kline(*t)))
                 .collect(),
+            |kline| kline.open_time,
         );
-        Ok(klines)
+        assert_eq!(page.len(), 2);
+        assert_eq!(range.pages.start_time, 60_001);
+        assert_eq!(range.pages.last_open_time, Some(60_000));
+        // checkpoints keep their flat layout
+        let checkpoint = serde_json::to_value(&range).unwrap();
+        assert_eq!(checkpoint["startTime"], 60_001);
+        assert_eq!(checkpoint["lastOpenTime"], 60_000);
+
+        // a checkpoint restored with an older start time refetches the boundary kline
+        let mut resumed: KlineRange =
+            serde_json::from_str(&serde_json::to_string(&range).unwrap()).unwrap();
+        resumed.pages.start_time = 60_000;
+        let page = resumed.pages.take_page(
+            [60_000, 120_000, 180_000]
+                .iter()
+                .map(|t| kline_summary(&kline(*t)))
+                .collect(),
+            |kline| kline.open_time,
+        );
+        assert_eq!(
+            page.iter().map(|k| k.open_time).collect::<Vec<_>>(),
+            vec![120_000, 180_000]
+        );
+        assert!(!resumed.is_done());
+        resumed.advance(240_000);
+        assert!(resumed.is_done());
+    }
+
+    #[test]
+    fn multi_symbol_tickers_weight_and_encoding() {
+        assert_eq!(price_stats_weight(1), 2);
+        assert_eq!(price_stats_weight(21), 40);
+        assert_eq!(price_stats_weight(0), 80);
+        assert_eq!(rolling_ticker_weight(3), 12);
+        assert_eq!(rolling_ticker_weight(100), 200);
+        assert!(TickerWindow::Hours(24).validate().is_err());
+        assert_eq!(TickerWindow::Days(7).to_string(), "7d");
+
+        let market: Market = Binance::new(None, None);
+        let request = market.symbols_request(&["BTCUSDT", "ETHBTC"], BTreeMap::new());
+        assert_eq!(request, "symbols=%5B%22BTCUSDT%22%2C%22ETHBTC%22%5D");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn weight_budget_is_shared_by_clones() {
+        let market: Market = Binance::new(None, None);
+        let market = market.set_weight_per_minute(80);
+        let start = tokio::time::Instant::now();
+        market.acquire(price_stats_weight(0)).await;
+        market.clone().acquire(price_stats_weight(21)).await;
+        assert_eq!(start.elapsed(), std::time::Duration::from_secs(60));
+    }
+
+    #[tokio::test]
+    async fn no_request_for_no_symbols() {
+        // nothing listens on the discard port, a request would fail
+        let market = Market {
+            client: Client::new(None, None, "http://127.0.0.1:9".into()),
+            recv_window: 5000,
+            weight_limiter: None,
+        };
+        let symbols: [&str; 0] = [];
+        assert!(market
+            .get_24h_price_stats_for(&symbols)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(market.get_prices_for(&symbols).await.unwrap().is_empty());
+        assert!(market
+            .get_book_tickers_for(&symbols)
+            .await
+            .unwrap()
+            .is_empty());
     }
 }
@@ -16,11 +16,16 @@ pub mod rest_model;
 pub mod ws_model;
 
 pub mod account;
+pub mod aggregation;
 pub mod api;
+pub mod archive;
+pub mod backfill;
 pub mod config;
+pub mod filters;
 pub mod general;
 pub mod margin;
 pub mod market;
+pub mod orderbook;
 pub mod savings;
 pub mod userstream;
 pub mod websockets;
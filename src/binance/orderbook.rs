@@ -0,0 +1,645 @@
+use super::errors::*;
+use super::market::Market;
+use super::rest_model::{Asks, Bids, OrderBookPartial, OrderSide};
+use super::ws_model::DepthOrderBookEvent;
+
+use log::{debug, warn};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// Minimum delay between two snapshots of the same resync, while the snapshot
+/// lags the buffered events or its request fails
+pub(crate) const SNAPSHOT_RETRY: Duration = Duration::from_secs(1);
+
+/// Allows one snapshot request per resync attempt, and another one `SNAPSHOT_RETRY` later
+#[derive(Debug, Default)]
+pub(crate) struct SnapshotGate {
+    next: Option<Instant>,
+}
+
+impl SnapshotGate {
+    /// Whether a new snapshot may be fetched, holds off further ones for `SNAPSHOT_RETRY` if so
+    pub(crate) fn due(&mut self, now: Instant) -> bool {
+        if self.next.is_some_and(|next| now < next) {
+            return false;
+        }
+        self.next = Some(now + SNAPSHOT_RETRY);
+        true
+    }
+
+    /// Allow the next snapshot right away, once a snapshot was applied or the book reset
+    pub(crate) fn clear(&mut self) {
+        self.next = None;
+    }
+}
+
+/// Outcome of applying a diff depth event to a local book
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// The event was applied and the book moved to its final update id
+    Applied,
+    /// The event is older than the book and was dropped
+    Stale,
+    /// Updates are missing between the book and the event, a new snapshot is required
+    Gap,
+}
+
+/// REST depth snapshot a book is rebuilt from
+pub trait DepthSnapshot {
+    fn last_update_id(&self) -> u64;
+    /// Event and transaction time, zero when not sent
+    fn times(&self) -> (u64, u64);
+    fn bids(&self) -> &[Bids];
+    fn asks(&self) -> &[Asks];
+}
+
+/// Diff depth event applied to a book
+pub trait DepthUpdate {
+    fn first_update_id(&self) -> u64;
+    fn final_update_id(&self) -> u64;
+    /// `pu` of futures events, None for spot where update ids are contiguous
+    fn previous_final_update_id(&self) -> Option<u64>;
+    /// Event and transaction time, zero when not sent
+    fn times(&self) -> (u64, u64);
+    fn bids(&self) -> &[Bids];
+    fn asks(&self) -> &[Asks];
+}
+
+impl DepthSnapshot for OrderBookPartial {
+    fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    fn times(&self) -> (u64, u64) {
+        (0, 0)
+    }
+
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
+impl DepthUpdate for DepthOrderBookEvent {
+    fn first_update_id(&self) -> u64 {
+        self.first_update_id
+    }
+
+    fn final_update_id(&self) -> u64 {
+        self.final_update_id
+    }
+
+    fn previous_final_update_id(&self) -> Option<u64> {
+        None
+    }
+
+    fn times(&self) -> (u64, u64) {
+        (self.event_time, 0)
+    }
+
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
+impl From<&OrderBook> for OrderBookPartial {
+    fn from(book: &OrderBook) -> Self {
+        OrderBookPartial {
+            last_update_id: book.last_update_id,
+            bids: book.bids_iter().collect(),
+            asks: book.asks_iter().collect(),
+        }
+    }
+}
+
+/// Local copy of a spot or futures order book, keyed by price
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OrderBook {
+    pub symbol: String,
+    pub last_update_id: u64,
+    pub event_time: u64,
+    pub transaction_time: u64,
+    pub bids: BTreeMap<Decimal, Decimal>,
+    pub asks: BTreeMap<Decimal, Decimal>,
+    /// No event applied since the last snapshot, the futures `pu` chain starts on the next one
+    #[serde(skip)]
+    from_snapshot: bool,
+}
+
+impl OrderBook {
+    pub fn new<S>(symbol: S) -> OrderBook
+    where
+        S: Into<String>,
+    {
+        OrderBook {
+            symbol: symbol.into(),
+            ..OrderBook::default()
+        }
+    }
+
+    /// Replace the whole book with a REST snapshot
+    pub fn apply_snapshot<P: DepthSnapshot>(&mut self, snapshot: &P) {
+        self.bids.clear();
+        self.asks.clear();
+        self.last_update_id = snapshot.last_update_id();
+        (self.event_time, self.transaction_time) = snapshot.times();
+        self.from_snapshot = true;
+        for bid in snapshot.bids() {
+            update_level(&mut self.bids, bid.price, bid.qty);
+        }
+        for ask in snapshot.asks() {
+            update_level(&mut self.asks, ask.price, ask.qty);
+        }
+    }
+
+    /// Apply a diff depth event following the update id rules of its market.
+    /// Spot: events ending at or before the book are stale, events starting
+    /// after `last_update_id + 1` leave a gap.
+    /// Futures: the first event after a snapshot must straddle its `lastUpdateId`,
+    /// every later event must have `pu` equal to the previous `u`.
+    pub fn apply_update<E: DepthUpdate>(&mut self, event: &E) -> UpdateStatus {
+        match event.previous_final_update_id() {
+            None => {
+                if event.final_update_id() <= self.last_update_id {
+                    return UpdateStatus::Stale;
+                }
+                if event.first_update_id() > self.last_update_id + 1 {
+                    return UpdateStatus::Gap;
+                }
+            }
+            Some(_) if self.from_snapshot => {
+                if event.final_update_id() < self.last_update_id {
+                    return UpdateStatus::Stale;
+                }
+                if event.first_update_id() > self.last_update_id {
+                    return UpdateStatus::Gap;
+                }
+            }
+            Some(previous) => {
+                if previous != self.last_update_id {
+                    if event.final_update_id() <= self.last_update_id {
+                        return UpdateStatus::Stale;
+                    }
+                    return UpdateStatus::Gap;
+                }
+            }
+        }
+
+        for bid in event.bids() {
+            update_level(&mut self.bids, bid.price, bid.qty);
+        }
+        for ask in event.asks() {
+            update_level(&mut self.asks, ask.price, ask.qty);
+        }
+        self.last_update_id = event.final_update_id();
+        (self.event_time, self.transaction_time) = event.times();
+        self.from_snapshot = false;
+
+        UpdateStatus::Applied
+    }
+
+    /// Returns the price and quantity of the best bid
+    pub fn best_bid(&self) -> Option<Bids> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, qty)| Bids::new(*price, *qty))
+    }
+
+    /// Returns the price and quantity of the best ask
+    pub fn best_ask(&self) -> Option<Asks> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, qty)| Asks::new(*price, *qty))
+    }
+
+    /// Returns the midpoint between the best bid price and best ask price.
+    /// Output is not rounded to the smallest price increment.
+    pub fn mid_price(&self) -> Option<Decimal> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / Decimal::TWO)
+    }
+
+    /// Best ask price minus best bid price
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Bid levels from the best price down
+    pub fn bids_iter(&self) -> impl Iterator<Item = Bids> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(price, qty)| Bids::new(*price, *qty))
+    }
+
+    /// Ask levels from the best price up
+    pub fn asks_iter(&self) -> impl Iterator<Item = Asks> + '_ {
+        self.asks.iter().map(|(price, qty)| Asks::new(*price, *qty))
+    }
+
+    /// The `depth` best bid levels
+    pub fn top_bids(&self, depth: usize) -> Vec<Bids> {
+        self.bids_iter().take(depth).collect()
+    }
+
+    /// The `depth` best ask levels
+    pub fn top_asks(&self, depth: usize) -> Vec<Asks> {
+        self.asks_iter().take(depth).collect()
+    }
+
+    /// Copy of the book in the spot or futures REST depth format, best levels first
+    pub fn snapshot<P>(&self) -> P
+    where
+        P: for<'a> From<&'a OrderBook>,
+    {
+        P::from(self)
+    }
+
+    /// A bid at or above the best ask means the book is corrupt
+    pub fn is_crossed(&self) -> bool {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => bid.price >= ask.price,
+            _ => false,
+        }
+    }
+}
+
+fn update_level(levels: &mut BTreeMap<Decimal, Decimal>, price: Decimal, qty: Decimal) {
+    if qty.is_zero() {
+        levels.remove(&price);
+    } else {
+        levels.insert(price, qty);
+    }
+}
+
+/// Order size to sweep through the book
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Size {
+    /// Quantity in the base asset
+    Base(Decimal),
+    /// Notional in the quote asset
+    Quote(Decimal),
+}
+
+/// Result of sweeping the book with a market order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fill {
+    pub base_qty: Decimal,
+    pub quote_qty: Decimal,
+    /// Volume weighted average fill price
+    pub avg_price: Decimal,
+    /// Price of the last level touched
+    pub worst_price: Decimal,
+}
+
+const BPS: Decimal = dec!(10000);
+
+fn sweep<I>(levels: I, size: Size) -> Option<Fill>
+where
+    I: Iterator<Item = (Decimal, Decimal)>,
+{
+    let (mut remaining, by_quote) = match size {
+        Size::Base(qty) => (qty, false),
+        Size::Quote(notional) => (notional, true),
+    };
+    if remaining <= Decimal::ZERO {
+        return None;
+    }
+
+    let mut base_qty = Decimal::ZERO;
+    let mut quote_qty = Decimal::ZERO;
+    for (price, qty) in levels {
+        let level_size = if by_quote { price * qty } else { qty };
+        let taken = remaining.min(level_size);
+        let taken_base = if by_quote { taken / price } else { taken };
+        base_qty += taken_base;
+        quote_qty += taken_base * price;
+        remaining -= taken;
+        if remaining.is_zero() {
+            return Some(Fill {
+                base_qty,
+                quote_qty,
+                avg_price: quote_qty / base_qty,
+                worst_price: price,
+            });
+        }
+    }
+    None
+}
+
+/// Order book analytics, prices and quantities as in the book
+impl OrderBook {
+    /// Average fill price of a market order of `size`, walking asks for a buy
+    /// and bids for a sell. None if the book is not deep enough.
+    pub fn vwap(&self, side: OrderSide, size: Size) -> Option<Fill> {
+        match side {
+            OrderSide::Buy => sweep(self.asks.iter().map(|(p, q)| (*p, *q)), size),
+            OrderSide::Sell => sweep(self.bids.iter().rev().map(|(p, q)| (*p, *q)), size),
+        }
+    }
+
+    /// Expected cost of a market order of `size` against the mid price, in bps.
+    /// Positive values are adverse for both sides.
+    pub fn slippage_bps(&self, side: OrderSide, size: Size) -> Option<Decimal> {
+        let mid = self.mid_price()?;
+        let fill = self.vwap(side.clone(), size)?;
+        let diff = match side {
+            OrderSide::Buy => fill.avg_price - mid,
+            OrderSide::Sell => mid - fill.avg_price,
+        };
+        Some(diff / mid * BPS)
+    }
+
+    /// Bid and ask quantity resting within `bps` of the mid price
+    /// (bid_quantity, ask_quantity)
+    pub fn depth_within_bps(&self, bps: Decimal) -> Option<(Decimal, Decimal)> {
+        let mid = self.mid_price()?;
+        let band = mid * bps / BPS;
+        let bid_qty = self.bids.range(mid - band..).map(|(_, q)| *q).sum();
+        let ask_qty = self.asks.range(..=mid + band).map(|(_, q)| *q).sum();
+        Some((bid_qty, ask_qty))
+    }
+
+    /// (bid_qty - ask_qty) / (bid_qty + ask_qty) over the `depth` best levels,
+    /// from -1 (all asks) to 1 (all bids)
+    pub fn imbalance(&self, depth: usize) -> Option<Decimal> {
+        let bid_qty: Decimal = self.bids.values().rev().take(depth).sum();
+        let ask_qty: Decimal = self.asks.values().take(depth).sum();
+        let total = bid_qty + ask_qty;
+        if total.is_zero() {
+            return None;
+        }
+        Some((bid_qty - ask_qty) / total)
+    }
+
+    /// Size weighted mid price over the `depth` best levels of each side.
+    /// Each side's average price is weighted by the opposite side's quantity,
+    /// `depth` 1 gives the classic top of book microprice.
+    pub fn microprice(&self, depth: usize) -> Option<Decimal> {
+        let (bid_price, bid_qty) = level_vwap(self.bids.iter().rev().take(depth))?;
+        let (ask_price, ask_qty) = level_vwap(self.asks.iter().take(depth))?;
+        Some((bid_price * ask_qty + ask_price * bid_qty) / (bid_qty + ask_qty))
+    }
+}
+
+/// Volume weighted price and total quantity of a set of levels
+fn level_vwap<'a, I>(levels: I) -> Option<(Decimal, Decimal)>
+where
+    I: Iterator<Item = (&'a Decimal, &'a Decimal)>,
+{
+    let (notional, qty) = levels.fold((Decimal::ZERO, Decimal::ZERO), |(n, q), (price, qty)| {
+        (n + price * qty, q + qty)
+    });
+    if qty.is_zero() {
+        return None;
+    }
+    Some((notional / qty, qty))
+}
+
+/// Keeps an `OrderBook` in sync with a `<symbol>@depth` stream.
+///
+/// Follows the documented spot algorithm: diff events are buffered until a
+/// `Market::get_custom_depth` snapshot covers the first of them, buffered events
+/// older than the snapshot are dropped and the rest are replayed. Any gap in
+/// update ids discards the book and triggers a new snapshot. A single snapshot
+/// is fetched per attempt, events keep being buffered until the next attempt.
+///
+/// # Examples
+/// ```rust,no_run
+/// use exrs::binance::{api::*, market::*, orderbook::*, websockets::*, ws_model::*};
+/// use std::sync::atomic::AtomicBool;
+///
+/// # async fn run() -> exrs::binance::errors::Result<()> {
+/// let market: Market = Binance::new(None, None);
+/// let mut sync = OrderBookSync::new(market, "BTCUSDT", 1000);
+/// let (tx, mut rx) = tokio::sync::mpsc::channel::<DepthOrderBookEvent>(1024);
+/// let mut web_socket = WebSockets::new(tx);
+/// web_socket.connect(&diff_book_depth_stream("btcusdt", 100)).await?;
+/// actix_rt::spawn(async move {
+///     while let Some(event) = rx.recv().await {
+///         sync.handle(event).await.unwrap();
+///         println!("{:?}", sync.book().best_bid());
+///     }
+/// });
+/// web_socket.event_loop(&AtomicBool::new(true)).await
+/// # }
+/// ```
+pub struct OrderBookSync {
+    market: Market,
+    depth_limit: u16,
+    book: OrderBook,
+    buffer: Vec<DepthOrderBookEvent>,
+    synced: bool,
+    snapshot_gate: SnapshotGate,
+}
+
+impl OrderBookSync {
+    /// `depth_limit` is the snapshot size passed to `get_custom_depth`
+    pub fn new<S>(market: Market, symbol: S, depth_limit: u16) -> OrderBookSync
+    where
+        S: Into<String>,
+    {
+        OrderBookSync {
+            market,
+            depth_limit,
+            book: OrderBook::new(symbol),
+            buffer: Vec::new(),
+            synced: false,
+            snapshot_gate: SnapshotGate::default(),
+        }
+    }
+
+    pub fn book(&self) -> &OrderBook {
+        &self.book
+    }
+
+    /// Whether the book reflects the stream, false while waiting for a snapshot
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// Feed the next diff depth event from the stream
+    pub async fn handle(&mut self, event: DepthOrderBookEvent) -> Result<()> {
+        if self.synced {
+            match self.book.apply_update(&event) {
+                UpdateStatus::Applied | UpdateStatus::Stale => return Ok(()),
+                UpdateStatus::Gap => {
+                    warn!(
+                        "{} depth gap: book at {}, event starts at {}, resyncing",
+                        self.book.symbol, self.book.last_update_id, event.first_update_id
+                    );
+                    self.synced = false;
+                }
+            }
+        }
+
+        self.buffer.push(event);
+        if !self.snapshot_gate.due(Instant::now()) {
+            return Ok(());
+        }
+        self.resync().await
+    }
+
+    /// Drop the book and rebuild it from a new snapshot on the next event
+    pub fn reset(&mut self) {
+        self.synced = false;
+        self.buffer.clear();
+        self.snapshot_gate.clear();
+    }
+
+    async fn resync(&mut self) -> Result<()> {
+        let snapshot = self
+            .market
+            .get_custom_depth(self.book.symbol.clone(), self.depth_limit)
+            .await?;
+        self.sync_with(snapshot);
+        Ok(())
+    }
+
+    /// Apply a snapshot and replay the buffered events on top of it.
+    /// A snapshot older than the first buffered event is discarded, the next
+    /// event will fetch a new one.
+    fn sync_with(&mut self, snapshot: OrderBookPartial) {
+        if let Some(first) = self.buffer.first() {
+            if snapshot.last_update_id < first.first_update_id {
+                debug!(
+                    "{} snapshot {} older than buffered event {}, waiting",
+                    self.book.symbol, snapshot.last_update_id, first.first_update_id
+                );
+                return;
+            }
+        }
+
+        self.book.apply_snapshot(&snapshot);
+        self.synced = true;
+        self.snapshot_gate.clear();
+        for event in self.buffer.drain(..) {
+            if self.book.apply_update(&event) == UpdateStatus::Gap {
+                warn!(
+                    "{} depth gap while replaying buffered events, resyncing",
+                    self.book.symbol
+                );
+                self.synced = false;
+                break;
+            }
+        }
+        self.buffer.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::api::Binance;
+
+    fn event(first: u64, last: u64, bids: &[(Decimal, Decimal)]) -> DepthOrderBookEvent {
+        DepthOrderBookEvent {
+            event_time: 0,
+            symbol: "BTCUSDT".into(),
+            first_update_id: first,
+            final_update_id: last,
+            bids: bids.iter().map(|(p, q)| Bids::new(*p, *q)).collect(),
+            asks: vec![],
+        }
+    }
+
+    fn snapshot(last_update_id: u64) -> OrderBookPartial {
+        OrderBookPartial {
+            last_update_id,
+            bids: vec![Bids::new(dec!(100), dec!(1)), Bids::new(dec!(99), dec!(2))],
+            asks: vec![Asks::new(dec!(101), dec!(1))],
+        }
+    }
+
+    #[test]
+    fn apply_update_checks_update_ids() {
+        let mut book = OrderBook::new("BTCUSDT");
+        book.apply_snapshot(&snapshot(10));
+
+        assert_eq!(book.apply_update(&event(5, 10, &[])), UpdateStatus::Stale);
+        assert_eq!(
+            book.apply_update(&event(8, 12, &[(dec!(100), dec!(0))])),
+            UpdateStatus::Applied
+        );
+        assert_eq!(book.best_bid(), Some(Bids::new(dec!(99), dec!(2))));
+        assert_eq!(book.apply_update(&event(14, 15, &[])), UpdateStatus::Gap);
+        assert_eq!(book.last_update_id, 12);
+    }
+
+    #[test]
+    fn sync_drops_old_events_and_replays_the_rest() {
+        let market: Market = Binance::new(None, None);
+        let mut sync = OrderBookSync::new(market, "BTCUSDT", 1000);
+        sync.buffer.push(event(8, 9, &[(dec!(98), dec!(5))]));
+
+        // snapshot older than the first buffered event is ignored
+        sync.sync_with(snapshot(7));
+        assert!(!sync.is_synced());
+
+        sync.buffer.push(event(10, 12, &[(dec!(100.5), dec!(3))]));
+        sync.sync_with(snapshot(11));
+        assert!(sync.is_synced());
+        assert_eq!(sync.book().last_update_id, 12);
+        assert_eq!(
+            sync.book().best_bid(),
+            Some(Bids::new(dec!(100.5), dec!(3)))
+        );
+        assert!(!sync.book().bids.contains_key(&dec!(98)));
+        assert_eq!(sync.book().mid_price(), Some(dec!(100.75)));
+    }
+
+    #[test]
+    fn one_snapshot_per_attempt() {
+        let market: Market = Binance::new(None, None);
+        let mut sync = OrderBookSync::new(market, "BTCUSDT", 1000);
+        let now = Instant::now();
+
+        assert!(sync.snapshot_gate.due(now));
+        // a lagging snapshot does not allow another fetch before the retry delay
+        sync.buffer.push(event(8, 9, &[]));
+        sync.sync_with(snapshot(7));
+        assert!(!sync.snapshot_gate.due(now + Duration::from_millis(10)));
+        assert!(sync.snapshot_gate.due(now + SNAPSHOT_RETRY));
+
+        // once synced, a gap resyncs right away
+        sync.sync_with(snapshot(9));
+        assert!(sync.is_synced());
+        assert!(sync.snapshot_gate.due(now + SNAPSHOT_RETRY));
+    }
+
+    #[test]
+    fn analytics() {
+        let mut book = OrderBook::new("BTCUSDT");
+        book.apply_snapshot(&OrderBookPartial {
+            last_update_id: 1,
+            bids: vec![Bids::new(dec!(100), dec!(1)), Bids::new(dec!(99), dec!(2))],
+            asks: vec![Asks::new(dec!(101), dec!(1)), Asks::new(dec!(102), dec!(3))],
+        });
+
+        let fill = book.vwap(OrderSide::Buy, Size::Base(dec!(2))).unwrap();
+        assert_eq!(fill.avg_price, dec!(101.5));
+        assert_eq!(fill.worst_price, dec!(102));
+        let fill = book.vwap(OrderSide::Buy, Size::Quote(dec!(305))).unwrap();
+        assert_eq!(fill.base_qty, dec!(3));
+        assert!(book.vwap(OrderSide::Buy, Size::Base(dec!(5))).is_none());
+        assert_eq!(
+            book.slippage_bps(OrderSide::Sell, Size::Base(dec!(1)))
+                .unwrap()
+                .round_dp(2),
+            dec!(49.75)
+        );
+        assert_eq!(book.depth_within_bps(dec!(100)), Some((dec!(1), dec!(1))));
+        assert_eq!(book.imbalance(2), Some(dec!(-1) / dec!(7)));
+        assert_eq!(book.microprice(1), Some(dec!(100.5)));
+    }
+}
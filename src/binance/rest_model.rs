@@ -1,5 +1,9 @@
+use chrono::{Datelike, TimeZone, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -55,18 +59,18 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     PriceFilter {
         #[serde(with = "string_or_float")]
-        min_price: f64,
+        min_price: Decimal,
         #[serde(with = "string_or_float")]
-        max_price: f64,
+        max_price: Decimal,
         tick_size: String,
     },
     #[serde(rename = "LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
     LotSize {
         #[serde(with = "string_or_float")]
-        min_qty: f64,
+        min_qty: Decimal,
         #[serde(with = "string_or_float")]
-        max_qty: f64,
+        max_qty: Decimal,
         step_size: String,
     },
     #[serde(rename = "MARKET_LOT_SIZE")]
@@ -80,10 +84,10 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     Notional {
         #[serde(with = "string_or_float")]
-        min_notional: f64,
+        min_notional: Decimal,
         apply_min_to_market: bool,
         #[serde(with = "string_or_float")]
-        max_notional: f64,
+        max_notional: Decimal,
         apply_max_to_market: bool,
         avg_price_mins: u64,
     },
@@ -91,13 +95,13 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     PercentPriceBySide {
         #[serde(with = "string_or_float")]
-        bid_multiplier_up: f64,
+        bid_multiplier_up: Decimal,
         #[serde(with = "string_or_float")]
-        bid_multiplier_down: f64,
+        bid_multiplier_down: Decimal,
         #[serde(with = "string_or_float")]
-        ask_multiplier_up: f64,
+        ask_multiplier_up: Decimal,
         #[serde(with = "string_or_float")]
-        ask_multiplier_down: f64,
+        ask_multiplier_down: Decimal,
         avg_price_mins: u64,
     },
     #[serde(rename = "ICEBERG_PARTS")]
@@ -116,7 +120,7 @@ pub enum Filters {
     #[serde(rename_all = "camelCase")]
     MaxPosition {
         #[serde(with = "string_or_float")]
-        max_position: f64,
+        max_position: Decimal,
     },
     #[serde(rename = "EXCHANGE_MAX_NUM_ORDERS")]
     #[serde(rename_all = "camelCase")]
@@ -200,27 +204,27 @@ pub struct Order {
     pub order_list_id: i32,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Decimal,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
     #[serde(with = "string_or_float")]
-    pub orig_quote_order_qty: f64,
+    pub orig_quote_order_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -236,11 +240,11 @@ pub struct OrderCanceled {
 #[serde(rename_all = "camelCase")]
 pub struct Fill {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Decimal,
     pub commission_asset: String,
 }
 
@@ -252,13 +256,13 @@ pub struct Transaction {
     pub client_order_id: String,
     pub transact_time: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
@@ -267,6 +271,72 @@ pub struct Transaction {
     pub fills: Vec<Fill>,
 }
 
+/// Whether the new order is placed when the cancellation fails
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceMode {
+    StopOnFailure,
+    AllowFailure,
+}
+
+/// By default, do not place the new order if the cancellation fails
+impl Default for CancelReplaceMode {
+    fn default() -> Self {
+        Self::StopOnFailure
+    }
+}
+
+/// Only cancel the order if it has this status
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelRestrictions {
+    OnlyNew,
+    OnlyPartiallyFilled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceResult {
+    Success,
+    Failure,
+    NotAttempted,
+}
+
+/// Order after a quantity decrease that kept its priority
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub prevented_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub cumulative_quote_qty: Decimal,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: OrderSide,
+    pub working_time: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendKeepPriority {
+    pub transact_time: u64,
+    pub execution_id: u64,
+    pub amended_order: AmendedOrder,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionId {
@@ -333,14 +403,14 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -350,6 +420,176 @@ pub enum BookTickers {
     AllBookTickers(Vec<Tickers>),
 }
 
+/// Kline/candlestick interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum KlineInterval {
+    #[serde(rename = "1s")]
+    Second1,
+    #[serde(rename = "1m")]
+    Minute1,
+    #[serde(rename = "3m")]
+    Minute3,
+    #[serde(rename = "5m")]
+    Minute5,
+    #[serde(rename = "15m")]
+    Minute15,
+    #[serde(rename = "30m")]
+    Minute30,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "2h")]
+    Hour2,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "6h")]
+    Hour6,
+    #[serde(rename = "8h")]
+    Hour8,
+    #[serde(rename = "12h")]
+    Hour12,
+    #[serde(rename = "1d")]
+    Day1,
+    #[serde(rename = "3d")]
+    Day3,
+    #[serde(rename = "1w")]
+    Week1,
+    #[serde(rename = "1M")]
+    Month1,
+}
+
+/// Weeks open on monday, 4 days after the epoch
+const WEEK_OFFSET: i64 = 4 * 86_400_000;
+
+impl KlineInterval {
+    pub const ALL: [KlineInterval; 16] = [
+        KlineInterval::Second1,
+        KlineInterval::Minute1,
+        KlineInterval::Minute3,
+        KlineInterval::Minute5,
+        KlineInterval::Minute15,
+        KlineInterval::Minute30,
+        KlineInterval::Hour1,
+        KlineInterval::Hour2,
+        KlineInterval::Hour4,
+        KlineInterval::Hour6,
+        KlineInterval::Hour8,
+        KlineInterval::Hour12,
+        KlineInterval::Day1,
+        KlineInterval::Day3,
+        KlineInterval::Week1,
+        KlineInterval::Month1,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KlineInterval::Second1 => "1s",
+            KlineInterval::Minute1 => "1m",
+            KlineInterval::Minute3 => "3m",
+            KlineInterval::Minute5 => "5m",
+            KlineInterval::Minute15 => "15m",
+            KlineInterval::Minute30 => "30m",
+            KlineInterval::Hour1 => "1h",
+            KlineInterval::Hour2 => "2h",
+            KlineInterval::Hour4 => "4h",
+            KlineInterval::Hour6 => "6h",
+            KlineInterval::Hour8 => "8h",
+            KlineInterval::Hour12 => "12h",
+            KlineInterval::Day1 => "1d",
+            KlineInterval::Day3 => "3d",
+            KlineInterval::Week1 => "1w",
+            KlineInterval::Month1 => "1M",
+        }
+    }
+
+    /// Length of a kline, months count 30 days, use `align` and `next_open` for calendar months
+    pub fn duration(&self) -> Duration {
+        let secs = match self {
+            KlineInterval::Second1 => 1,
+            KlineInterval::Minute1 => 60,
+            KlineInterval::Minute3 => 3 * 60,
+            KlineInterval::Minute5 => 5 * 60,
+            KlineInterval::Minute15 => 15 * 60,
+            KlineInterval::Minute30 => 30 * 60,
+            KlineInterval::Hour1 => 3600,
+            KlineInterval::Hour2 => 2 * 3600,
+            KlineInterval::Hour4 => 4 * 3600,
+            KlineInterval::Hour6 => 6 * 3600,
+            KlineInterval::Hour8 => 8 * 3600,
+            KlineInterval::Hour12 => 12 * 3600,
+            KlineInterval::Day1 => 86_400,
+            KlineInterval::Day3 => 3 * 86_400,
+            KlineInterval::Week1 => 7 * 86_400,
+            KlineInterval::Month1 => 30 * 86_400,
+        };
+        Duration::from_secs(secs)
+    }
+
+    /// Open time of the kline containing `time`, both in ms.
+    /// Months fall back to 30 days outside the dates chrono can represent.
+    pub fn align(&self, time: i64) -> i64 {
+        let length = self.duration().as_millis() as i64;
+        match self {
+            KlineInterval::Month1 => {
+                month_open(time, 0).unwrap_or_else(|| time.div_euclid(length) * length)
+            }
+            KlineInterval::Week1 => (time - WEEK_OFFSET).div_euclid(length) * length + WEEK_OFFSET,
+            _ => time.div_euclid(length) * length,
+        }
+    }
+
+    /// Open time of the kline following the one containing `time`, both in ms
+    pub fn next_open(&self, time: i64) -> i64 {
+        let next = self.align(time) + self.duration().as_millis() as i64;
+        match self {
+            KlineInterval::Month1 => month_open(time, 1).unwrap_or(next),
+            _ => next,
+        }
+    }
+}
+
+/// First millisecond of the month containing `time`, or of the month after.
+/// None outside the dates chrono can represent.
+fn month_open(time: i64, months_ahead: u32) -> Option<i64> {
+    let date = Utc.timestamp_millis_opt(time).single()?;
+    let month = date.month0() + months_ahead;
+    let open = Utc
+        .with_ymd_and_hms(
+            date.year() + (month / 12) as i32,
+            month % 12 + 1,
+            1,
+            0,
+            0,
+            0,
+        )
+        .single()?;
+    Some(open.timestamp_millis())
+}
+
+impl fmt::Display for KlineInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for KlineInterval {
+    type Err = super::errors::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KlineInterval::ALL
+            .into_iter()
+            .find(|interval| interval.as_str() == s)
+            .ok_or_else(|| super::errors::Error::InvalidPeriod(s.to_string()))
+    }
+}
+
+impl TryFrom<&str> for KlineInterval {
+    type Error = super::errors::Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum KlineSummaries {
     AllKlineSummaries(Vec<KlineSummary>),
@@ -360,13 +600,13 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -374,9 +614,9 @@ pub struct Tickers {
 pub struct TradeHistory {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     pub commission: String,
     pub commission_asset: String,
     pub time: u64,
@@ -385,28 +625,67 @@ pub struct TradeHistory {
     pub is_best_match: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Decimal,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggTrade {
+    #[serde(rename = "a")]
+    pub agg_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(rename = "f")]
+    pub first_id: u64,
+    #[serde(rename = "l")]
+    pub last_id: u64,
+    #[serde(rename = "T")]
+    pub time: u64,
+    #[serde(rename = "m")]
+    pub maker: bool,
+    #[serde(rename = "M")]
+    pub best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
+    #[serde(default)]
+    pub symbol: String,
     pub price_change: String,
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
+    #[serde(default, with = "string_or_float")]
+    pub quote_volume: Decimal,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -414,6 +693,69 @@ pub struct PriceStats {
     pub count: u64,
 }
 
+/// Price change statistics of the rolling window and trading day tickers
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerStatistics {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub open_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub high_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub low_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub last_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: Decimal,
+    pub open_time: u64,
+    pub close_time: u64,
+    /// -1 without trades in the window
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+/// Window of the rolling ticker: 1 to 59 minutes, 1 to 23 hours or 1 to 7 days
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerWindow {
+    Minutes(u8),
+    Hours(u8),
+    Days(u8),
+}
+
+impl TickerWindow {
+    pub fn validate(&self) -> super::errors::Result<()> {
+        let valid = match *self {
+            TickerWindow::Minutes(minutes) => (1..=59).contains(&minutes),
+            TickerWindow::Hours(hours) => (1..=23).contains(&hours),
+            TickerWindow::Days(days) => (1..=7).contains(&days),
+        };
+        if !valid {
+            return Err(super::errors::Error::InvalidPeriod(self.to_string()));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for TickerWindow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TickerWindow::Minutes(minutes) => write!(f, "{}m", minutes),
+            TickerWindow::Hours(hours) => write!(f, "{}h", hours),
+            TickerWindow::Days(days) => write!(f, "{}d", days),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum MarginTransferType {
     FromMainToMargin = 1,
@@ -424,7 +766,7 @@ pub enum MarginTransferType {
 #[serde(rename_all = "camelCase")]
 pub struct Transfer {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub transfer_type: MarginTransferType,
 }
@@ -441,7 +783,7 @@ pub enum IsolatedMarginTransferType {
 pub struct IsolatedTransfer {
     pub asset: String,
     pub symbol: String,
-    pub amount: f64,
+    pub amount: Decimal,
     pub trans_from: IsolatedMarginTransferType,
     pub trans_to: IsolatedMarginTransferType,
 }
@@ -450,7 +792,7 @@ pub struct IsolatedTransfer {
 #[serde(rename_all = "camelCase")]
 pub struct Loan {
     pub asset: String,
-    pub amount: f64,
+    pub amount: Decimal,
     pub is_isolated: Option<String>,
     pub symbol: Option<String>,
 }
@@ -538,14 +880,14 @@ pub struct MarginOrder {
     pub side: OrderSide,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
-    pub quantity: Option<f64>,
-    pub quote_order_qty: Option<f64>,
-    pub price: Option<f64>,
+    pub quantity: Option<Decimal>,
+    pub quote_order_qty: Option<Decimal>,
+    pub price: Option<Decimal>,
     /// Used with `OrderType::StopLoss`, `OrderType::StopLossLimit`, `OrderType::TakeProfit` and `OrderType::TakeProfitLimit`
-    pub stop_price: Option<f64>,
+    pub stop_price: Option<Decimal>,
     pub new_client_order_id: Option<String>,
     /// Used with `OrderType::Limit`, `OrderType::StopLossLimit` and `OrderType::TakeProfitLimit` to create an iceberg order
-    pub iceberg_qty: Option<f64>,
+    pub iceberg_qty: Option<Decimal>,
     /// Default is `OrderResponse::ACK`
     pub new_order_resp_type: OrderResponse,
     /// N.B. : do not set with `OrderType::Market`
@@ -574,13 +916,13 @@ pub struct MarginOrderCancellationResult {
     pub orig_client_order_id: String,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename(serialize = "type", deserialize = "type"))]
@@ -608,16 +950,16 @@ pub struct MarginOCOOrder {
     /// A unique identifier that will be applied to all orders
     pub list_client_order_id: Option<String>,
     pub side: OrderSide,
-    pub quantity: f64,
+    pub quantity: Decimal,
     /// A unique identifier that will be applied to the limit order
     pub limit_client_order_id: Option<String>,
-    pub price: f64,
-    pub limit_iceberg_qty: Option<f64>,
+    pub price: Decimal,
+    pub limit_iceberg_qty: Option<Decimal>,
     /// A unique identifier that will be applied to the stop order
     pub stop_client_order_id: Option<String>,
-    pub stop_price: f64,
-    pub stop_limit_price: Option<f64>,
-    pub stop_iceberg_qty: Option<f64>,
+    pub stop_price: Decimal,
+    pub stop_limit_price: Option<Decimal>,
+    pub stop_iceberg_qty: Option<Decimal>,
     pub stop_limit_time_in_force: Option<TimeInForce>,
     /// Default is `OrderResponse::ACK`
     pub new_order_resp_type: Option<OrderResponse>,
@@ -636,7 +978,7 @@ pub struct MarginOCOOrderResult {
     pub transaction_time: u128,
     pub symbol: String,
     #[serde(default, with = "string_or_float_opt")]
-    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_amount: Option<Decimal>,
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
     pub orders: Vec<OCOOrderDetail>,
@@ -659,21 +1001,39 @@ pub struct OCOOrderReport {
     pub client_order_id: Option<String>,
     pub transact_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
-    #[serde(rename = "type")]
     pub side: OrderSide,
-    #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    /// Zero for legs without a stop price
+    #[serde(default, with = "string_or_float")]
+    pub stop_price: Decimal,
+}
+
+/// Result of placing, querying or cancelling a spot order list,
+/// followed by [`crate::binance::ws_model::OrderListUpdate`] events on the user stream
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: OCOStatus,
+    pub list_order_status: OCOOrderStatus,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OCOOrderDetail>,
+    /// Missing from queries
+    #[serde(default)]
+    pub order_reports: Vec<OCOOrderReport>,
 }
 
 /// archived and is_isolated are only applicable to certain endpoints
@@ -748,12 +1108,12 @@ pub struct IsolatedTransfersQuery {
 #[serde(rename_all = "camelCase")]
 pub struct RepayState {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Decimal,
     pub status: TransactionStatus,
     pub timestamp: u64,
     pub tx_id: u64,
@@ -775,7 +1135,7 @@ pub enum TransactionStatus {
 pub struct LoanState {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Decimal,
     pub timestamp: u64,
     pub status: TransactionStatus,
     pub isolated_symbol: Option<String>,
@@ -796,7 +1156,7 @@ pub enum TransferType {
 #[serde(rename_all = "camelCase")]
 pub struct OrderState {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub asset: String,
     pub status: TransactionStatus,
     pub timestamp: u64,
@@ -825,12 +1185,12 @@ pub enum InterestType {
 pub struct InterestState {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     pub interest_accured_time: u64,
     #[serde(with = "string_or_float")]
-    pub interest_rate: f64,
+    pub interest_rate: Decimal,
     #[serde(with = "string_or_float")]
-    pub principal: f64,
+    pub principal: Decimal,
     #[serde(rename = "type")]
     pub interest_type: InterestType,
     pub isolated_symbol: Option<String>,
@@ -840,14 +1200,14 @@ pub struct InterestState {
 #[serde(rename_all = "camelCase")]
 pub struct ForcedLiquidationState {
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     pub side: OrderSide,
     pub symbol: String,
     pub time_in_force: TimeInForce,
@@ -867,15 +1227,15 @@ pub struct RecordsQueryResult<R> {
 pub struct UserAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub borrowed: f64,
+    pub borrowed: Decimal,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     #[serde(with = "string_or_float")]
-    pub net_asset: f64,
+    pub net_asset: Decimal,
 }
 
 pub type UserAssets = Vec<UserAsset>;
@@ -885,13 +1245,13 @@ pub type UserAssets = Vec<UserAsset>;
 pub struct MarginAccountDetails {
     pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub margin_level: f64,
+    pub margin_level: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_asset_of_btc: f64,
+    pub total_asset_of_btc: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_liability_of_btc: f64,
+    pub total_liability_of_btc: Decimal,
     #[serde(with = "string_or_float")]
-    pub total_net_asset_of_btc: f64,
+    pub total_net_asset_of_btc: Decimal,
     pub trade_enabled: bool,
     pub transfer_enabled: bool,
     pub user_assets: UserAssets,
@@ -903,20 +1263,20 @@ pub struct IsolatedMarginAccountAsset {
     pub asset: String,
     pub borrow_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub borrowed: f64,
+    pub borrowed: Decimal,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub interest: f64,
+    pub interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     #[serde(with = "string_or_float")]
-    pub net_asset: f64,
+    pub net_asset: Decimal,
     #[serde(with = "string_or_float")]
-    pub net_asset_of_btc: f64,
+    pub net_asset_of_btc: Decimal,
     pub repay_enabled: bool,
     #[serde(with = "string_or_float")]
-    pub total_asset: f64,
+    pub total_asset: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -928,16 +1288,16 @@ pub struct IsolatedMarginAccountAssetDetails {
     pub isolated_created: bool,
     pub enabled: bool,
     #[serde(with = "string_or_float")]
-    pub margin_level: f64,
+    pub margin_level: Decimal,
     #[serde(with = "string_or_float")]
-    pub margin_ratio: f64,
+    pub margin_ratio: Decimal,
     pub margin_level_status: MarginLevelStatus,
     #[serde(with = "string_or_float")]
-    pub index_price: f64,
+    pub index_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub liquidate_price: f64,
+    pub liquidate_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub liquidate_rate: f64,
+    pub liquidate_rate: Decimal,
     pub trade_enabled: bool,
 }
 
@@ -958,11 +1318,11 @@ pub enum MarginLevelStatus {
 pub struct IsolatedMarginAccountDetails {
     pub assets: Vec<IsolatedMarginAccountAssetDetails>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_asset_of_btc: Option<f64>,
+    pub total_asset_of_btc: Option<Decimal>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_liability_of_btc: Option<f64>,
+    pub total_liability_of_btc: Option<Decimal>,
     #[serde(default, with = "string_or_float_opt")]
-    pub total_net_asset_of_btc: Option<f64>,
+    pub total_net_asset_of_btc: Option<Decimal>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -986,9 +1346,9 @@ pub struct AssetDetails {
     pub is_borrowable: bool,
     pub is_mortgageable: bool,
     #[serde(with = "string_or_float")]
-    pub user_min_borrow: f64,
+    pub user_min_borrow: Decimal,
     #[serde(with = "string_or_float")]
-    pub user_min_repay: f64,
+    pub user_min_repay: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1051,7 +1411,7 @@ pub type AllIsolatedPairs = Vec<IsolatedPairDetails>;
 pub struct PriceIndex {
     pub calc_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub symbol: String,
 }
 
@@ -1072,20 +1432,20 @@ pub struct MarginOrderResult {
     pub client_order_id: String,
     pub transact_time: u128,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     pub status: OrderStatus,
     pub time_in_force: TimeInForce,
     #[serde(rename(serialize = "type", deserialize = "type"))]
     pub order_type: OrderType,
     pub side: OrderSide,
     #[serde(default, with = "string_or_float_opt")]
-    pub margin_buy_borrow_amount: Option<f64>,
+    pub margin_buy_borrow_amount: Option<Decimal>,
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
     pub fills: Vec<Fill>,
@@ -1096,21 +1456,21 @@ pub struct MarginOrderResult {
 pub struct MarginOrderState {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Decimal,
     pub is_working: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: OrderSide,
     pub status: OrderStatus,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub symbol: String,
     pub is_isolated: Option<bool>,
     pub time: u64,
@@ -1124,9 +1484,9 @@ pub struct MarginOrderState {
 #[serde(rename_all = "camelCase")]
 pub struct OrderSumaryState {
     pub id: u64,
-    pub price: f64,
-    pub qty: f64,
-    pub quote_qty: f64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
     pub symbol: String,
     pub time: u128,
 }
@@ -1135,7 +1495,7 @@ pub struct OrderSumaryState {
 #[serde(rename_all = "camelCase")]
 pub struct OwnTradesState {
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Decimal,
     pub commission_asset: String,
     pub id: u64,
     pub is_best_match: bool,
@@ -1143,9 +1503,9 @@ pub struct OwnTradesState {
     pub is_maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     pub symbol: String,
     pub time: u128,
     pub is_isolated: bool,
@@ -1155,16 +1515,16 @@ pub struct OwnTradesState {
 #[serde(rename_all = "camelCase")]
 pub struct MaxBorrowableAmount {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(with = "string_or_float")]
-    pub borrow_limit: f64,
+    pub borrow_limit: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MaxTransferableAmount {
     #[serde(with = "string_or_float")]
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1205,6 +1565,8 @@ pub enum OrderStatus {
     Canceled,
     /// (currently unused)
     PendingCancel,
+    /// The pending order of an OTO or OTOCO list, waiting for the working order to fill.
+    PendingNew,
     /// The order was not accepted by the engine and not processed.
     Rejected,
     /// The order was canceled according to the order type's rules (e.g. LIMIT FOK orders with no fill, LIMIT IOC or MARKET orders that partially fill) or by the exchange, (e.g. orders canceled during liquidation, orders canceled during maintenance)
@@ -1249,9 +1611,9 @@ pub struct MarginOCOOrderQuery {
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContingencyType {
     OCO,
+    OTO,
     #[serde(other)]
     Other,
 }
@@ -1327,7 +1689,7 @@ pub struct InterestRateHistoryQuery {
 pub struct InterestRateAssetHistory {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub daily_interest_rate: f64,
+    pub daily_interest_rate: Decimal,
     pub timestamp: u128,
     pub vip_level: u8,
 }
@@ -1337,16 +1699,16 @@ pub type InterestRateHistory = Vec<InterestRateAssetHistory>;
 #[derive(Debug, Clone)]
 pub struct KlineSummary {
     pub open_time: i64,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub volume: f64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
     pub close_time: i64,
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
     pub number_of_trades: i64,
-    pub taker_buy_base_asset_volume: f64,
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
+    pub taker_buy_quote_asset_volume: Decimal,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -1365,6 +1727,7 @@ pub struct IsolatedAccountLimit {
 
 pub mod string_or_float {
     use std::fmt;
+    use std::str::FromStr;
 
     use serde::{de, Deserialize, Deserializer, Serializer};
 
@@ -1376,8 +1739,11 @@ pub mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    /// Parses strings and JSON numbers into any `FromStr` type, such as `Decimal` or `f64`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
     where
+        T: FromStr,
+        T::Err: fmt::Display,
         D: Deserializer<'de>,
     {
         #[derive(Deserialize)]
@@ -1389,7 +1755,7 @@ pub mod string_or_float {
 
         match StringOrFloat::deserialize(deserializer)? {
             StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrFloat::Float(i) => Ok(i),
+            StringOrFloat::Float(f) => f.to_string().parse().map_err(de::Error::custom),
         }
     }
 }
@@ -1397,7 +1763,7 @@ pub mod string_or_float {
 pub(crate) mod string_or_float_opt {
     use std::fmt;
 
-    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::{Deserializer, Serializer};
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1410,17 +1776,12 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
     where
+        T: std::str::FromStr,
+        T::Err: fmt::Display,
         D: Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(()),
-            Float(()),
-        }
-
         Ok(Some(super::string_or_float::deserialize(deserializer)?))
     }
 }
@@ -1458,9 +1819,14 @@ pub(crate) mod string_or_float_opt {
 
 #[cfg(test)]
 mod test {
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use serde::Deserialize;
     use std::path::PathBuf;
 
-    use super::ExchangeInformation;
+    use super::{
+        ContingencyType, ExchangeInformation, KlineInterval, OrderList, OrderSide, OrderStatus,
+    };
 
     #[test]
     fn exchange_info_serde() {
@@ -1470,4 +1836,86 @@ mod test {
         let result = serde_json::from_str::<ExchangeInformation>(&fc);
         assert!(result.is_ok(), "{:?}", result);
     }
+
+    #[test]
+    fn decimal_from_strings_and_numbers() {
+        #[derive(Deserialize)]
+        struct Value {
+            #[serde(with = "super::string_or_float")]
+            v: Decimal,
+        }
+
+        let parse = |json: &str| serde_json::from_str::<Value>(json).unwrap().v;
+        assert_eq!(parse(r#"{"v":"0.1"}"#) + parse(r#"{"v":0.2}"#), dec!(0.3));
+        assert_eq!(parse(r#"{"v":0.0000001}"#), dec!(0.0000001));
+        assert_eq!(parse(r#"{"v":42}"#), dec!(42));
+    }
+
+    #[test]
+    fn kline_interval_parse_and_align() {
+        assert_eq!(
+            "1M".parse::<KlineInterval>().unwrap(),
+            KlineInterval::Month1
+        );
+        assert_eq!(KlineInterval::Hour4.to_string(), "4h");
+        assert_eq!(
+            serde_json::to_string(&KlineInterval::Second1).unwrap(),
+            "\"1s\""
+        );
+        assert!(matches!(
+            "2m".parse::<KlineInterval>(),
+            Err(super::super::errors::Error::InvalidPeriod(_))
+        ));
+
+        // 2024-02-29T13:45:30.5Z, a thursday
+        let time = 1_709_214_330_500;
+        assert_eq!(KlineInterval::Minute15.align(time), 1_709_214_300_000);
+        assert_eq!(KlineInterval::Day1.next_open(time), 1_709_251_200_000);
+        // monday 2024-02-26
+        assert_eq!(KlineInterval::Week1.align(time), 1_708_905_600_000);
+        assert_eq!(KlineInterval::Month1.align(time), 1_706_745_600_000);
+        assert_eq!(KlineInterval::Month1.next_open(time), 1_709_251_200_000);
+        // december rolls over to january
+        assert_eq!(
+            KlineInterval::Month1.next_open(1_703_980_800_000),
+            1_704_067_200_000
+        );
+        // out of chrono's range months count 30 days instead of panicking
+        let month = KlineInterval::Month1.duration().as_millis() as i64;
+        assert_eq!(
+            KlineInterval::Month1.align(i64::MAX),
+            i64::MAX / month * month
+        );
+        assert_eq!(
+            KlineInterval::Month1.next_open(i64::MIN / 2),
+            (i64::MIN / 2).div_euclid(month) * month + month
+        );
+    }
+
+    #[test]
+    fn order_list_serde() {
+        let list: OrderList = serde_json::from_str(
+            r#"{"orderListId":1,"contingencyType":"OTO","listStatusType":"EXEC_STARTED",
+            "listOrderStatus":"EXECUTING","listClientOrderId":"list","transactionTime":1712289389158,
+            "symbol":"BTCUSDT","orders":[{"symbol":"BTCUSDT","orderId":2,"clientOrderId":"w"},
+            {"symbol":"BTCUSDT","orderId":3,"clientOrderId":"p"}],
+            "orderReports":[{"symbol":"BTCUSDT","orderId":2,"orderListId":1,"clientOrderId":"w",
+            "transactTime":1712289389158,"price":"60000","origQty":"0.01","executedQty":"0",
+            "cummulativeQuoteQty":"0","status":"NEW","timeInForce":"GTC","type":"LIMIT",
+            "side":"BUY","workingTime":1712289389158,"selfTradePreventionMode":"NONE"},
+            {"symbol":"BTCUSDT","orderId":3,"orderListId":1,"clientOrderId":"p",
+            "transactTime":1712289389158,"price":"0","origQty":"0.01","executedQty":"0",
+            "cummulativeQuoteQty":"0","status":"PENDING_NEW","timeInForce":"GTC",
+            "type":"STOP_LOSS","side":"SELL","stopPrice":"59000","workingTime":-1,
+            "selfTradePreventionMode":"NONE"}]}"#,
+        )
+        .unwrap();
+        assert!(matches!(list.contingency_type, ContingencyType::OTO));
+        assert!(matches!(list.order_reports[0].side, OrderSide::Buy));
+        assert!(matches!(
+            list.order_reports[1].status,
+            OrderStatus::PendingNew
+        ));
+        assert_eq!(list.order_reports[1].stop_price, dec!(59000));
+    }
 }
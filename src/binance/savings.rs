@@ -2,6 +2,7 @@ use super::client::*;
 use super::errors::*;
 use super::rest_model::*;
 use super::util::*;
+use rust_decimal::Decimal;
 use std::collections::BTreeMap;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -10,24 +11,24 @@ pub struct CoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Decimal,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Decimal,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Decimal,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Decimal,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Decimal,
     pub name: String,
     pub network_list: Vec<Network>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Decimal,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -52,9 +53,9 @@ pub struct Network {
     pub withdraw_desc: Option<String>,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Decimal,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Decimal,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -64,11 +65,11 @@ pub struct Network {
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
+    pub min_withdraw_amount: Decimal,
     /// false if ALL of networks' are false
     pub deposit_status: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Decimal,
     /// false if ALL of networks' are false
     pub withdraw_status: bool,
     /// reason
@@ -93,7 +94,7 @@ impl Savings {
     /// Get all coins available for deposit and withdrawal
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, savings::*, config::*};
+    /// use exrs::binance::{api::*, savings::*, config::*};
     /// let savings: Savings = Binance::new_with_env(&Config::testnet());
     /// let coins = tokio_test::block_on(savings.get_all_coins());
     /// assert!(coins.is_ok(), "{:?}", coins)
@@ -108,7 +109,7 @@ impl Savings {
     /// Fetch details of assets supported on Binance.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, savings::*, config::*};
+    /// use exrs::binance::{api::*, savings::*, config::*};
     /// let savings: Savings = Binance::new_with_env(&Config::testnet());
     /// let coins = tokio_test::block_on(savings.asset_detail(Some("CTR".to_string())));
     /// assert!(coins.is_ok(), "{:?}", coins)
@@ -133,7 +134,7 @@ impl Savings {
     /// If no network is specified, the address for the default network is returned.
     /// # Examples
     /// ```rust,no_run
-    /// use binance::{api::*, savings::*, config::*};
+    /// use exrs::binance::{api::*, savings::*, config::*};
     /// let savings: Savings = Binance::new_with_env(&Config::testnet());
     /// let coins = tokio_test::block_on(savings.deposit_address("CTR", None));
     /// assert!(coins.is_ok(), "{:?}", coins)
@@ -1,7 +1,11 @@
 use std::collections::BTreeMap;
 
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde_json::Value;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::{sleep_until, Instant};
 
 use super::errors::*;
 
@@ -87,6 +91,10 @@ pub fn to_f64(v: &Value) -> f64 {
     v.as_str().unwrap().parse().unwrap()
 }
 
+pub fn to_decimal(v: &Value) -> Decimal {
+    v.as_str().unwrap().parse().unwrap()
+}
+
 pub fn get_timestamp() -> Result<u64> {
     Ok(Utc::now().timestamp_millis() as u64)
 }
@@ -109,3 +117,31 @@ pub fn bool_to_string(b: bool) -> String {
 pub fn bool_to_string_some(b: bool) -> Option<String> {
     Some(bool_to_string(b))
 }
+
+/// Spaces requests evenly so that their total weight stays under a per minute budget.
+/// Shared by reference between the tasks drawing from the same budget.
+pub struct WeightLimiter {
+    weight_per_minute: u32,
+    next_slot: Mutex<Instant>,
+}
+
+impl WeightLimiter {
+    pub fn new(weight_per_minute: u32) -> WeightLimiter {
+        WeightLimiter {
+            weight_per_minute: weight_per_minute.max(1),
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait for the slot of a request of `weight`, the first request goes through at once
+    pub async fn acquire(&self, weight: u32) {
+        let spacing = Duration::from_secs(60) * weight / self.weight_per_minute;
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + spacing;
+            slot
+        };
+        sleep_until(slot).await;
+    }
+}
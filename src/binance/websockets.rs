@@ -1,19 +1,23 @@
 use super::config::*;
 use super::errors::*;
+use super::rest_model::KlineInterval;
 
 use awc::ws::Message;
+use bytes::Bytes;
 use log::debug;
+use std::future::pending;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use actix_codec::Framed;
 use awc::{
     ws::{Codec, Frame},
     BoxedSocket, Client, ClientResponse,
 };
-// use bytes::Bytes;
 use futures_util::{sink::SinkExt as _, stream::StreamExt as _};
 use serde_json::from_slice;
 use tokio::sync::mpsc;
+use tokio::time::{interval_at, sleep_until, Instant, MissedTickBehavior};
 
 pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
@@ -42,7 +46,7 @@ pub fn trade_stream(symbol: &str) -> String {
     format!("{}@trade", symbol)
 }
 
-pub fn kline_stream(symbol: &str, interval: &str) -> String {
+pub fn kline_stream(symbol: &str, interval: KlineInterval) -> String {
     format!("{}@kline_{}", symbol, interval)
 }
 
@@ -83,10 +87,55 @@ pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String {
 //     streams.join("/")
 // }
 
+/// Stream type, used to pick an idle timeout for stale detection
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StreamKind {
+    Depth,
+    Trade,
+    Ticker,
+    UserData,
+}
+
+impl StreamKind {
+    /// Classify a single stream such as `btcusdt@depth@100ms`, `!ticker@arr` or a listen key
+    pub fn from_stream(stream: &str) -> StreamKind {
+        let channel = match stream.strip_prefix('!') {
+            Some(all_market) => all_market.split('@').next(),
+            None => stream.split('@').nth(1),
+        };
+        match channel {
+            None => StreamKind::UserData,
+            Some(c) if c.starts_with("depth") => StreamKind::Depth,
+            Some("trade") | Some("aggTrade") => StreamKind::Trade,
+            Some(_) => StreamKind::Ticker,
+        }
+    }
+
+    /// Idle timeout of an endpoint, the shortest of its streams joined by `/`
+    /// as any message of a combined stream shows the connection is alive
+    pub fn idle_timeout(endpoint: &str, timeouts: &IdleTimeouts) -> Option<Duration> {
+        endpoint
+            .split('/')
+            .filter_map(|stream| StreamKind::from_stream(stream).timeout(timeouts))
+            .min()
+    }
+
+    fn timeout(self, timeouts: &IdleTimeouts) -> Option<Duration> {
+        match self {
+            StreamKind::Depth => timeouts.depth,
+            StreamKind::Trade => timeouts.trade,
+            StreamKind::Ticker => timeouts.ticker,
+            StreamKind::UserData => timeouts.user_data,
+        }
+    }
+}
+
 pub struct WebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
     pub socket: Option<(ClientResponse, Framed<BoxedSocket, Codec>)>,
     sender: mpsc::Sender<WE>,
     conf: Config,
+    idle_timeout: Option<Duration>,
+    last_message: Instant,
 }
 
 impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
@@ -103,8 +152,10 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
     pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> WebSockets<WE> {
         WebSockets {
             socket: None,
-            sender: sender,
+            sender,
             conf,
+            idle_timeout: None,
+            last_message: Instant::now(),
         }
     }
 
@@ -119,6 +170,8 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
         match client.ws(wss).connect().await {
             Ok(answer) => {
                 self.socket = Some(answer);
+                self.idle_timeout = StreamKind::idle_timeout(endpoint, &self.conf.ws_idle_timeouts);
+                self.last_message = Instant::now();
                 Ok(())
             }
             Err(e) => Err(Error::Msg(format!("Error during handshake {}", e))),
@@ -139,37 +192,81 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
         &self.socket
     }
 
+    /// Override the idle timeout picked from the stream type on `connect`
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) {
+        self.idle_timeout = idle_timeout;
+    }
+
+    /// Time elapsed since the last data message was received from the server
+    pub fn idle_time(&self) -> Duration {
+        self.last_message.elapsed()
+    }
+
+    /// Forward every message to the sender until `running` is cleared.
+    /// Sends a ping frame every `ws_ping_interval` and fails with `Error::Stale`
+    /// when no message arrives within the idle timeout of the stream.
     pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        let ping_interval = self.conf.ws_ping_interval;
+        let mut ping = ping_interval.map(|period| {
+            let mut ping = interval_at(Instant::now() + period, period);
+            ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ping
+        });
+
         while running.load(Ordering::Relaxed) {
             if let Some((_, ref mut socket)) = self.socket {
-                let message = socket.next().await;
-                match message {
-                    Some(message) => {
-                        let message = message?;
-                        debug!("event_loop message - {:?}", message);
+                let stale_at = self.idle_timeout.map(|timeout| self.last_message + timeout);
+
+                tokio::select! {
+                    message = socket.next() => {
                         match message {
-                            Frame::Text(msg) => {
-                                if msg.is_empty() {
-                                    return Ok(());
-                                }
-                                let event: WE = from_slice(&msg)?;
+                            Some(message) => {
+                                let message = message?;
+                                debug!("event_loop message - {:?}", message);
+                                match message {
+                                    Frame::Text(msg) => {
+                                        if msg.is_empty() {
+                                            return Ok(());
+                                        }
+                                        self.last_message = Instant::now();
+                                        let event: WE = from_slice(&msg)?;
 
-                                if let Err(e) = self.sender.send(event).await {
-                                    return Err(Error::Msg(format!("{:?}", e)));
+                                        if let Err(e) = self.sender.send(event).await {
+                                            return Err(Error::Msg(format!("{:?}", e)));
+                                        }
+                                    }
+                                    Frame::Ping(msg) => {
+                                        debug!("spot ping msg: {:?}", msg);
+                                        socket.send(Message::Pong(msg)).await?;
+                                    }
+                                    Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {}
+                                    Frame::Close(e) => {
+                                        return Err(Error::Msg(format!("Disconnected {:?}", e)));
+                                    }
                                 }
                             }
-                            Frame::Ping(msg) => {
-                                println!("spot ping msg: {:?}", msg);
-                                socket.send(Message::Pong(msg)).await?;
-                            }
-                            Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {}
-                            Frame::Close(e) => {
-                                return Err(Error::Msg(format!("Disconnected {:?}", e)));
+                            None => {
+                                return Err(Error::Msg(
+                                    "Option::unwrap()` on a `None` value.".to_string(),
+                                ))
                             }
                         }
                     }
-                    None => {
-                        return Err(Error::Msg(format!("Option::unwrap()` on a `None` value.")))
+                    _ = async {
+                        match ping.as_mut() {
+                            Some(ping) => ping.tick().await,
+                            None => pending().await,
+                        }
+                    } => {
+                        socket.send(Message::Ping(Bytes::new())).await?;
+                    }
+                    _ = async {
+                        match stale_at {
+                            Some(deadline) => sleep_until(deadline).await,
+                            None => pending().await,
+                        }
+                    } => {
+                        return Err(Error::Stale(self.last_message.elapsed()));
                     }
                 }
             }
@@ -177,3 +274,110 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// A websocket server that accepts one connection and never sends anything,
+    /// each ping it receives is reported on the returned channel
+    pub(crate) async fn silent_server() -> (String, mpsc::UnboundedReceiver<()>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        let (pings, receiver) = mpsc::unbounded_channel();
+        actix_rt::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buf = [0u8; 1024];
+            while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                let read = stream.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..read]);
+            }
+            let key = String::from_utf8_lossy(&request)
+                .lines()
+                .find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("sec-websocket-key")
+                        .then(|| value.trim().to_string())
+                })
+                .unwrap();
+            let accept = actix_http::ws::hash_key(key.as_bytes());
+            let response = format!(
+                "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                std::str::from_utf8(&accept).unwrap()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+
+            let mut frames = Framed::new(stream, actix_http::ws::Codec::new());
+            while let Some(Ok(frame)) = frames.next().await {
+                if let Frame::Ping(_) = frame {
+                    let _ = pings.send(());
+                }
+            }
+        });
+        (endpoint, receiver)
+    }
+
+    #[actix_rt::test]
+    async fn silent_stream_is_pinged_then_stale() {
+        let (endpoint, mut pings) = silent_server().await;
+        let conf = Config::default()
+            .set_ws_endpoint(endpoint)
+            .set_ws_ping_interval(Some(Duration::from_secs(10)));
+        let (sender, _receiver) = mpsc::channel::<serde_json::Value>(16);
+        let mut web_socket = WebSockets::new_with_options(sender, conf);
+        web_socket.connect("btcusdt@trade").await.unwrap();
+        web_socket.set_idle_timeout(Some(Duration::from_secs(25)));
+
+        tokio::time::pause();
+        let result = web_socket.event_loop(&AtomicBool::new(true)).await;
+        assert!(
+            // timers have a millisecond resolution
+            matches!(result, Err(Error::Stale(idle))
+                if (Duration::from_secs(25)..Duration::from_millis(25_010)).contains(&idle)),
+            "{:?}",
+            result
+        );
+        // pinged at 10s and 20s
+        pings.recv().await.unwrap();
+        pings.recv().await.unwrap();
+        assert!(pings.try_recv().is_err());
+    }
+
+    #[test]
+    fn stream_kind_and_idle_timeout() {
+        assert_eq!(
+            StreamKind::from_stream("btcusdt@depth@100ms"),
+            StreamKind::Depth
+        );
+        assert_eq!(StreamKind::from_stream("btcusdt@depth5"), StreamKind::Depth);
+        assert_eq!(
+            StreamKind::from_stream("btcusdt@aggTrade"),
+            StreamKind::Trade
+        );
+        assert_eq!(
+            StreamKind::from_stream("btcusdt@kline_1m"),
+            StreamKind::Ticker
+        );
+        assert_eq!(StreamKind::from_stream("!ticker@arr"), StreamKind::Ticker);
+        assert_eq!(StreamKind::from_stream("!bookTicker"), StreamKind::Ticker);
+        assert_eq!(
+            StreamKind::from_stream(
+                "pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+            ),
+            StreamKind::UserData
+        );
+
+        let timeouts = IdleTimeouts::default();
+        assert_eq!(
+            StreamKind::idle_timeout("btcusdt@trade", &timeouts),
+            timeouts.trade
+        );
+        assert_eq!(
+            StreamKind::idle_timeout("btcusdt@trade/btcusdt@depth", &timeouts),
+            timeouts.depth
+        );
+        assert_eq!(StreamKind::idle_timeout("listenkey", &timeouts), None);
+    }
+}
@@ -1,6 +1,8 @@
 use super::rest_model::{
-    string_or_float, Asks, Bids, OrderBookPartial, OrderSide, OrderStatus, OrderType, TimeInForce,
+    string_or_float, Asks, Bids, KlineInterval, OrderBookPartial, OrderSide, OrderStatus,
+    OrderType, TimeInForce,
 };
+use rust_decimal::Decimal;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "e")]
@@ -45,11 +47,11 @@ pub struct TradesEvent {
     #[serde(rename = "a")]
     pub aggregated_trade_id: u64,
 
-    #[serde(rename = "p")]
-    pub price: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
 
-    #[serde(rename = "q")]
-    pub qty: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Decimal,
 
     #[serde(rename = "f")]
     pub first_break_trade_id: u64,
@@ -79,11 +81,11 @@ pub struct TradeEvent {
     #[serde(rename = "t")]
     pub trade_id: u64,
 
-    #[serde(rename = "p")]
-    pub price: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
 
-    #[serde(rename = "q")]
-    pub qty: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Decimal,
 
     #[serde(rename = "b")]
     pub buyer_order_id: u64,
@@ -108,36 +110,36 @@ pub struct DayTickerEvent {
     pub event_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "p")]
-    pub price_change: String,
-    #[serde(rename = "P")]
-    pub price_change_percent: String,
-    #[serde(rename = "w")]
-    pub average_price: String,
-    #[serde(rename = "x")]
-    pub prev_close: String,
-    #[serde(rename = "c")]
-    pub current_close: String,
-    #[serde(rename = "Q")]
-    pub current_close_qty: String,
-    #[serde(rename = "b")]
-    pub best_bid: String,
-    #[serde(rename = "B")]
-    pub best_bid_qty: String,
-    #[serde(rename = "a")]
-    pub best_ask: String,
-    #[serde(rename = "A")]
-    pub best_ask_qty: String,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "w", with = "string_or_float")]
+    pub average_price: Decimal,
+    #[serde(rename = "x", with = "string_or_float")]
+    pub prev_close: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub current_close: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub current_close_qty: Decimal,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub best_bid: Decimal,
+    #[serde(rename = "B", with = "string_or_float")]
+    pub best_bid_qty: Decimal,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub best_ask: Decimal,
+    #[serde(rename = "A", with = "string_or_float")]
+    pub best_ask_qty: Decimal,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
     #[serde(rename = "O")]
     pub open_time: u64,
     #[serde(rename = "C")]
@@ -157,18 +159,18 @@ pub struct MiniDayTickerEvent {
     pub event_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "c")]
-    pub current_close: String,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub current_close: Decimal,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -192,31 +194,31 @@ pub struct Kline {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "i")]
-    pub interval: String,
+    pub interval: KlineInterval,
     #[serde(rename = "f")]
     pub first_trade_id: i64,
     #[serde(rename = "L")]
     pub last_trade_id: i64,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "c")]
-    pub close: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
     #[serde(rename = "n")]
     pub number_of_trades: i64,
     #[serde(rename = "x")]
     pub is_final_bar: bool,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
-    #[serde(rename = "V")]
-    pub active_buy_volume: String,
-    #[serde(rename = "Q")]
-    pub active_volume_buy_quote: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub active_buy_volume: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub active_volume_buy_quote: Decimal,
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
 }
@@ -248,16 +250,16 @@ pub struct BookTickerEvent {
     pub symbol: String,
 
     #[serde(rename = "b", with = "string_or_float")]
-    pub best_bid: f64,
+    pub best_bid: Decimal,
 
     #[serde(rename = "B", with = "string_or_float")]
-    pub best_bid_qty: f64,
+    pub best_bid_qty: Decimal,
 
     #[serde(rename = "a", with = "string_or_float")]
-    pub best_ask: f64,
+    pub best_ask: Decimal,
 
     #[serde(rename = "A", with = "string_or_float")]
-    pub best_ask_qty: f64,
+    pub best_ask_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -266,7 +268,6 @@ pub struct CombinedStreamEvent<T> {
     pub data: T,
 }
 
-///
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebsocketEventUntag {
@@ -287,7 +288,7 @@ impl<T> CombinedStreamEvent<T> {
     }
 }
 
-/// User Stream related events
+// User Stream related events
 
 /// Account position update
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -338,10 +339,10 @@ pub struct AccountUpdate {
 pub struct EventBalance {
     #[serde(rename = "a")]
     pub asset: String,
-    #[serde(rename = "f")]
-    pub free: String,
-    #[serde(rename = "l")]
-    pub locked: String,
+    #[serde(rename = "f", with = "string_or_float")]
+    pub free: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub locked: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -355,7 +356,7 @@ pub struct BalanceUpdate {
 
     #[serde(rename = "d")]
     #[serde(with = "string_or_float")]
-    pub delta: f64,
+    pub delta: Decimal,
 
     #[serde(alias = "T")]
     pub clear_time: u64,
@@ -378,16 +379,16 @@ pub struct OrderUpdate {
     pub time_in_force: TimeInForce,
     #[serde(rename = "q")]
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(rename = "p")]
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "P")]
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     #[serde(rename = "F")]
     #[serde(with = "string_or_float")]
-    pub iceberg_qty: f64,
+    pub iceberg_qty: Decimal,
     #[serde(rename = "g")]
     pub order_list_id: i64,
     #[serde(rename = "C")]
@@ -402,16 +403,16 @@ pub struct OrderUpdate {
     pub order_id: u64,
     #[serde(rename = "l")]
     #[serde(with = "string_or_float")]
-    pub qty_last_executed: f64,
+    pub qty_last_executed: Decimal,
     #[serde(rename = "z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_filled_qty: f64,
+    pub cumulative_filled_qty: Decimal,
     #[serde(rename = "L")]
     #[serde(with = "string_or_float")]
-    pub last_executed_price: f64,
+    pub last_executed_price: Decimal,
     #[serde(rename = "n")]
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Decimal,
     #[serde(rename = "N")]
     pub commission_asset: Option<String>,
     #[serde(rename = "T")]
@@ -430,14 +431,14 @@ pub struct OrderUpdate {
     pub order_creation_time: u64,
     #[serde(rename = "Z")]
     #[serde(with = "string_or_float")]
-    pub cumulative_quote_asset_transacted_qty: f64,
+    pub cumulative_quote_asset_transacted_qty: Decimal,
     /// (i.e. lastPrice * lastQty)
     #[serde(rename = "Y")]
     #[serde(with = "string_or_float")]
-    pub last_quote_asset_transacted_qty: f64,
+    pub last_quote_asset_transacted_qty: Decimal,
     #[serde(rename = "Q")]
     #[serde(with = "string_or_float")]
-    pub quote_order_qty: f64,
+    pub quote_order_qty: Decimal,
 }
 
 /// For OCO Events
@@ -448,18 +449,19 @@ pub struct OrderListUpdate {
     pub event_time: u64,
     #[serde(rename = "S")]
     pub symbol: String,
+    /// `order_list_id` of the `OrderList` returned when placing the list
     #[serde(rename = "g")]
-    order_list_id: i64,
+    pub order_list_id: i64,
     #[serde(rename = "c")]
-    contingency_type: String,
+    pub contingency_type: String,
     #[serde(rename = "l")]
-    list_status_type: String,
+    pub list_status_type: String,
     #[serde(rename = "L")]
-    list_order_status: String,
+    pub list_order_status: String,
     #[serde(rename = "r")]
-    list_reject_reason: String,
+    pub list_reject_reason: String,
     #[serde(rename = "C")]
-    list_client_order_id: String,
+    pub list_client_order_id: String,
     #[serde(rename = "T")]
     pub transaction_time: u64,
     #[serde(rename = "O")]
@@ -474,5 +476,5 @@ pub struct OrderListTransaction {
     #[serde(rename = "i")]
     pub order_id: i64,
     #[serde(rename = "c")]
-    pub client_order_id: i64,
+    pub client_order_id: String,
 }
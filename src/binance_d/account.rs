@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::account::{OrderCancellation, WorkingType};
+use crate::binance_f::client::Client;
+use crate::binance_f::util::*;
+use rust_decimal::Decimal;
+
+static DAPI_ORDER: &str = "/dapi/v1/order";
+static DAPI_OPEN_ORDERS: &str = "/dapi/v1/openOrders";
+static DAPI_ALL_OPEN_ORDERS: &str = "/dapi/v1/allOpenOrders";
+static DAPI_POSITION_RISK: &str = "/dapi/v1/positionRisk";
+static DAPI_BALANCE: &str = "/dapi/v1/balance";
+static DAPI_LEVERAGE: &str = "/dapi/v1/leverage";
+static DAPI_POSITION_SIDE_DUAL: &str = "/dapi/v1/positionSide/dual";
+
+#[derive(Clone)]
+pub struct DeliveryAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Quantities are whole contracts
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OrderRequest {
+    pub new_client_order_id: Option<String>,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub position_side: Option<PositionSide>,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(rename = "quantity")]
+    pub qty: Option<u64>,
+    pub reduce_only: Option<bool>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
+    pub close_position: Option<bool>,
+    pub working_type: Option<WorkingType>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PositionQuery {
+    pub pair: Option<String>,
+}
+
+impl DeliveryAccount {
+    async fn post_order(&self, order: OrderRequest) -> Result<Order> {
+        self.client
+            .post_signed_p(DAPI_ORDER, order, self.recv_window)
+            .await
+    }
+
+    fn limit_order(
+        new_client_order_id: String,
+        symbol: String,
+        side: OrderSide,
+        contracts: u64,
+        price: Decimal,
+        position_side: PositionSide,
+        time_in_force: TimeInForce,
+    ) -> OrderRequest {
+        OrderRequest {
+            new_client_order_id: Some(new_client_order_id),
+            symbol,
+            side,
+            position_side: Some(position_side),
+            order_type: OrderType::Limit,
+            time_in_force: Some(time_in_force),
+            qty: Some(contracts),
+            reduce_only: None,
+            price: Some(price),
+            stop_price: None,
+            close_position: None,
+            working_type: None,
+        }
+    }
+
+    fn market_order(
+        symbol: String,
+        side: OrderSide,
+        contracts: u64,
+        position_side: PositionSide,
+    ) -> OrderRequest {
+        OrderRequest {
+            new_client_order_id: None,
+            symbol,
+            side,
+            position_side: Some(position_side),
+            order_type: OrderType::Market,
+            time_in_force: None,
+            qty: Some(contracts),
+            reduce_only: None,
+            price: None,
+            stop_price: None,
+            close_position: None,
+            working_type: None,
+        }
+    }
+
+    /// Place a LIMIT order - BUY, for a number of contracts
+    pub async fn limit_buy(
+        &self,
+        new_client_order_id: impl Into<String>,
+        symbol: impl Into<String>,
+        contracts: u64,
+        price: impl Into<Decimal>,
+        position_side: PositionSide,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        let order = Self::limit_order(
+            new_client_order_id.into(),
+            symbol.into(),
+            OrderSide::Buy,
+            contracts,
+            price.into(),
+            position_side,
+            time_in_force,
+        );
+        self.post_order(order).await
+    }
+
+    /// Place a LIMIT order - SELL, for a number of contracts
+    pub async fn limit_sell(
+        &self,
+        new_client_order_id: impl Into<String>,
+        symbol: impl Into<String>,
+        contracts: u64,
+        price: impl Into<Decimal>,
+        position_side: PositionSide,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        let order = Self::limit_order(
+            new_client_order_id.into(),
+            symbol.into(),
+            OrderSide::Sell,
+            contracts,
+            price.into(),
+            position_side,
+            time_in_force,
+        );
+        self.post_order(order).await
+    }
+
+    /// Place a MARKET order - BUY, for a number of contracts
+    pub async fn market_buy<S>(
+        &self,
+        symbol: S,
+        contracts: u64,
+        position_side: PositionSide,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let order = Self::market_order(symbol.into(), OrderSide::Buy, contracts, position_side);
+        self.post_order(order).await
+    }
+
+    /// Place a MARKET order - SELL, for a number of contracts
+    pub async fn market_sell<S>(
+        &self,
+        symbol: S,
+        contracts: u64,
+        position_side: PositionSide,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let order = Self::market_order(symbol.into(), OrderSide::Sell, contracts, position_side);
+        self.post_order(order).await
+    }
+
+    /// Place a cancellation order
+    pub async fn cancel_order(&self, o: OrderCancellation) -> Result<Order> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p(DAPI_ORDER, &o, recv_window)
+            .await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<CancelAllOpenOrdersResponse>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p(
+                DAPI_ALL_OPEN_ORDERS,
+                PairQuery {
+                    symbol: symbol.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    pub async fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                DAPI_OPEN_ORDERS,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Positions of every contract, or of the contracts of `pair`
+    pub async fn position_information<S>(&self, pair: Option<S>) -> Result<Vec<Position>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                DAPI_POSITION_RISK,
+                Some(PositionQuery {
+                    pair: pair.map(Into::into),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(DAPI_BALANCE, request.as_str())
+            .await
+    }
+
+    pub async fn change_initial_leverage<S>(
+        &self,
+        symbol: S,
+        leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("leverage".into(), leverage.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_d(DAPI_LEVERAGE, request.as_str())
+            .await
+    }
+
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("dualSidePosition".into(), dual_side_position.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_d::<Empty>(DAPI_POSITION_SIDE_DUAL, request.as_str())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_position_mode(&self) -> Result<PositionModeResponse> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(DAPI_POSITION_SIDE_DUAL, request.as_str())
+            .await
+    }
+}
@@ -0,0 +1,94 @@
+use super::account::*;
+use super::config::Config;
+use super::general::*;
+use super::market::*;
+use super::userstream::*;
+use crate::binance_f::client::*;
+
+pub trait BinanceD: Sized {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    /// Create a binance API using environment variables for credentials
+    /// BINANCE_API_KEY=<your api key>
+    /// BINANCE_API_SECRET_KEY=<your secret key>
+    fn new_with_env(config: &Config) -> Self {
+        let api_key = std::env::var("BINANCE_API_KEY").ok();
+        let secret = std::env::var("BINANCE_API_SECRET_KEY").ok();
+        Self::new_with_config(api_key, secret, config)
+    }
+
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> Self;
+}
+
+impl BinanceD for DeliveryGeneral {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> DeliveryGeneral {
+        DeliveryGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+        }
+    }
+}
+
+impl BinanceD for DeliveryMarket {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> DeliveryMarket {
+        DeliveryMarket {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl BinanceD for DeliveryAccount {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> DeliveryAccount {
+        DeliveryAccount {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl BinanceD for DeliveryUserStream {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> DeliveryUserStream {
+        DeliveryUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.delivery_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
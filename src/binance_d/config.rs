@@ -0,0 +1,85 @@
+use crate::binance_f::config::{Config as FuturesConfig, IdleTimeouts};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub delivery_rest_api_endpoint: String,
+    pub delivery_ws_endpoint: String,
+
+    pub recv_window: u64,
+    /// Interval at which the websocket client sends its own ping frames
+    pub ws_ping_interval: Option<Duration>,
+    /// How long a stream may go without a message before it is considered stale
+    pub ws_idle_timeouts: IdleTimeouts,
+}
+
+impl Default for Config {
+    /// Configure binance with default production endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_d::config::Config;
+    /// let config = Config::default();
+    /// ```
+    fn default() -> Config {
+        Config {
+            delivery_rest_api_endpoint: "https://dapi.binance.com".into(),
+            delivery_ws_endpoint: "wss://dstream.binance.com".into(),
+
+            recv_window: 5000,
+            ws_ping_interval: Some(Duration::from_secs(30)),
+            ws_idle_timeouts: IdleTimeouts::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Configure binance with all testnet endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_d::config::Config;
+    /// let config = Config::testnet();
+    /// ```
+    pub fn testnet() -> Config {
+        Config::default()
+            .set_delivery_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_delivery_ws_endpoint("wss://dstream.binancefuture.com")
+    }
+
+    pub fn set_delivery_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        delivery_rest_api_endpoint: T,
+    ) -> Self {
+        self.delivery_rest_api_endpoint = delivery_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_delivery_ws_endpoint<T: Into<String>>(mut self, delivery_ws_endpoint: T) -> Self {
+        self.delivery_ws_endpoint = delivery_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Set the client ping interval, `None` only answers server pings
+    pub fn set_ws_ping_interval(mut self, ws_ping_interval: Option<Duration>) -> Self {
+        self.ws_ping_interval = ws_ping_interval;
+        self
+    }
+
+    pub fn set_ws_idle_timeouts(mut self, ws_idle_timeouts: IdleTimeouts) -> Self {
+        self.ws_idle_timeouts = ws_idle_timeouts;
+        self
+    }
+
+    /// Websocket settings in the shape expected by `FuturesWebSockets`
+    pub(crate) fn ws_config(&self) -> FuturesConfig {
+        FuturesConfig::default()
+            .set_futures_ws_endpoint(self.delivery_ws_endpoint.clone())
+            .set_recv_window(self.recv_window)
+            .set_ws_ping_interval(self.ws_ping_interval)
+            .set_ws_idle_timeouts(self.ws_idle_timeouts.clone())
+    }
+}
@@ -0,0 +1,42 @@
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+#[derive(Clone)]
+pub struct DeliveryGeneral {
+    pub client: Client,
+}
+
+impl DeliveryGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client.get("/dapi/v1/ping", "").await?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get_p("/dapi/v1/time", "").await
+    }
+
+    // Obtain exchange information
+    // - Current exchange trading rules and contract information
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get_p("/dapi/v1/exchangeInfo", "").await
+    }
+
+    // Get contract information, `symbol` is a contract such as BTCUSD_PERP
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    where
+        S: Into<String>,
+    {
+        let symbol_string = symbol.into();
+        let upper_symbol = symbol_string.to_uppercase();
+
+        let info = self.exchange_info().await?;
+        info.symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+            .ok_or(Error::UnknownSymbol(symbol_string))
+    }
+}
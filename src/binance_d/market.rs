@@ -0,0 +1,501 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Recent Trades List`
+- [x] `Compressed/Aggregate Trades List`
+- [x] `Kline/Candlestick Data`
+- [x] `Continuous Contract Kline/Candlestick Data`
+- [x] `Index Price Kline/Candlestick Data`
+- [x] `Mark Price Kline/Candlestick Data`
+- [x] `Index Price and Mark Price`
+- [x] `Get Funding Rate History of Perpetual Futures`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Symbol Price Ticker`
+- [x] `Symbol Order Book Ticker`
+- [x] `Open Interest`
+- [x] `Open Interest Statistics`
+- [x] `Basis`
+*/
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+use crate::binance_f::util::{to_decimal, to_i64};
+use serde_json::Value;
+
+#[derive(Clone)]
+pub struct DeliveryMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SymbolOrPairQuery {
+    symbol: Option<String>,
+    pair: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KlineQuery {
+    symbol: Option<String>,
+    pair: Option<String>,
+    contract_type: Option<ContractType>,
+    interval: KlineInterval,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    limit: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HistoryQuery {
+    symbol: Option<String>,
+    pair: Option<String>,
+    contract_type: Option<ContractType>,
+    period: Option<KlineInterval>,
+    from_id: Option<u64>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    limit: Option<u16>,
+}
+
+impl DeliveryMarket {
+    // Order book (Default 500; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBookPartial>
+    where
+        S: Into<String>,
+    {
+        self.get_custom_depth(symbol, 500).await
+    }
+
+    // Order book with a custom depth: 5, 10, 20, 50, 100, 500 or 1000
+    pub async fn get_custom_depth<S>(&self, symbol: S, limit: u16) -> Result<OrderBookPartial>
+    where
+        S: Into<String>,
+    {
+        let request = format!("symbol={}&limit={}", symbol.into(), limit);
+        self.client.get_p("/dapi/v1/depth", &request).await
+    }
+
+    /// Latest trades of a contract
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                "/dapi/v1/trades",
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Aggregate trades from `from_id` or between `start_time` and `end_time`
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        start_time: S3,
+        end_time: S4,
+        limit: S5,
+    ) -> Result<Vec<AggTrade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let query = HistoryQuery {
+            symbol: Some(symbol.into()),
+            pair: None,
+            contract_type: None,
+            period: None,
+            from_id: from_id.into(),
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        self.client.get_d("/dapi/v1/aggTrades", Some(query)).await
+    }
+
+    /// Klines of a contract, the volume is in contracts
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_d::{api::*, market::*, config::*, rest_model::KlineInterval};
+    /// let market: DeliveryMarket = BinanceD::new_with_env(&Config::default());
+    /// let klines = tokio_test::block_on(market.get_klines("BTCUSD_PERP", KlineInterval::Minute1, 10, None, None));
+    /// assert!(klines.is_ok(), "{:?}", klines);
+    /// ```
+    pub async fn get_klines<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+    {
+        self.klines(
+            "/dapi/v1/klines",
+            KlineQuery {
+                symbol: Some(symbol.into()),
+                pair: None,
+                contract_type: None,
+                interval,
+                start_time: start_time.into(),
+                end_time: end_time.into(),
+                limit: limit.into(),
+            },
+        )
+        .await
+    }
+
+    /// Klines of the contract of `contract_type` on `pair`, rolling over deliveries
+    pub async fn get_continuous_contract_klines<S1, S2, S3, S4>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+    {
+        self.klines(
+            "/dapi/v1/continuousKlines",
+            KlineQuery {
+                symbol: None,
+                pair: Some(pair.into()),
+                contract_type: Some(contract_type),
+                interval,
+                start_time: start_time.into(),
+                end_time: end_time.into(),
+                limit: limit.into(),
+            },
+        )
+        .await
+    }
+
+    /// Index price klines of `pair`, volumes are zero
+    pub async fn get_index_price_klines<S1, S2, S3, S4>(
+        &self,
+        pair: S1,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+    {
+        self.klines(
+            "/dapi/v1/indexPriceKlines",
+            KlineQuery {
+                symbol: None,
+                pair: Some(pair.into()),
+                contract_type: None,
+                interval,
+                start_time: start_time.into(),
+                end_time: end_time.into(),
+                limit: limit.into(),
+            },
+        )
+        .await
+    }
+
+    /// Mark price klines of a contract, volumes are zero
+    pub async fn get_mark_price_klines<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+    {
+        self.klines(
+            "/dapi/v1/markPriceKlines",
+            KlineQuery {
+                symbol: Some(symbol.into()),
+                pair: None,
+                contract_type: None,
+                interval,
+                start_time: start_time.into(),
+                end_time: end_time.into(),
+                limit: limit.into(),
+            },
+        )
+        .await
+    }
+
+    async fn klines(&self, endpoint: &str, query: KlineQuery) -> Result<Vec<Kline>> {
+        if !INTERVALS.contains(&query.interval) {
+            return Err(Error::InvalidPeriod(query.interval.to_string()));
+        }
+        let rows: Vec<Vec<Value>> = self.client.get_d(endpoint, Some(query)).await?;
+        Ok(rows.iter().map(|row| kline(row)).collect())
+    }
+
+    /// Mark price, index price and funding rate of a contract
+    pub async fn get_premium_index<S>(&self, symbol: S) -> Result<Vec<PremiumIndex>>
+    where
+        S: Into<String>,
+    {
+        self.premium_index(SymbolOrPairQuery {
+            symbol: Some(symbol.into()),
+            pair: None,
+        })
+        .await
+    }
+
+    /// Mark price, index price and funding rate of every contract of `pair`
+    pub async fn get_pair_premium_index<S>(&self, pair: S) -> Result<Vec<PremiumIndex>>
+    where
+        S: Into<String>,
+    {
+        self.premium_index(SymbolOrPairQuery {
+            symbol: None,
+            pair: Some(pair.into()),
+        })
+        .await
+    }
+
+    async fn premium_index(&self, query: SymbolOrPairQuery) -> Result<Vec<PremiumIndex>> {
+        self.client
+            .get_d("/dapi/v1/premiumIndex", Some(query))
+            .await
+    }
+
+    /// Funding rate history of a perpetual contract
+    pub async fn get_funding_rate_history<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let query = HistoryQuery {
+            symbol: Some(symbol.into()),
+            pair: None,
+            contract_type: None,
+            period: None,
+            from_id: None,
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        self.client.get_d("/dapi/v1/fundingRate", Some(query)).await
+    }
+
+    /// 24hr ticker price change statistics of a contract
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.ticker(
+            "/dapi/v1/ticker/24hr",
+            SymbolOrPairQuery {
+                symbol: Some(symbol.into()),
+                pair: None,
+            },
+        )
+        .await
+    }
+
+    /// 24hr ticker price change statistics of every contract of `pair`
+    pub async fn get_pair_24h_price_stats<S>(&self, pair: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.ticker(
+            "/dapi/v1/ticker/24hr",
+            SymbolOrPairQuery {
+                symbol: None,
+                pair: Some(pair.into()),
+            },
+        )
+        .await
+    }
+
+    /// 24hr ticker price change statistics of all contracts
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.ticker("/dapi/v1/ticker/24hr", SymbolOrPairQuery::default())
+            .await
+    }
+
+    /// Latest price of a contract
+    pub async fn get_price<S>(&self, symbol: S) -> Result<Vec<SymbolPrice>>
+    where
+        S: Into<String>,
+    {
+        self.ticker(
+            "/dapi/v1/ticker/price",
+            SymbolOrPairQuery {
+                symbol: Some(symbol.into()),
+                pair: None,
+            },
+        )
+        .await
+    }
+
+    /// Latest price of all contracts
+    pub async fn get_all_prices(&self) -> Result<Vec<SymbolPrice>> {
+        self.ticker("/dapi/v1/ticker/price", SymbolOrPairQuery::default())
+            .await
+    }
+
+    /// Best price/qty on the order book of a contract
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Vec<BookTicker>>
+    where
+        S: Into<String>,
+    {
+        self.ticker(
+            "/dapi/v1/ticker/bookTicker",
+            SymbolOrPairQuery {
+                symbol: Some(symbol.into()),
+                pair: None,
+            },
+        )
+        .await
+    }
+
+    /// Best price/qty on the order book of all contracts
+    pub async fn get_all_book_tickers(&self) -> Result<Vec<BookTicker>> {
+        self.ticker("/dapi/v1/ticker/bookTicker", SymbolOrPairQuery::default())
+            .await
+    }
+
+    async fn ticker<T>(&self, endpoint: &str, query: SymbolOrPairQuery) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.client.get_d(endpoint, Some(query)).await
+    }
+
+    /// Open interest of a contract, in contracts
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                "/dapi/v1/openInterest",
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Open interest statistics of the contracts of `contract_type` on `pair`,
+    /// only the last 30 days are available
+    pub async fn get_open_interest_history<S1, S2, S3, S4>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<OpenInterestHistory>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        if !PERIODS.contains(&period) {
+            return Err(Error::InvalidPeriod(period.to_string()));
+        }
+        let query = HistoryQuery {
+            symbol: None,
+            pair: Some(pair.into()),
+            contract_type: Some(contract_type),
+            period: Some(period),
+            from_id: None,
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        self.client
+            .get_d("/futures/data/openInterestHist", Some(query))
+            .await
+    }
+
+    /// Basis of the contracts of `contract_type` on `pair`, only the last 30 days are available
+    pub async fn get_basis<S1, S2, S3, S4>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<Basis>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        if !PERIODS.contains(&period) {
+            return Err(Error::InvalidPeriod(period.to_string()));
+        }
+        let query = HistoryQuery {
+            symbol: None,
+            pair: Some(pair.into()),
+            contract_type: Some(contract_type),
+            period: Some(period),
+            from_id: None,
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        self.client.get_d("/futures/data/basis", Some(query)).await
+    }
+}
+
+fn kline(row: &[Value]) -> Kline {
+    Kline {
+        open_time: to_i64(&row[0]),
+        open: to_decimal(&row[1]),
+        high: to_decimal(&row[2]),
+        low: to_decimal(&row[3]),
+        close: to_decimal(&row[4]),
+        volume: to_decimal(&row[5]),
+        close_time: to_i64(&row[6]),
+        base_asset_volume: to_decimal(&row[7]),
+        number_of_trades: to_i64(&row[8]),
+        taker_buy_volume: to_decimal(&row[9]),
+        taker_buy_base_asset_volume: to_decimal(&row[10]),
+    }
+}
@@ -0,0 +1,23 @@
+/*!
+COIN-margined futures, `dapi.binance.com` and `dstream.binance.com`
+
+Contracts are inverse: quantities are a number of contracts of `contract_size`
+USD each, PnL, margin and notional values are in the base asset.
+`symbol` names a contract (BTCUSD_PERP, BTCUSD_240628) while `pair` names the
+underlying (BTCUSD), which several contracts share.
+
+The REST client, errors and websocket event loop are those of `binance_f`.
+*/
+
+pub use crate::binance_f::errors;
+
+pub mod rest_model;
+pub mod ws_model;
+
+pub mod account;
+pub mod api;
+pub mod config;
+pub mod general;
+pub mod market;
+pub mod userstream;
+pub mod websockets;
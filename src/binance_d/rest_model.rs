@@ -0,0 +1,341 @@
+use crate::binance_f::rest_model::{string_or_bool, string_or_float, string_or_float_opt};
+use rust_decimal::Decimal;
+
+pub use crate::binance_f::account::{ContractType, PositionSide};
+pub use crate::binance_f::rest_model::{
+    AggTrade, Asks, Basis, Bids, CancelAllOpenOrdersResponse, Empty, Filters, FundingRate,
+    KlineInterval, OrderBookPartial, OrderSide, OrderType, PairQuery, PositionModeResponse,
+    RateLimit, ServerTime, Success, TimeInForce, UserDataStream, INTERVALS, PERIODS,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub rate_limits: Vec<RateLimit>,
+    pub exchange_filters: Vec<String>,
+    pub symbols: Vec<Symbol>,
+}
+
+/// A delivery or perpetual contract
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: String,
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    /// Value of one contract in the quote asset, 100 USD for BTCUSD and 10 USD for most others
+    pub contract_size: u32,
+    pub margin_asset: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u32,
+    pub quantity_precision: u32,
+    pub base_asset_precision: u32,
+    pub quote_precision: u32,
+    #[serde(default)]
+    pub equal_qty_precision: u32,
+    #[serde(with = "string_or_float")]
+    pub maint_margin_percent: Decimal,
+    #[serde(with = "string_or_float")]
+    pub required_margin_percent: Decimal,
+    #[serde(with = "string_or_float")]
+    pub trigger_protect: Decimal,
+    #[serde(default, with = "string_or_float_opt")]
+    pub liquidation_fee: Option<Decimal>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub market_take_bound: Option<Decimal>,
+    pub underlying_type: String,
+    #[serde(default)]
+    pub underlying_sub_type: Vec<String>,
+    pub filters: Vec<Filters>,
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+impl Symbol {
+    /// Value in the base asset of `contracts` at `price`
+    pub fn base_value(&self, contracts: Decimal, price: Decimal) -> Decimal {
+        contracts * Decimal::from(self.contract_size) / price
+    }
+
+    /// Number of contracts worth `base_qty` of the base asset at `price`, not rounded
+    pub fn contracts_for(&self, base_qty: Decimal, price: Decimal) -> Decimal {
+        base_qty * price / Decimal::from(self.contract_size)
+    }
+
+    /// PnL in the base asset of `contracts` (negative when short) opened at `entry_price`
+    pub fn pnl(&self, contracts: Decimal, entry_price: Decimal, exit_price: Decimal) -> Decimal {
+        contracts
+            * Decimal::from(self.contract_size)
+            * (Decimal::ONE / entry_price - Decimal::ONE / exit_price)
+    }
+}
+
+/// Kline of a contract, the volume is in contracts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    pub open_time: i64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub close_time: i64,
+    pub base_asset_volume: Decimal,
+    pub number_of_trades: i64,
+    pub taker_buy_volume: Decimal,
+    pub taker_buy_base_asset_volume: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub is_buyer_maker: bool,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Decimal,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub last_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub open_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub high_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub low_price: Decimal,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(with = "string_or_float")]
+    pub base_volume: Decimal,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPrice {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub bid_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub ask_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub ask_qty: Decimal,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub index_price: Decimal,
+    #[serde(default, with = "string_or_float_opt")]
+    pub estimated_settle_price: Option<Decimal>,
+    /// Empty for delivery contracts
+    #[serde(default, with = "string_or_float_opt")]
+    pub last_funding_rate: Option<Decimal>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest_rate: Option<Decimal>,
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub open_interest: Decimal,
+    pub contract_type: ContractType,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHistory {
+    pub pair: String,
+    pub contract_type: ContractType,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: Decimal,
+    /// Base asset
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_value: Decimal,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub status: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Decimal,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Decimal,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cum_qty: Option<Decimal>,
+    /// Base asset
+    #[serde(with = "string_or_float")]
+    pub cum_base: Decimal,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub reduce_only: bool,
+    pub close_position: bool,
+    pub side: String,
+    pub position_side: String,
+    #[serde(default, with = "string_or_float_opt")]
+    pub stop_price: Option<Decimal>,
+    pub working_type: String,
+    pub price_protect: bool,
+    pub orig_type: String,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    /// Contracts, negative when short
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Decimal,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Decimal,
+    #[serde(default, with = "string_or_float_opt")]
+    pub break_even_price: Option<Decimal>,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Decimal,
+    /// Base asset
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: Decimal,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: Decimal,
+    pub leverage: String,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub max_qty: Decimal,
+    pub margin_type: String,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: Decimal,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: String,
+    /// Base asset
+    #[serde(with = "string_or_float")]
+    pub notional_value: Decimal,
+    #[serde(default, with = "string_or_float_opt")]
+    pub isolated_wallet: Option<Decimal>,
+    #[serde(default)]
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: Decimal,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: Decimal,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Decimal,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: Decimal,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Decimal,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub max_qty: Decimal,
+    pub symbol: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn inverse_contract_math() {
+        let symbol: Symbol = serde_json::from_str(
+            r#"{"symbol":"BTCUSD_PERP","pair":"BTCUSD","contractType":"PERPETUAL",
+            "deliveryDate":4133404800000,"onboardDate":1597042800000,"contractStatus":"TRADING",
+            "contractSize":100,"marginAsset":"BTC","maintMarginPercent":"2.5000",
+            "requiredMarginPercent":"5.0000","baseAsset":"BTC","quoteAsset":"USD",
+            "pricePrecision":1,"quantityPrecision":0,"baseAssetPrecision":8,"quotePrecision":8,
+            "equalQtyPrecision":4,"triggerProtect":"0.0500","liquidationFee":"0.015000",
+            "marketTakeBound":"0.05","underlyingType":"COIN","underlyingSubType":[],
+            "filters":[],"orderTypes":["LIMIT"],"timeInForce":["GTC"]}"#,
+        )
+        .unwrap();
+        assert_eq!(symbol.base_value(dec!(10), dec!(50000)), dec!(0.02));
+        assert_eq!(symbol.contracts_for(dec!(0.02), dec!(50000)), dec!(10));
+        assert_eq!(symbol.pnl(dec!(10), dec!(50000), dec!(40000)), dec!(-0.005));
+    }
+}
@@ -0,0 +1,54 @@
+use serde_json::from_str;
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+static DELIVERY_USER_DATA_STREAM: &str = "/dapi/v1/listenKey";
+
+#[derive(Clone)]
+pub struct DeliveryUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl DeliveryUserStream {
+    /// Get a listen key for the stream
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_d::{api::*, userstream::*, config::*};
+    /// let userstream: DeliveryUserStream = BinanceD::new_with_env(&Config::testnet());
+    /// let start = tokio_test::block_on(userstream.start());
+    /// assert!(start.is_ok(), "{:?}", start);
+    /// ```
+    pub async fn start(&self) -> Result<UserDataStream> {
+        let data = self.client.post(DELIVERY_USER_DATA_STREAM).await?;
+        let user_data_stream: UserDataStream = from_str(data.as_str())?;
+
+        Ok(user_data_stream)
+    }
+
+    /// Keep the connection alive, as the listen key becomes invalid after 60mn
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        let data = self
+            .client
+            .put(DELIVERY_USER_DATA_STREAM, listen_key)
+            .await?;
+
+        let success: Success = from_str(data.as_str())?;
+
+        Ok(success)
+    }
+
+    /// Invalidate the listen key
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
+        let data = self
+            .client
+            .delete(DELIVERY_USER_DATA_STREAM, listen_key)
+            .await?;
+
+        let success: Success = from_str(data.as_str())?;
+
+        Ok(success)
+    }
+}
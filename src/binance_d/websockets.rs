@@ -0,0 +1,90 @@
+use super::config::Config;
+use super::errors::*;
+use crate::binance_f::websockets::FuturesWebSockets;
+
+use actix_codec::Framed;
+use awc::ws::Codec;
+use awc::{BoxedSocket, ClientResponse};
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+pub use crate::binance_f::websockets::{
+    agg_trade_stream, all_book_ticker_stream, all_mini_ticker_stream, all_ticker_stream,
+    book_ticker_stream, diff_book_depth_stream, kline_stream, mini_ticker_stream,
+    partial_book_depth_stream, ticker_stream, StreamKind,
+};
+
+/// Index price of a pair (ex: btcusd), every 1000ms or 3000ms
+pub fn index_price_stream(pair: &str, update_speed: u16) -> String {
+    format!("{}@indexPrice@{}ms", pair, update_speed)
+}
+
+/// Mark price of every contract of a pair (ex: btcusd), every 1000ms or 3000ms
+pub fn mark_price_stream(pair: &str, update_speed: u16) -> String {
+    format!("{}@markPrice@{}ms", pair, update_speed)
+}
+
+/// Klines of the contract of `contract_type` (perpetual, current_quarter, next_quarter) on a pair
+pub fn continuous_kline_stream(
+    pair: &str,
+    contract_type: &str,
+    interval: super::rest_model::KlineInterval,
+) -> String {
+    format!("{}_{}@continuousKline_{}", pair, contract_type, interval)
+}
+
+/// Websockets of `dstream.binance.com`, the event loop is the one of `FuturesWebSockets`
+pub struct DeliveryWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
+    inner: FuturesWebSockets<WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> DeliveryWebSockets<WE> {
+    /// New websocket holder with default configuration
+    pub fn new(sender: mpsc::Sender<WE>) -> DeliveryWebSockets<WE> {
+        Self::new_with_options(sender, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> DeliveryWebSockets<WE> {
+        DeliveryWebSockets {
+            inner: FuturesWebSockets::new_with_options(sender, conf.ws_config()),
+        }
+    }
+
+    /// Connect to a websocket endpoint
+    pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
+        self.inner.connect(endpoint).await
+    }
+
+    /// Connect to several streams over one connection,
+    /// events are received wrapped in a `CombinedStreamEvent`
+    pub async fn connect_multiple_streams(&mut self, endpoints: &[String]) -> Result<()> {
+        self.inner.connect_multiple_streams(endpoints).await
+    }
+
+    /// Disconnect from the endpoint
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.inner.disconnect().await
+    }
+
+    pub fn socket(&self) -> &Option<(ClientResponse, Framed<BoxedSocket, Codec>)> {
+        self.inner.socket()
+    }
+
+    /// Override the idle timeout picked from the stream type on `connect`
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) {
+        self.inner.set_idle_timeout(idle_timeout);
+    }
+
+    /// Time elapsed since the last data message was received from the server
+    pub fn idle_time(&self) -> Duration {
+        self.inner.idle_time()
+    }
+
+    /// Forward every message to the sender until `running` is cleared,
+    /// see `FuturesWebSockets::event_loop`
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        self.inner.event_loop(running).await
+    }
+}
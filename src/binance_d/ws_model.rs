@@ -0,0 +1,216 @@
+use crate::binance_f::rest_model::{string_or_float, string_or_float_opt};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+pub use crate::binance_f::ws_model::{
+    AccountUpdateEvent, AggrTradesEvent, BookTickerEvent, CombinedStreamEvent,
+    ContinuousKlineEvent, DepthOrderBookEvent, KlineEvent, LiquidationEvent, MarkPriceEvent,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum DeliveryWebsocketEvent {
+    #[serde(alias = "aggTrade")]
+    AggrTrades(Box<AggrTradesEvent>),
+
+    #[serde(alias = "indexPriceUpdate")]
+    IndexPrice(Box<IndexPriceEvent>),
+
+    #[serde(alias = "markPriceUpdate")]
+    MarkPrice(Box<MarkPriceEvent>),
+
+    #[serde(alias = "kline")]
+    Kline(Box<KlineEvent>),
+
+    #[serde(alias = "continuous_kline")]
+    ContinuousKline(Box<ContinuousKlineEvent>),
+
+    #[serde(alias = "24hrTicker")]
+    DayTicker(Box<DayTickerEvent>),
+
+    #[serde(alias = "bookTicker")]
+    BookTicker(Box<BookTickerEvent>),
+
+    #[serde(alias = "forceOrder")]
+    Liquidation(Box<LiquidationEvent>),
+
+    #[serde(alias = "depthUpdate")]
+    DepthOrderBook(Box<DepthOrderBookEvent>),
+
+    #[serde(alias = "ACCOUNT_UPDATE")]
+    AccountUpdate(Box<AccountUpdateEvent>),
+    #[serde(alias = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(Box<OrderTradeUpdateEvent>),
+}
+
+// https://binance-docs.github.io/apidocs/delivery/en/#index-price-stream
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "i")]
+    pub pair: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub index_price: Decimal,
+}
+
+/// 24hr statistics of a contract, the volume is in contracts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DayTickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "w", with = "string_or_float")]
+    pub average_price: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub current_close: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub current_close_qty: Decimal,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub base_volume: Decimal,
+    #[serde(rename = "O")]
+    pub open_time: u64,
+    #[serde(rename = "C")]
+    pub close_time: u64,
+    #[serde(rename = "F")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "n")]
+    pub num_trades: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "i", default)]
+    pub account_alias: String,
+    #[serde(rename = "o")]
+    pub order_trade_update: OrderTradeUpdate,
+}
+
+/// Order update, quantities are in contracts and the profit in the margin asset
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub original_quantity: Decimal,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub original_price: Decimal,
+    #[serde(rename = "ap", with = "string_or_float")]
+    pub average_price: Decimal,
+    #[serde(rename = "sp", with = "string_or_float")]
+    pub stop_price: Decimal,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub order_last_filled_quantity: Decimal,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub order_filled_accumulated_quantity: Decimal,
+    #[serde(rename = "L", with = "string_or_float")]
+    pub last_filled_price: Decimal,
+    #[serde(rename = "ma")]
+    pub margin_asset: String,
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "n", default, with = "string_or_float_opt")]
+    pub commission: Option<Decimal>,
+    #[serde(rename = "T")]
+    pub order_trade_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+    #[serde(rename = "rp", with = "string_or_float")]
+    pub realized_profit: Decimal,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_quantity: Decimal,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_quantity: Decimal,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+    #[serde(rename = "wt")]
+    pub stop_price_working_type: String,
+    #[serde(rename = "ot")]
+    pub original_order_type: String,
+    #[serde(rename = "ps")]
+    pub position_side: String,
+    #[serde(rename = "cp", default)]
+    pub close_all: bool,
+    #[serde(rename = "pP", default)]
+    pub price_protect: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn parses_coin_margined_events() {
+        let event: DeliveryWebsocketEvent = serde_json::from_str(
+            r#"{"e":"indexPriceUpdate","E":1591261236000,"i":"BTCUSD","p":"9636.57860000"}"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(event, DeliveryWebsocketEvent::IndexPrice(ref index) if index.pair == "BTCUSD")
+        );
+
+        let event: DeliveryWebsocketEvent = serde_json::from_str(
+            r#"{"e":"markPriceUpdate","E":1596095725000,"s":"BTCUSD_201225","p":"10934.62615417",
+            "P":"10962.17178236","r":"","T":0}"#,
+        )
+        .unwrap();
+        let DeliveryWebsocketEvent::MarkPrice(mark) = event else {
+            panic!("not a mark price event: {:?}", event);
+        };
+        assert_eq!(mark.mark_price, dec!(10934.62615417));
+        assert_eq!(mark.funding_rate, None);
+
+        let event: DeliveryWebsocketEvent = serde_json::from_str(
+            r#"{"e":"ORDER_TRADE_UPDATE","E":1591274595442,"T":1591274595453,"i":"SfsR",
+            "o":{"s":"BTCUSD_200925","c":"TEST","S":"SELL","o":"TRAILING_STOP_MARKET","f":"GTC",
+            "q":"2","p":"0","ap":"0","sp":"9103.1","x":"NEW","X":"NEW","i":8888888,"l":"0",
+            "z":"0","L":"0","ma":"BTC","N":"BTC","n":"0","T":1591274595442,"t":0,"rp":"0",
+            "b":"0","a":"0","m":false,"R":false,"wt":"CONTRACT_PRICE","ot":"TRAILING_STOP_MARKET",
+            "ps":"LONG","cp":false,"AP":"9476.8","cr":"5.0","pP":false}}"#,
+        )
+        .unwrap();
+        let DeliveryWebsocketEvent::OrderTradeUpdate(event) = event else {
+            panic!("not an order update: {:?}", event);
+        };
+        assert_eq!(event.order_trade_update.original_quantity, dec!(2));
+        assert_eq!(event.order_trade_update.margin_asset, "BTC");
+    }
+}
@@ -2,17 +2,28 @@ use serde::Serializer;
 use std::collections::BTreeMap;
 use std::fmt;
 
-use super::client::Client;
+use super::client::{handle_content_error, Client};
 use super::errors::*;
+use super::market::{id_range, time_range, TimeRange};
 use super::rest_model::CancelAllOpenOrdersResponse;
+use super::rest_model::Empty;
 use super::rest_model::MultiAssetsMarginResponse;
 use super::rest_model::PositionModeResponse;
 use super::rest_model::{
-    AccountBalance, CanceledOrder, ChangeLeverageResponse, OrderType, Position, Transaction,
+    AccountBalance, AccountHistoryQuery, AccountTrade, AdlQuantile, AutoCloseType, CanceledOrder,
+    ChangeLeverageResponse, CommissionRate, CountdownCancelAll, Income, IncomeType, MarginType,
+    OrderAmendment, OrderType, Position, PositionMarginChange, PositionMarginHistoryQuery,
+    PositionMarginRequest, PositionMarginResponse, PositionMarginType, Symbol, Transaction,
 };
-use super::rest_model::{OrderSide, TimeInForce};
+use super::rest_model::{OrderSide, PriceMatch, SelfTradePreventionMode, TimeInForce};
 use super::rest_model::{PairAndWindowQuery, PairQuery};
 use super::util::*;
+use crate::binance::filters::{ReferencePrice, SymbolFilters};
+use futures::Stream;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::HashMap;
+use std::sync::Arc;
 
 static FAPI_ORDER: &str = "/fapi/v1/order";
 static FAPI_OPEN_ORDERS: &str = "/fapi/v2/openOrders";
@@ -22,11 +33,42 @@ static FAPI_BALANCE: &str = "/fapi/v2/balance";
 static FAPI_LEVERAGE: &str = "/fapi/v1/leverage";
 static FAPI_POSITION_SIDE_DUAL: &str = "/fapi/v1/positionSide/dual";
 static FAPI_MULTI_ASSETS_MARGIN: &str = "/fapi/v1/multiAssetsMargin";
+static FAPI_BATCH_ORDERS: &str = "/fapi/v1/batchOrders";
+static FAPI_ORDER_AMENDMENT: &str = "/fapi/v1/orderAmendment";
+static FAPI_ALL_ORDERS: &str = "/fapi/v1/allOrders";
+static FAPI_USER_TRADES: &str = "/fapi/v1/userTrades";
+static FAPI_INCOME: &str = "/fapi/v1/income";
+static FAPI_FORCE_ORDERS: &str = "/fapi/v1/forceOrders";
+static FAPI_ADL_QUANTILE: &str = "/fapi/v1/adlQuantile";
+static FAPI_COMMISSION_RATE: &str = "/fapi/v1/commissionRate";
+static FAPI_COUNTDOWN_CANCEL_ALL: &str = "/fapi/v1/countdownCancelAll";
+static FAPI_MARGIN_TYPE: &str = "/fapi/v1/marginType";
+static FAPI_POSITION_MARGIN: &str = "/fapi/v1/positionMargin";
+static FAPI_POSITION_MARGIN_HISTORY: &str = "/fapi/v1/positionMargin/history";
+
+/// Rejections of a change to the current setting
+const NO_NEED_TO_CHANGE_MARGIN_TYPE: i16 = -4046;
+const NO_NEED_TO_CHANGE_POSITION_SIDE: i16 = -4059;
+
+/// Longest range of allOrders, userTrades and forceOrders queries
+const HISTORY_MAX_SPAN: u64 = 7 * 24 * 3_600_000;
+const HISTORY_MAX_LIMIT: u16 = 1000;
+const FORCE_ORDERS_MAX_LIMIT: u16 = 100;
+/// History downloads use at most half of the 2400 weight per minute
+const HISTORY_WEIGHT_PER_MINUTE: u32 = 1200;
+const ALL_ORDERS_WEIGHT: u32 = 5;
+const USER_TRADES_WEIGHT: u32 = 5;
+const INCOME_WEIGHT: u32 = 30;
+const FORCE_ORDERS_WEIGHT: u32 = 50;
 
 #[derive(Clone)]
 pub struct FuturesAccount {
     pub client: Client,
     pub recv_window: u64,
+    /// Filters checked before placing orders when set, see `set_filters`
+    pub filters: Option<Arc<HashMap<String, SymbolFilters>>>,
+    /// Reference prices of the filter checks, see `set_reference_price`
+    pub reference_price: Option<ReferencePrice>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -54,7 +96,7 @@ pub enum PositionSide {
     Short,
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WorkingType {
     MarkPrice,
@@ -87,9 +129,10 @@ where
     }
 }
 
-#[derive(Serialize)]
+/// A new order, also the element of a batch
+#[derive(Default, Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-struct OrderRequest {
+pub struct OrderRequest {
     pub new_client_order_id: Option<String>,
     pub symbol: String,
     pub side: OrderSide,
@@ -98,16 +141,257 @@ struct OrderRequest {
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
     #[serde(rename = "quantity")]
-    pub qty: Option<f64>,
+    pub qty: Option<Decimal>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Decimal>,
+    pub stop_price: Option<Decimal>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Decimal>,
+    pub callback_rate: Option<Decimal>,
     pub working_type: Option<WorkingType>,
     #[serde(serialize_with = "serialize_opt_as_uppercase")]
     pub price_protect: Option<bool>,
+    pub good_till_date: Option<u64>,
+    pub price_match: Option<PriceMatch>,
+    pub self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+}
+
+/// Callback rate bounds of trailing stops, in percent
+const CALLBACK_RATE_MIN: Decimal = dec!(0.1);
+const CALLBACK_RATE_MAX: Decimal = dec!(10);
+
+impl OrderRequest {
+    fn new(symbol: String, side: OrderSide, order_type: OrderType) -> Self {
+        OrderRequest {
+            symbol,
+            side,
+            order_type,
+            ..Default::default()
+        }
+    }
+
+    /// A GTC limit order
+    pub fn limit<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: Decimal,
+        price: Decimal,
+    ) -> Self {
+        let mut order = Self::new(symbol.into(), side, OrderType::Limit);
+        order.time_in_force = Some(TimeInForce::GTC);
+        order.qty = Some(qty);
+        order.price = Some(price);
+        order
+    }
+
+    pub fn market<S: Into<String>>(symbol: S, side: OrderSide, qty: Decimal) -> Self {
+        let mut order = Self::new(symbol.into(), side, OrderType::Market);
+        order.qty = Some(qty);
+        order
+    }
+
+    /// A limit order at `price` triggered at `stop_price`
+    pub fn stop<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: Decimal,
+        price: Decimal,
+        stop_price: Decimal,
+    ) -> Self {
+        let mut order = Self::limit(symbol, side, qty, price);
+        order.order_type = OrderType::Stop;
+        order.stop_price = Some(stop_price);
+        order
+    }
+
+    /// A market order triggered at `stop_price`, set either a quantity or close_position
+    pub fn stop_market<S: Into<String>>(symbol: S, side: OrderSide, stop_price: Decimal) -> Self {
+        let mut order = Self::new(symbol.into(), side, OrderType::StopMarket);
+        order.stop_price = Some(stop_price);
+        order
+    }
+
+    /// A limit order at `price` triggered at `stop_price`
+    pub fn take_profit<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: Decimal,
+        price: Decimal,
+        stop_price: Decimal,
+    ) -> Self {
+        let mut order = Self::stop(symbol, side, qty, price, stop_price);
+        order.order_type = OrderType::TakeProfit;
+        order
+    }
+
+    /// A market order triggered at `stop_price`, set either a quantity or close_position
+    pub fn take_profit_market<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        stop_price: Decimal,
+    ) -> Self {
+        let mut order = Self::stop_market(symbol, side, stop_price);
+        order.order_type = OrderType::TakeProfitMarket;
+        order
+    }
+
+    /// A market order triggered once the price moves back by `callback_rate` percent
+    pub fn trailing_stop_market<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: Decimal,
+        callback_rate: Decimal,
+    ) -> Self {
+        let mut order = Self::new(symbol.into(), side, OrderType::TrailingStopMarket);
+        order.qty = Some(qty);
+        order.callback_rate = Some(callback_rate);
+        order
+    }
+
+    pub fn set_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.new_client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    pub fn set_position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Expire the order at `good_till_date` in ms, implies GTD
+    pub fn set_good_till_date(mut self, good_till_date: u64) -> Self {
+        self.time_in_force = Some(TimeInForce::GTD);
+        self.good_till_date = Some(good_till_date);
+        self
+    }
+
+    pub fn set_qty(mut self, qty: Decimal) -> Self {
+        self.qty = Some(qty);
+        self
+    }
+
+    pub fn set_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    /// Close the whole position when triggered, replaces the quantity
+    pub fn set_close_position(mut self, close_position: bool) -> Self {
+        self.close_position = Some(close_position);
+        self
+    }
+
+    /// Price from which the trailing stop starts tracking
+    pub fn set_activation_price(mut self, activation_price: Decimal) -> Self {
+        self.activation_price = Some(activation_price);
+        self
+    }
+
+    pub fn set_working_type(mut self, working_type: WorkingType) -> Self {
+        self.working_type = Some(working_type);
+        self
+    }
+
+    pub fn set_price_protect(mut self, price_protect: bool) -> Self {
+        self.price_protect = Some(price_protect);
+        self
+    }
+
+    /// Price the order from the book, replaces the price
+    pub fn set_price_match(mut self, price_match: PriceMatch) -> Self {
+        self.price = None;
+        self.price_match = Some(price_match);
+        self
+    }
+
+    pub fn set_self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    /// Check that the fields required by the order type are set
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |msg: &str| {
+            Err(Error::InvalidOrderError {
+                msg: format!("{:?} order: {}", self.order_type, msg),
+            })
+        };
+        let close_position = self.close_position == Some(true);
+        // PriceMatch::None asks for the price given, it does not price the order
+        let price_match = self.price_match.filter(|m| *m != PriceMatch::None);
+        let has_price = self.price.is_some() || price_match.is_some();
+        match self.order_type {
+            OrderType::Limit | OrderType::Stop | OrderType::TakeProfit => {
+                if self.time_in_force.is_none() {
+                    return invalid("time_in_force is required");
+                }
+                if self.qty.is_none() {
+                    return invalid("qty is required");
+                }
+                if !has_price {
+                    return invalid("price or price_match is required");
+                }
+            }
+            OrderType::Market | OrderType::TrailingStopMarket => {
+                if self.qty.is_none() {
+                    return invalid("qty is required");
+                }
+            }
+            OrderType::StopMarket | OrderType::TakeProfitMarket => {
+                if self.qty.is_none() && !close_position {
+                    return invalid("qty or close_position is required");
+                }
+            }
+        }
+        let triggered = matches!(
+            self.order_type,
+            OrderType::Stop
+                | OrderType::StopMarket
+                | OrderType::TakeProfit
+                | OrderType::TakeProfitMarket
+        );
+        if triggered && self.stop_price.is_none() {
+            return invalid("stop_price is required");
+        }
+        if let OrderType::TrailingStopMarket = self.order_type {
+            match self.callback_rate {
+                Some(rate) if (CALLBACK_RATE_MIN..=CALLBACK_RATE_MAX).contains(&rate) => {}
+                _ => return invalid("callback_rate must be between 0.1 and 10"),
+            }
+        }
+        if close_position {
+            if !matches!(
+                self.order_type,
+                OrderType::StopMarket | OrderType::TakeProfitMarket
+            ) {
+                return invalid("close_position is only for STOP_MARKET and TAKE_PROFIT_MARKET");
+            }
+            if self.qty.is_some() || self.reduce_only.is_some() {
+                return invalid("close_position cannot be sent with qty or reduce_only");
+            }
+        }
+        if price_match.is_some() {
+            if !matches!(
+                self.order_type,
+                OrderType::Limit | OrderType::Stop | OrderType::TakeProfit
+            ) {
+                return invalid("price_match is only for LIMIT, STOP and TAKE_PROFIT");
+            }
+            if self.price.is_some() {
+                return invalid("price cannot be sent with price_match");
+            }
+        }
+        let gtd = self.time_in_force == Some(TimeInForce::GTD);
+        if gtd != self.good_till_date.is_some() {
+            return invalid("good_till_date is required by and only allowed with GTD");
+        }
+        Ok(())
+    }
 }
 
 /// Order Cancellation Request
@@ -131,44 +415,168 @@ struct ChangePositionModeRequest {
     pub dual_side_position: bool,
 }
 
-/// todo: BatchOrder
-// struct BatchOrdersRequest {
-//     pub batch_orders: Vec<OrderRequest>,
-// }
+/// Price and quantity change of an open LIMIT order
+/// either order_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderModification {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub side: OrderSide,
+    #[serde(rename = "quantity")]
+    pub qty: Decimal,
+    pub price: Decimal,
+}
+
+/// Modifications of an order, most recent first
+/// either order_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendmentRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 50, max 100
+    pub limit: Option<u16>,
+}
+
+/// Orders per batch request
+const BATCH_ORDERS_MAX: usize = 5;
+/// Ids per batch cancellation
+const BATCH_CANCEL_MAX: usize = 10;
+
+/// Each element of a batch response is either the order or the error that rejected it
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchResponse<T> {
+    Err(BinanceContentError),
+    Ok(T),
+}
+
+impl<T> From<BatchResponse<T>> for Result<T> {
+    fn from(r: BatchResponse<T>) -> Self {
+        match r {
+            BatchResponse::Err(e) => Err(handle_content_error(e)),
+            BatchResponse::Ok(t) => Ok(t),
+        }
+    }
+}
+
+/// `batchOrders` parameter, a url encoded json list where every value is a string
+fn batch_orders_param<T: serde::Serialize>(orders: &[T]) -> Result<BTreeMap<String, String>> {
+    let orders = orders
+        .iter()
+        .map(|o| {
+            Ok(qs::from_str::<BTreeMap<String, String>>(&qs::to_string(
+                o,
+            )?)?)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let json = serde_json::to_string(&orders)?;
+    let mut parameters = BTreeMap::new();
+    parameters.insert(
+        "batchOrders".into(),
+        url::form_urlencoded::byte_serialize(json.as_bytes()).collect(),
+    );
+    Ok(parameters)
+}
+
+fn id_list_param<T: serde::Serialize>(
+    symbol: &str,
+    key: &str,
+    ids: &[T],
+) -> Result<BTreeMap<String, String>> {
+    let json = serde_json::to_string(ids)?;
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol.to_string());
+    parameters.insert(
+        key.into(),
+        url::form_urlencoded::byte_serialize(json.as_bytes()).collect(),
+    );
+    Ok(parameters)
+}
 
 impl FuturesAccount {
+    /// Check orders against the filters of `symbols` before sending them,
+    /// orders of other symbols are rejected with `Error::UnknownSymbol`
+    pub fn set_filters(mut self, symbols: &[Symbol]) -> Result<Self> {
+        let filters = symbols
+            .iter()
+            .map(|symbol| Ok((symbol.symbol.clone(), SymbolFilters::try_from(symbol)?)))
+            .collect::<Result<_>>()?;
+        self.filters = Some(Arc::new(filters));
+        Ok(self)
+    }
+
+    /// Check market order notionals and percent price bands against the price returned
+    /// for the symbol, such as the last or the mark price, they are skipped on None
+    pub fn set_reference_price(
+        mut self,
+        reference_price: impl Fn(&str) -> Option<Decimal> + Send + Sync + 'static,
+    ) -> Self {
+        self.reference_price = Some(Arc::new(reference_price));
+        self
+    }
+
+    /// Orders priced with price_match and close_position orders without quantity are not checked
+    fn check_filters(&self, order: &OrderRequest) -> Result<()> {
+        let (Some(filters), Some(qty)) = (&self.filters, order.qty) else {
+            return Ok(());
+        };
+        if order.price_match.is_some() {
+            return Ok(());
+        }
+        let filters = filters
+            .get(&order.symbol)
+            .ok_or_else(|| Error::UnknownSymbol(order.symbol.clone()))?;
+        let buy = matches!(order.side, OrderSide::Buy);
+        let price = match order.order_type {
+            OrderType::Limit | OrderType::Stop | OrderType::TakeProfit => order.price,
+            _ => None,
+        };
+        let reference_price = self
+            .reference_price
+            .as_ref()
+            .and_then(|reference_price| reference_price(&order.symbol));
+        filters.check(buy, price, qty, reference_price)?;
+        for price in [order.stop_price, order.activation_price]
+            .into_iter()
+            .flatten()
+        {
+            filters.check_price(price)?;
+        }
+        Ok(())
+    }
+
     async fn post_order(&self, order: OrderRequest) -> Result<Transaction> {
+        self.check_filters(&order)?;
         self.client
             .post_signed_p(FAPI_ORDER, order, self.recv_window)
             .await
     }
 
+    /// Place any order built with [`OrderRequest`], checked before sending
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        order.validate()?;
+        self.post_order(order).await
+    }
+
     pub async fn limit_buy(
         &self,
         new_client_order_id: impl Into<String>,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: impl Into<f64>,
+        qty: impl Into<Decimal>,
+        price: impl Into<Decimal>,
         position_side: PositionSide,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
-        let order = OrderRequest {
-            new_client_order_id: Some(new_client_order_id.into()),
-            symbol: symbol.into(),
-            side: OrderSide::Buy,
-            position_side: Some(position_side),
-            order_type: OrderType::Limit,
-            time_in_force: Some(time_in_force),
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: Some(price.into()),
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
+        let order = OrderRequest::limit(symbol, OrderSide::Buy, qty.into(), price.into())
+            .set_client_order_id(new_client_order_id)
+            .set_position_side(position_side)
+            .set_time_in_force(time_in_force);
         self.post_order(order).await
     }
 
@@ -176,80 +584,47 @@ impl FuturesAccount {
         &self,
         new_client_order_id: impl Into<String>,
         symbol: impl Into<String>,
-        qty: impl Into<f64>,
-        price: impl Into<f64>,
+        qty: impl Into<Decimal>,
+        price: impl Into<Decimal>,
         position_side: PositionSide,
         time_in_force: TimeInForce,
     ) -> Result<Transaction> {
-        let order = OrderRequest {
-            new_client_order_id: Some(new_client_order_id.into()),
-            symbol: symbol.into(),
-            side: OrderSide::Sell,
-            position_side: Some(position_side),
-            order_type: OrderType::Limit,
-            time_in_force: Some(time_in_force),
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: Some(price.into()),
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
+        let order = OrderRequest::limit(symbol, OrderSide::Sell, qty.into(), price.into())
+            .set_client_order_id(new_client_order_id)
+            .set_position_side(position_side)
+            .set_time_in_force(time_in_force);
         self.post_order(order).await
     }
 
     // Place a MARKET order - BUY
-    pub async fn market_buy<S, F>(&self, symbol: S, qty: F, position_side: PositionSide) -> Result<Transaction>
+    pub async fn market_buy<S, F>(
+        &self,
+        symbol: S,
+        qty: F,
+        position_side: PositionSide,
+    ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
-        let order = OrderRequest {
-            new_client_order_id: None,
-            symbol: symbol.into(),
-            side: OrderSide::Buy,
-            position_side: Some(position_side),
-            order_type: OrderType::Market,
-            time_in_force: None,
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: None,
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
+        let order = OrderRequest::market(symbol, OrderSide::Buy, qty.into())
+            .set_position_side(position_side);
         self.post_order(order).await
     }
 
     // Place a MARKET order - SELL
-    pub async fn market_sell<S, F>(&self, symbol: S, qty: F, position_side: PositionSide) -> Result<Transaction>
+    pub async fn market_sell<S, F>(
+        &self,
+        symbol: S,
+        qty: F,
+        position_side: PositionSide,
+    ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: Into<Decimal>,
     {
-        let order: OrderRequest = OrderRequest {
-            new_client_order_id: None,
-            symbol: symbol.into(),
-            side: OrderSide::Sell,
-            position_side: Some(position_side),
-            order_type: OrderType::Market,
-            time_in_force: None,
-            qty: Some(qty.into()),
-            reduce_only: None,
-            price: None,
-            stop_price: None,
-            close_position: None,
-            activation_price: None,
-            callback_rate: None,
-            working_type: None,
-            price_protect: None,
-        };
+        let order = OrderRequest::market(symbol, OrderSide::Sell, qty.into())
+            .set_position_side(position_side);
         self.post_order(order).await
     }
 
@@ -336,13 +711,83 @@ impl FuturesAccount {
 
     pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
         self.client
-            .post_signed_p(
+            .post_signed_p::<Empty, _>(
                 FAPI_POSITION_SIDE_DUAL,
                 ChangePositionModeRequest { dual_side_position },
                 self.recv_window,
             )
-            .await?;
-        Ok(())
+            .await
+            .map(|_| ())
+            .or_else(|e| unchanged(e, NO_NEED_TO_CHANGE_POSITION_SIDE))
+    }
+
+    /// Switch `symbol` to isolated or cross margin, succeeds if it already is
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert(
+            "marginType".into(),
+            match margin_type {
+                MarginType::Isolated => "ISOLATED".into(),
+                MarginType::Crossed => "CROSSED".into(),
+            },
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_d::<Empty>(FAPI_MARGIN_TYPE, request.as_str())
+            .await
+            .map(|_| ())
+            .or_else(|e| unchanged(e, NO_NEED_TO_CHANGE_MARGIN_TYPE))
+    }
+
+    /// Add or reduce the margin of an isolated position,
+    /// `position_side` is required in hedge mode
+    pub async fn modify_position_margin<S>(
+        &self,
+        symbol: S,
+        position_side: Option<PositionSide>,
+        amount: Decimal,
+        margin_type: PositionMarginType,
+    ) -> Result<PositionMarginResponse>
+    where
+        S: Into<String>,
+    {
+        let request = PositionMarginRequest {
+            symbol: symbol.into(),
+            position_side,
+            amount,
+            margin_type,
+        };
+        self.client
+            .post_signed_p(FAPI_POSITION_MARGIN, request, self.recv_window)
+            .await
+    }
+
+    /// Isolated margin changes of `symbol`, of the last 30 days by default (max 500)
+    pub async fn get_position_margin_history<S>(
+        &self,
+        symbol: S,
+        margin_type: Option<PositionMarginType>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<PositionMarginChange>>
+    where
+        S: Into<String>,
+    {
+        let query = PositionMarginHistoryQuery {
+            symbol: symbol.into(),
+            margin_type,
+            start_time,
+            end_time,
+            limit,
+        };
+        self.client
+            .get_signed_p(FAPI_POSITION_MARGIN_HISTORY, Some(query), self.recv_window)
+            .await
     }
 
     pub async fn get_position_mode(&self) -> Result<PositionModeResponse> {
@@ -369,8 +814,745 @@ impl FuturesAccount {
         parameters.insert("multiAssetsMargin".into(), mutl_assets_margin.into());
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .post_signed_d(FAPI_MULTI_ASSETS_MARGIN, request.as_str())
+            .post_signed_d::<Empty>(FAPI_MULTI_ASSETS_MARGIN, request.as_str())
             .await?;
         Ok(())
     }
+
+    /// Change the price and quantity of an open LIMIT order, keeping its order id
+    pub async fn modify_order(&self, o: OrderModification) -> Result<Transaction> {
+        self.client
+            .put_signed_p(FAPI_ORDER, o, self.recv_window)
+            .await
+    }
+
+    pub async fn order_amendment_history(
+        &self,
+        r: OrderAmendmentRequest,
+    ) -> Result<Vec<OrderAmendment>> {
+        self.client
+            .get_signed_p(FAPI_ORDER_AMENDMENT, Some(r), self.recv_window)
+            .await
+    }
+
+    /// Cancel every open order of `symbol` unless called again within `countdown_time` ms,
+    /// 0 disarms the countdown. See [`super::heartbeat::CountdownHeartbeat`] to keep it armed
+    pub async fn countdown_cancel_all<S>(
+        &self,
+        symbol: S,
+        countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_d(FAPI_COUNTDOWN_CANCEL_ALL, request.as_str())
+            .await
+    }
+
+    /// Order by order id or client order id
+    pub async fn get_order<S>(
+        &self,
+        symbol: S,
+        order_id: Option<u64>,
+        orig_client_order_id: Option<String>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed_d(FAPI_ORDER, request.as_str()).await
+    }
+
+    /// Orders of `symbol` from `order_id` on, or within a range of at most 7 days (max 1000)
+    pub async fn get_all_orders<S>(
+        &self,
+        symbol: S,
+        order_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Transaction>>
+    where
+        S: Into<String>,
+    {
+        let query = AccountHistoryQuery {
+            symbol: Some(symbol.into()),
+            order_id,
+            start_time,
+            end_time,
+            limit,
+            ..Default::default()
+        };
+        self.client
+            .get_signed_p(FAPI_ALL_ORDERS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Stream the orders of `symbol` over `range`, 7 days at a time
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_f::{api::*, account::*, config::*, market::TimeRange};
+    /// use futures::TryStreamExt;
+    /// let account: FuturesAccount = BinanceF::new_with_env(&Config::default());
+    /// let range = TimeRange::new(1_672_531_200_000, 1_675_209_600_000);
+    /// let orders: Vec<_> = tokio_test::block_on(account.all_orders_range("BTCUSDT", range).try_collect()).unwrap();
+    /// ```
+    pub fn all_orders_range<S>(
+        &self,
+        symbol: S,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<Transaction>>
+    where
+        S: Into<String>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        time_range(
+            range.cap_limit(HISTORY_MAX_LIMIT),
+            HISTORY_WEIGHT_PER_MINUTE / ALL_ORDERS_WEIGHT,
+            None,
+            Some(HISTORY_MAX_SPAN),
+            move |start_time, end_time, limit| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_all_orders(symbol, None, Some(start_time), Some(end_time), Some(limit))
+                        .await
+                }
+            },
+            |order: &Transaction| order.time,
+            |order: &Transaction| Some(order.order_id),
+        )
+    }
+
+    /// Trades of `symbol` from `from_id` on, or within a range of at most 7 days (max 1000)
+    pub async fn get_user_trades<S>(
+        &self,
+        symbol: S,
+        from_id: Option<u64>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<AccountTrade>>
+    where
+        S: Into<String>,
+    {
+        let query = AccountHistoryQuery {
+            symbol: Some(symbol.into()),
+            from_id,
+            start_time,
+            end_time,
+            limit,
+            ..Default::default()
+        };
+        self.client
+            .get_signed_p(FAPI_USER_TRADES, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Stream the trades of `symbol` over `range`. The first trade is searched for
+    /// 7 days at a time, the following ones are paged by trade id
+    pub fn user_trades_range<S>(
+        &self,
+        symbol: S,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<AccountTrade>>
+    where
+        S: Into<String>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        id_range(
+            range.cap_limit(HISTORY_MAX_LIMIT),
+            HISTORY_WEIGHT_PER_MINUTE / USER_TRADES_WEIGHT,
+            Some(HISTORY_MAX_SPAN),
+            move |from_id, start_time, end_time, limit| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_user_trades(symbol, from_id, start_time, end_time, Some(limit))
+                        .await
+                }
+            },
+            |trade: &AccountTrade| trade.time,
+            |trade: &AccountTrade| trade.id,
+        )
+    }
+
+    /// Income of the last 3 months, of every kind unless `income_type` is set (max 1000)
+    pub async fn get_income(
+        &self,
+        symbol: Option<String>,
+        income_type: Option<IncomeType>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Income>> {
+        let query = AccountHistoryQuery {
+            symbol,
+            income_type,
+            start_time,
+            end_time,
+            limit,
+            ..Default::default()
+        };
+        self.client
+            .get_signed_p(FAPI_INCOME, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Stream the income over `range`
+    pub fn income_range(
+        &self,
+        symbol: Option<String>,
+        income_type: Option<IncomeType>,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<Income>> {
+        let account = self.clone();
+        time_range(
+            range.cap_limit(HISTORY_MAX_LIMIT),
+            HISTORY_WEIGHT_PER_MINUTE / INCOME_WEIGHT,
+            None,
+            None,
+            move |start_time, end_time, limit| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_income(
+                            symbol,
+                            income_type,
+                            Some(start_time),
+                            Some(end_time),
+                            Some(limit),
+                        )
+                        .await
+                }
+            },
+            |income: &Income| income.time,
+            |income: &Income| Some(income.tran_id),
+        )
+    }
+
+    /// Liquidation and ADL orders of the account within a range of at most 7 days (max 100)
+    pub async fn get_force_orders(
+        &self,
+        symbol: Option<String>,
+        auto_close_type: Option<AutoCloseType>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Transaction>> {
+        let query = AccountHistoryQuery {
+            symbol,
+            auto_close_type,
+            start_time,
+            end_time,
+            limit,
+            ..Default::default()
+        };
+        self.client
+            .get_signed_p(FAPI_FORCE_ORDERS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Stream the liquidation and ADL orders over `range`, 7 days at a time
+    pub fn force_orders_range(
+        &self,
+        symbol: Option<String>,
+        auto_close_type: Option<AutoCloseType>,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<Transaction>> {
+        let account = self.clone();
+        time_range(
+            range.cap_limit(FORCE_ORDERS_MAX_LIMIT),
+            HISTORY_WEIGHT_PER_MINUTE / FORCE_ORDERS_WEIGHT,
+            None,
+            Some(HISTORY_MAX_SPAN),
+            move |start_time, end_time, limit| {
+                let account = account.clone();
+                let symbol = symbol.clone();
+                async move {
+                    account
+                        .get_force_orders(
+                            symbol,
+                            auto_close_type,
+                            Some(start_time),
+                            Some(end_time),
+                            Some(limit),
+                        )
+                        .await
+                }
+            },
+            |order: &Transaction| order.time,
+            |order: &Transaction| Some(order.order_id),
+        )
+    }
+
+    /// ADL queue position of every open position, or of those of `symbol`
+    pub async fn get_adl_quantile(&self, symbol: Option<String>) -> Result<Vec<AdlQuantile>> {
+        let query = AccountHistoryQuery {
+            symbol,
+            ..Default::default()
+        };
+        self.client
+            .get_signed_p(FAPI_ADL_QUANTILE, Some(query), self.recv_window)
+            .await
+    }
+
+    pub async fn get_commission_rate<S>(&self, symbol: S) -> Result<CommissionRate>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                FAPI_COMMISSION_RATE,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Place orders 5 at a time, one result per order in the same order
+    /// Orders failing their checks get the error in their slot and are not sent
+    /// A rejected order or a failed request does not fail the others
+    pub async fn place_batch_orders(
+        &self,
+        orders: &[OrderRequest],
+    ) -> Result<Vec<Result<Transaction>>> {
+        let mut results: Vec<Option<Result<Transaction>>> = orders
+            .iter()
+            .map(|order| {
+                order
+                    .validate()
+                    .and_then(|_| self.check_filters(order))
+                    .err()
+                    .map(Err)
+            })
+            .collect();
+        let valid: Vec<(usize, &OrderRequest)> = orders
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| results[*i].is_none())
+            .collect();
+        for chunk in valid.chunks(BATCH_ORDERS_MAX) {
+            let orders: Vec<&OrderRequest> = chunk.iter().map(|(_, order)| *order).collect();
+            let response = async {
+                let request = build_signed_request(batch_orders_param(&orders)?, self.recv_window)?;
+                let response = self.client.post_signed(FAPI_BATCH_ORDERS, &request).await?;
+                parse_batch_response(&response)
+            }
+            .await;
+            for ((i, _), result) in chunk.iter().zip(chunk_results(response, chunk.len())) {
+                results[*i] = Some(result);
+            }
+        }
+        Ok(results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| Err(Error::Msg("no response for this order".into())))
+            })
+            .collect())
+    }
+
+    /// Modify LIMIT orders 5 at a time, one result per order in the same order
+    /// A rejected modification or a failed request does not fail the others
+    pub async fn modify_batch_orders(
+        &self,
+        orders: &[OrderModification],
+    ) -> Result<Vec<Result<Transaction>>> {
+        let mut results = Vec::with_capacity(orders.len());
+        for chunk in orders.chunks(BATCH_ORDERS_MAX) {
+            let response = async {
+                let request = build_signed_request(batch_orders_param(chunk)?, self.recv_window)?;
+                let response = self.client.put_signed(FAPI_BATCH_ORDERS, &request).await?;
+                parse_batch_response(&response)
+            }
+            .await;
+            results.extend(chunk_results(response, chunk.len()));
+        }
+        Ok(results)
+    }
+
+    /// Cancel orders by order id 10 at a time, one result per id in the same order
+    pub async fn cancel_batch_orders<S>(
+        &self,
+        symbol: S,
+        order_ids: &[u64],
+    ) -> Result<Vec<Result<CanceledOrder>>>
+    where
+        S: AsRef<str>,
+    {
+        self.cancel_batch(symbol.as_ref(), "orderIdList", order_ids)
+            .await
+    }
+
+    /// Cancel orders by client order id 10 at a time, one result per id in the same order
+    pub async fn cancel_batch_orders_by_client_id<S>(
+        &self,
+        symbol: S,
+        orig_client_order_ids: &[String],
+    ) -> Result<Vec<Result<CanceledOrder>>>
+    where
+        S: AsRef<str>,
+    {
+        self.cancel_batch(
+            symbol.as_ref(),
+            "origClientOrderIdList",
+            orig_client_order_ids,
+        )
+        .await
+    }
+
+    async fn cancel_batch<T: serde::Serialize>(
+        &self,
+        symbol: &str,
+        key: &str,
+        ids: &[T],
+    ) -> Result<Vec<Result<CanceledOrder>>> {
+        let mut results = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(BATCH_CANCEL_MAX) {
+            let response = async {
+                let request =
+                    build_signed_request(id_list_param(symbol, key, chunk)?, self.recv_window)?;
+                let response = self
+                    .client
+                    .delete_signed(FAPI_BATCH_ORDERS, &request)
+                    .await?;
+                parse_batch_response(&response)
+            }
+            .await;
+            results.extend(chunk_results(response, chunk.len()));
+        }
+        Ok(results)
+    }
+}
+
+/// Treat the rejection of a change to the current setting as a success
+fn unchanged(e: Error, code: i16) -> Result<()> {
+    match e {
+        Error::BinanceError { response } if response.code == code => Ok(()),
+        e => Err(e),
+    }
+}
+
+fn parse_batch_response<T: serde::de::DeserializeOwned>(response: &str) -> Result<Vec<Result<T>>> {
+    let responses: Vec<BatchResponse<T>> = serde_json::from_str(response)?;
+    Ok(responses.into_iter().map(Into::into).collect())
+}
+
+/// Results of the `len` orders of one batch request. When the request fails as a whole,
+/// every order gets its error as `Error::BatchRequest`.
+fn chunk_results<T>(response: Result<Vec<Result<T>>>, len: usize) -> Vec<Result<T>> {
+    match response {
+        Ok(results) => results,
+        Err(e) => {
+            let e = Arc::new(e);
+            (0..len)
+                .map(|_| Err(Error::BatchRequest(e.clone())))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::filters::FilterViolation;
+
+    #[test]
+    fn market_orders_are_checked_against_the_reference_price() {
+        let filters = SymbolFilters {
+            min_notional: dec!(5),
+            apply_min_to_market: true,
+            ..Default::default()
+        };
+        let account = FuturesAccount {
+            client: Client::new(None, None, "http://127.0.0.1:9".into()),
+            recv_window: 5000,
+            filters: Some(Arc::new(HashMap::from([("BTCUSDT".to_string(), filters)]))),
+            reference_price: None,
+        };
+        let order = OrderRequest::market("BTCUSDT", OrderSide::Buy, dec!(0.001));
+        assert!(account.check_filters(&order).is_ok());
+
+        let account =
+            account.set_reference_price(|symbol| (symbol == "BTCUSDT").then_some(dec!(1000)));
+        assert!(matches!(
+            account.check_filters(&order),
+            Err(Error::FilterViolation(
+                FilterViolation::NotionalTooLow { .. }
+            ))
+        ));
+        let order = OrderRequest::market("BTCUSDT", OrderSide::Buy, dec!(0.01));
+        assert!(account.check_filters(&order).is_ok());
+    }
+
+    #[test]
+    fn batch_orders_are_string_valued_json() {
+        let order = OrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            qty: Some(dec!(0.001)),
+            price: Some(dec!(30000.5)),
+            reduce_only: Some(true),
+            ..Default::default()
+        };
+        let parameters = batch_orders_param(&[order]).unwrap();
+        let json: String = url::form_urlencoded::parse(
+            format!("batchOrders={}", parameters["batchOrders"]).as_bytes(),
+        )
+        .map(|(_, v)| v.into_owned())
+        .next()
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"[{"price":"30000.5","quantity":"0.001","reduceOnly":"true","side":"SELL","symbol":"BTCUSDT","timeInForce":"GTC","type":"LIMIT"}]"#
+        );
+    }
+
+    #[test]
+    fn order_modifications_by_order_id_or_client_order_id() {
+        let by_id = OrderModification {
+            symbol: "BTCUSDT".into(),
+            order_id: Some(42),
+            side: OrderSide::Buy,
+            qty: dec!(0.002),
+            price: dec!(29000),
+            ..Default::default()
+        };
+        let by_client_id = OrderModification {
+            symbol: "BTCUSDT".into(),
+            orig_client_order_id: Some("my_order".into()),
+            side: OrderSide::Sell,
+            qty: dec!(0.001),
+            price: dec!(31000.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            qs::to_string(&by_id).unwrap(),
+            "symbol=BTCUSDT&orderId=42&side=BUY&quantity=0.002&price=29000"
+        );
+        assert_eq!(
+            qs::to_string(&by_client_id).unwrap(),
+            "symbol=BTCUSDT&origClientOrderId=my_order&side=SELL&quantity=0.001&price=31000.1"
+        );
+
+        let parameters = batch_orders_param(&[by_id, by_client_id]).unwrap();
+        let json: String = url::form_urlencoded::parse(
+            format!("batchOrders={}", parameters["batchOrders"]).as_bytes(),
+        )
+        .map(|(_, v)| v.into_owned())
+        .next()
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"[{"orderId":"42","price":"29000","quantity":"0.002","side":"BUY","symbol":"BTCUSDT"},{"origClientOrderId":"my_order","price":"31000.1","quantity":"0.001","side":"SELL","symbol":"BTCUSDT"}]"#
+        );
+    }
+
+    #[test]
+    fn order_builder_validation() {
+        let sl = OrderRequest::stop_market("BTCUSDT", OrderSide::Sell, dec!(25000));
+        assert!(sl.validate().is_err());
+        assert!(sl.clone().set_close_position(true).validate().is_ok());
+        assert!(sl
+            .set_qty(dec!(0.01))
+            .set_close_position(true)
+            .validate()
+            .is_err());
+        let tp = OrderRequest::take_profit(
+            "BTCUSDT",
+            OrderSide::Sell,
+            dec!(0.01),
+            dec!(35000),
+            dec!(34900),
+        )
+        .set_working_type(WorkingType::MarkPrice);
+        assert!(tp.validate().is_ok());
+        let trailing =
+            OrderRequest::trailing_stop_market("BTCUSDT", OrderSide::Sell, dec!(0.01), dec!(20));
+        assert!(trailing.validate().is_err());
+        let maker = OrderRequest::limit("BTCUSDT", OrderSide::Buy, dec!(0.01), dec!(30000))
+            .set_time_in_force(TimeInForce::GTD);
+        assert!(maker.validate().is_err());
+        let maker = maker
+            .set_good_till_date(1_700_000_000_000)
+            .set_price_match(PriceMatch::Queue5)
+            .set_self_trade_prevention_mode(SelfTradePreventionMode::ExpireMaker);
+        assert!(maker.validate().is_ok());
+        assert_eq!(
+            build_request_p(&maker).unwrap(),
+            "symbol=BTCUSDT&side=BUY&type=LIMIT&timeInForce=GTD&quantity=0.01\
+             &goodTillDate=1700000000000&priceMatch=QUEUE_5&selfTradePreventionMode=EXPIRE_MAKER"
+        );
+
+        // PriceMatch::None is not a price
+        let unpriced = OrderRequest::limit("BTCUSDT", OrderSide::Buy, dec!(0.01), dec!(30000))
+            .set_price_match(PriceMatch::None);
+        assert!(unpriced.validate().is_err());
+        let mut priced = unpriced;
+        priced.price = Some(dec!(30000));
+        assert!(priced.validate().is_ok());
+        // price_match only prices limit orders
+        let market = OrderRequest::market("BTCUSDT", OrderSide::Buy, dec!(0.01));
+        assert!(market
+            .clone()
+            .set_price_match(PriceMatch::Opponent)
+            .validate()
+            .is_err());
+        assert!(market.set_price_match(PriceMatch::None).validate().is_ok());
+        let sl = OrderRequest::stop_market("BTCUSDT", OrderSide::Sell, dec!(25000))
+            .set_close_position(true)
+            .set_price_match(PriceMatch::Queue);
+        assert!(sl.validate().is_err());
+    }
+
+    #[test]
+    fn account_history_models() {
+        let order: Transaction = serde_json::from_str(
+            r#"{"avgPrice":"0.00000","clientOrderId":"abc","cumQuote":"0","executedQty":"0",
+            "orderId":1917641,"origQty":"0.40","origType":"TRAILING_STOP_MARKET","price":"0",
+            "reduceOnly":false,"side":"BUY","positionSide":"SHORT","status":"NEW",
+            "stopPrice":"9300","closePosition":false,"symbol":"BTCUSDT","time":1579276756075,
+            "timeInForce":"GTC","type":"TRAILING_STOP_MARKET","activatePrice":"9020",
+            "priceRate":"0.3","updateTime":1579276756075,"workingType":"CONTRACT_PRICE",
+            "priceProtect":false}"#,
+        )
+        .unwrap();
+        assert_eq!(order.time, 1579276756075);
+        assert_eq!(order.cum_qty, dec!(0));
+        let quantiles: Vec<AdlQuantile> = serde_json::from_str(
+            r#"[{"symbol":"ETHUSDT","adlQuantile":{"LONG":3,"SHORT":3,"HEDGE":0}},
+            {"symbol":"BTCUSDT","adlQuantile":{"LONG":1,"BOTH":0}}]"#,
+        )
+        .unwrap();
+        assert_eq!(quantiles[0].adl_quantile.hedge, Some(0));
+        assert_eq!(quantiles[1].adl_quantile.both, Some(0));
+        let income: Income = serde_json::from_str(
+            r#"{"symbol":"BTCUSDT","incomeType":"FUNDING_FEE","income":"-0.01","asset":"USDT",
+            "info":"FUNDING_FEE","time":1570636800000,"tranId":9689322392,"tradeId":""}"#,
+        )
+        .unwrap();
+        assert_eq!(income.income_type, IncomeType::FundingFee);
+    }
+
+    #[test]
+    fn unchanged_setting_is_success() {
+        let error = |code: i16| Error::BinanceError {
+            response: serde_json::from_str(&format!(r#"{{"code":{},"msg":""}}"#, code)).unwrap(),
+        };
+        assert!(unchanged(error(-4046), NO_NEED_TO_CHANGE_MARGIN_TYPE).is_ok());
+        assert!(unchanged(error(-4048), NO_NEED_TO_CHANGE_MARGIN_TYPE).is_err());
+        assert!(unchanged(Error::Unauthorized, NO_NEED_TO_CHANGE_POSITION_SIDE).is_err());
+
+        let history: Vec<PositionMarginChange> = serde_json::from_str(
+            r#"[{"symbol":"BTCUSDT","type":1,"deltaType":"USER_ADJUST","amount":"23.36",
+            "asset":"USDT","time":1578047897183,"positionSide":"BOTH"}]"#,
+        )
+        .unwrap();
+        assert_eq!(history[0].margin_type, PositionMarginType::Add);
+        let request = PositionMarginRequest {
+            symbol: "BTCUSDT".into(),
+            position_side: None,
+            amount: dec!(10),
+            margin_type: PositionMarginType::Reduce,
+        };
+        assert_eq!(
+            build_request_p(request).unwrap(),
+            "symbol=BTCUSDT&amount=10&type=2"
+        );
+    }
+
+    #[test]
+    fn order_amendment_history() {
+        let amendments: Vec<OrderAmendment> = serde_json::from_str(
+            r#"[{"amendmentId":5363,"symbol":"BTCUSDT","pair":"BTCUSDT","orderId":20072994037,
+            "clientOrderId":"LJ9R4QZDihCaS8UAOOLpgW","time":1629184560899,
+            "amendment":{"price":{"before":"30004","after":"30003.2"},
+            "origQty":{"before":"1","after":"1"},"count":3}}]"#,
+        )
+        .unwrap();
+        assert_eq!(amendments[0].amendment.price.after, dec!(30003.2));
+        assert_eq!(amendments[0].amendment.count, 3);
+    }
+
+    #[test]
+    fn batch_response_keeps_partial_failures() {
+        let response = r#"[{"clientOrderId":"a","cumQty":"0","cumQuote":"0","executedQty":"0",
+            "orderId":1,"avgPrice":"0.0","origQty":"0.001","price":"30000","reduceOnly":false,
+            "side":"BUY","positionSide":"BOTH","status":"NEW","stopPrice":"0","closePosition":false,
+            "symbol":"BTCUSDT","timeInForce":"GTC","type":"LIMIT","origType":"LIMIT",
+            "updateTime":1,"workingType":"CONTRACT_PRICE","priceProtect":false},
+            {"code":-2019,"msg":"Margin is insufficient."},
+            {"code":-1013,"msg":"Invalid price."}]"#;
+        let results: Vec<Result<Transaction>> = parse_batch_response(response).unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().order_id, 1);
+        assert!(
+            matches!(&results[1], Err(Error::BinanceError { response }) if response.code == -2019)
+        );
+        assert!(matches!(results[2], Err(Error::InvalidPrice)));
+
+        // a failed request fails each of its orders, earlier results are kept
+        let mut results = chunk_results(parse_batch_response(response), 3);
+        results.extend(chunk_results::<Transaction>(
+            Err(Error::Msg("connection reset".into())),
+            2,
+        ));
+        assert_eq!(results.len(), 5);
+        assert!(results[0].is_ok());
+        assert!(results[3..].iter().all(|result| matches!(
+            result,
+            Err(Error::BatchRequest(e)) if matches!(e.as_ref(), Error::Msg(msg) if msg == "connection reset")
+        )));
+    }
+
+    #[tokio::test]
+    async fn batch_orders_failing_checks_are_not_sent() {
+        let account = FuturesAccount {
+            // nothing listens on the discard port, the request of the valid order fails
+            client: Client::new(None, None, "http://127.0.0.1:9".into()),
+            recv_window: 5000,
+            filters: Some(Arc::new(HashMap::from([(
+                "BTCUSDT".to_string(),
+                SymbolFilters::default(),
+            )]))),
+            reference_price: None,
+        };
+        let orders = [
+            OrderRequest::limit("BTCUSDT", OrderSide::Buy, dec!(0.001), dec!(30000)),
+            OrderRequest::market("ETHUSDT", OrderSide::Buy, dec!(0.01)),
+            OrderRequest {
+                price: None,
+                ..OrderRequest::limit("BTCUSDT", OrderSide::Sell, dec!(0.001), dec!(31000))
+            },
+        ];
+        let results = account.place_batch_orders(&orders).await.unwrap();
+        assert_eq!(results.len(), 3);
+        assert!(
+            matches!(&results[0], Err(Error::BatchRequest(e)) if matches!(e.as_ref(), Error::ReqError(_)))
+        );
+        assert!(matches!(&results[1], Err(Error::UnknownSymbol(symbol)) if symbol == "ETHUSDT"));
+        assert!(matches!(results[2], Err(Error::InvalidOrderError { .. })));
+    }
 }
@@ -72,6 +72,8 @@ impl BinanceF for FuturesAccount {
                 config.futures_rest_api_endpoint.clone(),
             ),
             recv_window: config.recv_window,
+            filters: None,
+            reference_price: None,
         }
     }
 }
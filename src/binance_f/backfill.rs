@@ -0,0 +1,121 @@
+use super::errors::*;
+use super::market::FuturesMarket;
+use super::rest_model::{AggTrade, AggTrades, Trade, Trades};
+use crate::binance::backfill::{walk, BackfillError};
+pub use crate::binance::backfill::{Backfilled, Sequenced, TradeBackfill};
+
+use futures::stream::Stream;
+
+/// Request weight of `/fapi/v1/historicalTrades`
+const HISTORICAL_TRADES_WEIGHT: u32 = 20;
+/// Request weight of `/fapi/v1/aggTrades`
+const AGG_TRADES_WEIGHT: u32 = 20;
+
+impl BackfillError for Error {
+    fn msg(msg: String) -> Self {
+        Error::Msg(msg)
+    }
+}
+
+impl Sequenced for Trade {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Sequenced for AggTrade {
+    fn id(&self) -> u64 {
+        self.agg_id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl FuturesMarket {
+    /// Stream every trade of a backfill in id order, with a `Backfilled::Gap` for each
+    /// range of ids missing from the exchange.
+    /// A backfill started from a time is located with aggregate trades.
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_f::{api::*, backfill::*, market::*};
+    /// use futures::TryStreamExt;
+    /// let market: FuturesMarket = BinanceF::new(None, None);
+    /// let backfill = TradeBackfill::from_time("BTCUSDT", 1_640_995_200_000, 1_640_998_800_000);
+    /// let trades: Vec<_> = tokio_test::block_on(market.trades_backfill(backfill).try_collect()).unwrap();
+    /// ```
+    pub fn trades_backfill(
+        &self,
+        backfill: TradeBackfill,
+    ) -> impl Stream<Item = Result<Backfilled<Trade>>> {
+        let fetch_market = self.clone();
+        let locate_market = self.clone();
+        walk(
+            backfill,
+            HISTORICAL_TRADES_WEIGHT,
+            AGG_TRADES_WEIGHT,
+            move |backfill| {
+                let market = fetch_market.clone();
+                async move {
+                    let Trades::AllTrades(trades) = market
+                        .get_historical_trades(backfill.symbol, backfill.next_id, backfill.limit)
+                        .await?;
+                    Ok(trades)
+                }
+            },
+            move |symbol, start_time, end_time| {
+                let market = locate_market.clone();
+                async move {
+                    let AggTrades::AllAggTrades(trades) = market
+                        .get_agg_trades(symbol, None, start_time, end_time, 1u16)
+                        .await?;
+                    Ok(trades.first().map(|trade| trade.first_id))
+                }
+            },
+        )
+    }
+
+    /// Stream every aggregate trade of a backfill in id order, with a `Backfilled::Gap`
+    /// for each range of aggregate ids missing from the exchange
+    pub fn agg_trades_backfill(
+        &self,
+        backfill: TradeBackfill,
+    ) -> impl Stream<Item = Result<Backfilled<AggTrade>>> {
+        let fetch_market = self.clone();
+        let locate_market = self.clone();
+        walk(
+            backfill,
+            AGG_TRADES_WEIGHT,
+            AGG_TRADES_WEIGHT,
+            move |backfill| {
+                let market = fetch_market.clone();
+                async move {
+                    let AggTrades::AllAggTrades(trades) = market
+                        .get_agg_trades(
+                            backfill.symbol,
+                            backfill.next_id,
+                            None,
+                            None,
+                            backfill.limit,
+                        )
+                        .await?;
+                    Ok(trades)
+                }
+            },
+            move |symbol, start_time, end_time| {
+                let market = locate_market.clone();
+                async move {
+                    let AggTrades::AllAggTrades(trades) = market
+                        .get_agg_trades(symbol, None, start_time, end_time, 1u16)
+                        .await?;
+                    Ok(trades.first().map(|trade| trade.agg_id))
+                }
+            },
+        )
+    }
+}
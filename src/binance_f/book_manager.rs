@@ -0,0 +1,355 @@
+use super::config::Config;
+use super::market::FuturesMarket;
+use super::orderbook::{BookEvent, BookSync, DesyncReason, OrderBook};
+use super::rest_model::OrderBookPartial;
+use super::util::WeightLimiter;
+use super::websockets::{diff_book_depth_stream, FuturesWebSockets};
+use super::ws_model::{CombinedStreamEvent, DepthOrderBookEvent};
+
+use futures::future::join_all;
+use log::{info, warn};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::sleep;
+
+/// Binance accepts at most 200 streams on a single connection
+pub const MAX_STREAMS_PER_CONNECTION: usize = 200;
+
+/// Request weight of `/fapi/v1/depth` for a given limit
+pub fn depth_weight(limit: u16) -> u32 {
+    match limit {
+        0..=50 => 2,
+        51..=100 => 5,
+        101..=500 => 10,
+        _ => 20,
+    }
+}
+
+/// Cheap, cloneable read access to every book of a `BookManager`
+#[derive(Clone)]
+pub struct BookReader {
+    books: Arc<HashMap<String, BookHandle>>,
+}
+
+#[derive(Clone)]
+struct BookHandle {
+    book: Arc<RwLock<OrderBook>>,
+    synced: Arc<AtomicBool>,
+    notifier: broadcast::Sender<BookEvent>,
+}
+
+impl BookReader {
+    /// Symbols managed, uppercase
+    pub fn symbols(&self) -> impl Iterator<Item = &String> {
+        self.books.keys()
+    }
+
+    /// Shared handle on the book of `symbol`
+    pub fn book(&self, symbol: &str) -> Option<Arc<RwLock<OrderBook>>> {
+        self.books.get(symbol).map(|handle| handle.book.clone())
+    }
+
+    /// Run `f` on the book of `symbol` under its read lock.
+    /// Returns None for an unknown symbol or a book waiting for a snapshot.
+    pub fn read<F, T>(&self, symbol: &str, f: F) -> Option<T>
+    where
+        F: FnOnce(&OrderBook) -> T,
+    {
+        let handle = self.books.get(symbol)?;
+        if !handle.synced.load(Ordering::Relaxed) {
+            return None;
+        }
+        let book = handle.book.read().unwrap();
+        Some(f(&book))
+    }
+
+    /// Whether the book of `symbol` reflects its stream
+    pub fn is_synced(&self, symbol: &str) -> bool {
+        self.books
+            .get(symbol)
+            .map(|handle| handle.synced.load(Ordering::Relaxed))
+            .unwrap_or(false)
+    }
+
+    /// Receive a `BookEvent` for every change of the book of `symbol`
+    pub fn subscribe(&self, symbol: &str) -> Option<broadcast::Receiver<BookEvent>> {
+        self.books
+            .get(symbol)
+            .map(|handle| handle.notifier.subscribe())
+    }
+}
+
+/// One combined stream connection and the books it feeds, borrowed from the manager
+struct BookConnection<'a> {
+    streams: Vec<String>,
+    web_socket: FuturesWebSockets<CombinedStreamEvent<DepthOrderBookEvent>>,
+    receiver: mpsc::Receiver<CombinedStreamEvent<DepthOrderBookEvent>>,
+    syncs: HashMap<String, &'a mut BookSync>,
+}
+
+impl BookConnection<'_> {
+    async fn run(
+        &mut self,
+        market: &FuturesMarket,
+        limiter: &WeightLimiter,
+        depth_limit: u16,
+        running: &AtomicBool,
+    ) {
+        while running.load(Ordering::Relaxed) {
+            if let Err(e) = self
+                .web_socket
+                .connect_multiple_streams(&self.streams)
+                .await
+            {
+                warn!("depth streams connect error: {}, try again...", e);
+                sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+
+            let (request_tx, mut request_rx) = mpsc::unbounded_channel();
+            let (snapshot_tx, mut snapshot_rx) = mpsc::unbounded_channel();
+            let web_socket = &mut self.web_socket;
+            tokio::select! {
+                result = web_socket.event_loop(running) => {
+                    if let Err(e) = result {
+                        warn!("depth streams event_loop error: {}, starting reconnect...", e);
+                    }
+                }
+                _ = fetch_snapshots(market, limiter, depth_limit, &mut request_rx, snapshot_tx) => {}
+                _ = process(&mut self.syncs, &mut self.receiver, request_tx, &mut snapshot_rx) => {}
+            }
+
+            // events from the old connection cannot be chained to the new one
+            while self.receiver.try_recv().is_ok() {}
+            for sync in self.syncs.values_mut() {
+                sync.desync(DesyncReason::Reconnect);
+            }
+        }
+    }
+}
+
+/// Route events to their book and ask for a snapshot when a book loses sync
+async fn process(
+    syncs: &mut HashMap<String, &mut BookSync>,
+    events: &mut mpsc::Receiver<CombinedStreamEvent<DepthOrderBookEvent>>,
+    requests: mpsc::UnboundedSender<String>,
+    snapshots: &mut mpsc::UnboundedReceiver<(String, OrderBookPartial)>,
+) {
+    let mut pending: HashMap<String, bool> = HashMap::new();
+    loop {
+        tokio::select! {
+            event = events.recv() => {
+                let Some(event) = event else { return };
+                let symbol = event.data.symbol.clone();
+                let Some(sync) = syncs.get_mut(&symbol) else { continue };
+                if sync.on_event(event.data) && !pending.get(&symbol).copied().unwrap_or(false) {
+                    pending.insert(symbol.clone(), true);
+                    let _ = requests.send(symbol);
+                }
+            }
+            snapshot = snapshots.recv() => {
+                let Some((symbol, snapshot)) = snapshot else { return };
+                let Some(sync) = syncs.get_mut(&symbol) else { continue };
+                if sync.sync_with(snapshot) {
+                    pending.insert(symbol, false);
+                } else {
+                    let _ = requests.send(symbol);
+                }
+            }
+        }
+    }
+}
+
+/// Fetch requested snapshots one at a time within the weight budget, retrying on error
+async fn fetch_snapshots(
+    market: &FuturesMarket,
+    limiter: &WeightLimiter,
+    depth_limit: u16,
+    requests: &mut mpsc::UnboundedReceiver<String>,
+    snapshots: mpsc::UnboundedSender<(String, OrderBookPartial)>,
+) {
+    while let Some(symbol) = requests.recv().await {
+        loop {
+            limiter.acquire(depth_weight(depth_limit)).await;
+            match market.get_custom_depth(symbol.clone(), depth_limit).await {
+                Ok(snapshot) => {
+                    if snapshots.send((symbol, snapshot)).is_err() {
+                        return;
+                    }
+                    break;
+                }
+                Err(e) => warn!("{} snapshot error: {}, retrying", symbol, e),
+            }
+        }
+    }
+}
+
+/// Keeps the order books of many futures symbols in sync.
+///
+/// Diff depth streams are shared over combined stream connections of up to
+/// `streams_per_connection` symbols. Snapshots are fetched one at a time and
+/// spaced so that their request weight stays under `snapshot_weight_per_minute`,
+/// so syncing hundreds of symbols at start takes a few minutes instead of
+/// tripping the REST limit. Strategies read the books through a `BookReader`.
+///
+/// # Examples
+/// ```rust,no_run
+/// use exrs::binance_f::{api::*, book_manager::*, config::*, market::*};
+/// use std::sync::atomic::AtomicBool;
+///
+/// # async fn run() {
+/// let market: FuturesMarket = BinanceF::new(None, None);
+/// let mut manager = BookManager::new(market, Config::default(), &["BTCUSDT", "ETHUSDT"], 1000)
+///     .set_snapshot_weight_per_minute(600);
+/// let reader = manager.reader();
+/// actix_rt::spawn(async move {
+///     loop {
+///         println!("{:?}", reader.read("ETHUSDT", |book| book.mid_price()));
+///         tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+///     }
+/// });
+/// manager.run(&AtomicBool::new(true)).await;
+/// # }
+/// ```
+pub struct BookManager {
+    market: FuturesMarket,
+    conf: Config,
+    symbols: Vec<String>,
+    depth_limit: u16,
+    update_speed: u16,
+    streams_per_connection: usize,
+    snapshot_weight_per_minute: u32,
+    syncs: HashMap<String, BookSync>,
+}
+
+impl BookManager {
+    /// * `depth_limit`: snapshot size passed to `get_custom_depth`
+    pub fn new<S>(market: FuturesMarket, conf: Config, symbols: &[S], depth_limit: u16) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let symbols: Vec<String> = symbols
+            .iter()
+            .map(|symbol| symbol.as_ref().to_uppercase())
+            .collect();
+        let syncs = symbols
+            .iter()
+            .map(|symbol| (symbol.clone(), BookSync::new(symbol.clone())))
+            .collect();
+        BookManager {
+            market,
+            conf,
+            symbols,
+            depth_limit,
+            update_speed: 100,
+            streams_per_connection: MAX_STREAMS_PER_CONNECTION,
+            snapshot_weight_per_minute: 1200,
+            syncs,
+        }
+    }
+
+    /// Diff depth stream speed in ms, 100, 250 or 500
+    pub fn set_update_speed(mut self, update_speed: u16) -> Self {
+        self.update_speed = update_speed;
+        self
+    }
+
+    pub fn set_streams_per_connection(mut self, streams_per_connection: usize) -> Self {
+        self.streams_per_connection = streams_per_connection.clamp(1, MAX_STREAMS_PER_CONNECTION);
+        self
+    }
+
+    /// Request weight budget for snapshots, leave room for the rest of the strategy
+    pub fn set_snapshot_weight_per_minute(mut self, snapshot_weight_per_minute: u32) -> Self {
+        self.snapshot_weight_per_minute = snapshot_weight_per_minute;
+        self
+    }
+
+    /// Read access to the books, valid for the lifetime of the manager
+    pub fn reader(&self) -> BookReader {
+        let books = self
+            .syncs
+            .iter()
+            .map(|(symbol, sync)| {
+                let handle = BookHandle {
+                    book: sync.book(),
+                    synced: sync.synced(),
+                    notifier: sync.notifier(),
+                };
+                (symbol.clone(), handle)
+            })
+            .collect();
+        BookReader {
+            books: Arc::new(books),
+        }
+    }
+
+    /// Connect every combined stream and keep all books in sync until `running`
+    /// is cleared. Connections reconnect on their own and snapshot errors are
+    /// retried, so this only returns once stopped.
+    pub async fn run(&mut self, running: &AtomicBool) {
+        let limiter = WeightLimiter::new(self.snapshot_weight_per_minute);
+        let chunks: Vec<&[String]> = self.symbols.chunks(self.streams_per_connection).collect();
+        let mut connections: Vec<BookConnection> = chunks
+            .iter()
+            .map(|symbols| {
+                let (sender, receiver) = mpsc::channel(4096);
+                BookConnection {
+                    streams: symbols
+                        .iter()
+                        .map(|symbol| {
+                            diff_book_depth_stream(&symbol.to_lowercase(), self.update_speed)
+                        })
+                        .collect(),
+                    web_socket: FuturesWebSockets::new_with_options(sender, self.conf.clone()),
+                    receiver,
+                    syncs: HashMap::new(),
+                }
+            })
+            .collect();
+        // the syncs stay in the manager, so dropping this future loses no book
+        for (symbol, sync) in self.syncs.iter_mut() {
+            if let Some(connection) = chunks.iter().position(|symbols| symbols.contains(symbol)) {
+                connections[connection].syncs.insert(symbol.clone(), sync);
+            }
+        }
+        info!(
+            "syncing {} books over {} connections",
+            self.symbols.len(),
+            connections.len()
+        );
+
+        join_all(
+            connections.iter_mut().map(|connection| {
+                connection.run(&self.market, &limiter, self.depth_limit, running)
+            }),
+        )
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::time::Instant;
+
+    #[tokio::test(start_paused = true)]
+    async fn limiter_spaces_snapshots_by_weight() {
+        // limit 1000 weighs 20, so 60 snapshots a minute fit in 1200
+        let limiter = WeightLimiter::new(1200);
+        let start = Instant::now();
+        limiter.acquire(depth_weight(1000)).await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        limiter.acquire(depth_weight(1000)).await;
+        limiter.acquire(depth_weight(1000)).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+
+        let limiter = WeightLimiter::new(10);
+        limiter.acquire(depth_weight(100)).await;
+        limiter.acquire(depth_weight(100)).await;
+        assert_eq!(start.elapsed(), Duration::from_secs(32));
+    }
+}
@@ -140,6 +140,32 @@ impl Client {
         self.handler(response).await
     }
 
+    pub async fn put_signed(&self, endpoint: &str, request: &str) -> Result<String> {
+        let url = self.sign_request(endpoint, request);
+        let response = self
+            .inner
+            .clone()
+            .put(url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    pub async fn put_signed_p<T: de::DeserializeOwned, P: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        payload: P,
+        recv_window: u64,
+    ) -> Result<T> {
+        let request = build_signed_request_p(payload, recv_window)?;
+        let string = self.put_signed(endpoint, &request).await?;
+        let data: &str = string.as_str();
+        let t = from_str(data)?;
+        Ok(t)
+    }
+
     pub async fn get(&self, endpoint: &str, request: &str) -> Result<String> {
         let mut url: String = format!("{}{}", self.host, endpoint);
         if !request.is_empty() {
@@ -263,7 +289,7 @@ impl Client {
     }
 }
 
-fn handle_content_error(error: BinanceContentError) -> crate::binance_f::errors::Error {
+pub(crate) fn handle_content_error(error: BinanceContentError) -> crate::binance_f::errors::Error {
     match (error.code, error.msg.as_ref()) {
         (-1013, error_messages::INVALID_PRICE) => Error::InvalidPrice,
         (-1125, msg) => Error::InvalidListenKey(msg.to_string()),
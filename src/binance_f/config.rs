@@ -1,19 +1,27 @@
+use std::time::Duration;
+
+pub use crate::binance::config::IdleTimeouts;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Config {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
     pub recv_window: u64,
+    /// Interval at which the websocket client sends its own ping frames
+    pub ws_ping_interval: Option<Duration>,
+    /// How long a stream may go without a message before it is considered stale
+    pub ws_idle_timeouts: IdleTimeouts,
 }
 
-impl Config {
+impl Default for Config {
     /// Configure binance with default production endpoints
     /// # Examples
     /// ```
-    /// use binance_f::config::Config;
+    /// use exrs::binance_f::config::Config;
     /// let config = Config::default();
     /// ```
-    pub fn default() -> Config {
+    fn default() -> Config {
         Config {
             // futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             // futures_ws_endpoint: "wss://fstream.binance.com".into(),
@@ -21,22 +29,28 @@ impl Config {
             futures_ws_endpoint: "wss://fstream-mm.binance.com".into(),
 
             recv_window: 5000,
+            ws_ping_interval: Some(Duration::from_secs(30)),
+            ws_idle_timeouts: IdleTimeouts::default(),
         }
     }
+}
 
+impl Config {
     pub fn colo() -> Config {
         Config {
             futures_rest_api_endpoint: "https://fapi-mm.binance.com".into(),
             futures_ws_endpoint: "wss://fstream-mm.binance.com".into(),
 
             recv_window: 5000,
+            ws_ping_interval: Some(Duration::from_secs(30)),
+            ws_idle_timeouts: IdleTimeouts::default(),
         }
     }
 
     /// Configure binance with all testnet endpoints
     /// # Examples
     /// ```
-    /// use binance::config::Config;
+    /// use exrs::binance::config::Config;
     /// let config = Config::testnet();
     /// ```
     pub fn testnet() -> Config {
@@ -62,4 +76,15 @@ impl Config {
         self.recv_window = recv_window;
         self
     }
+
+    /// Set the client ping interval, `None` only answers server pings
+    pub fn set_ws_ping_interval(mut self, ws_ping_interval: Option<Duration>) -> Self {
+        self.ws_ping_interval = ws_ping_interval;
+        self
+    }
+
+    pub fn set_ws_idle_timeouts(mut self, ws_idle_timeouts: IdleTimeouts) -> Self {
+        self.ws_idle_timeouts = ws_idle_timeouts;
+        self
+    }
 }
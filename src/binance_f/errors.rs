@@ -47,6 +47,12 @@ pub enum Error {
     InvalidListenKey(String),
     #[error("unknown symbol {0}")]
     UnknownSymbol(String),
+    #[error(transparent)]
+    FilterViolation(#[from] crate::binance::filters::FilterViolation),
+    #[error(transparent)]
+    InvalidFilter(#[from] crate::binance::filters::InvalidFilter),
+    #[error("invalid option symbol {0}")]
+    InvalidOptionSymbol(String),
     #[error("{msg}")]
     InvalidOrderError { msg: String },
     #[error("invalid price")]
@@ -59,6 +65,11 @@ pub enum Error {
     ServiceUnavailable,
     #[error("Unauthorized")]
     Unauthorized,
+    #[error("stream stale, no message for {0:?}")]
+    Stale(std::time::Duration),
+    /// The request of several batched orders failed, each of them shares its error
+    #[error(transparent)]
+    BatchRequest(std::sync::Arc<Error>),
     #[error("{0}")]
     Msg(String),
 }
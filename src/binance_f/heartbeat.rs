@@ -0,0 +1,253 @@
+use super::account::FuturesAccount;
+use super::errors::*;
+use super::util::get_timestamp;
+
+use log::warn;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use tokio::time::{interval, MissedTickBehavior};
+
+/// Lifecycle of a `CountdownHeartbeat`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeartbeatState {
+    /// `run` was not called yet
+    Idle,
+    /// The countdowns of every symbol were refreshed at the last interval
+    Armed,
+    /// The last refresh of some symbols failed, their countdowns may run out
+    Degraded,
+    /// The health check failed, the countdowns are left to expire and cancel the orders
+    Tripped,
+    /// `running` was cleared and the countdowns were disarmed
+    Stopped,
+}
+
+/// Monitoring view of a `CountdownHeartbeat`
+#[derive(Clone, Debug)]
+pub struct HeartbeatStatus {
+    pub state: HeartbeatState,
+    /// Time in ms of the last successful refresh of each symbol
+    pub last_refresh: BTreeMap<String, u64>,
+    /// Failed refreshes of each symbol since its last successful one
+    pub consecutive_failures: BTreeMap<String, u32>,
+    pub last_error: Option<String>,
+}
+
+impl HeartbeatStatus {
+    fn record(&mut self, symbol: &str, result: Result<()>) {
+        match result {
+            Ok(()) => {
+                self.consecutive_failures.insert(symbol.to_string(), 0);
+                self.last_refresh
+                    .insert(symbol.to_string(), get_timestamp().unwrap_or_default());
+            }
+            Err(e) => {
+                warn!("{} countdown cancel all refresh error: {}", symbol, e);
+                *self
+                    .consecutive_failures
+                    .entry(symbol.to_string())
+                    .or_default() += 1;
+                self.last_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Armed only when the last refresh of every symbol succeeded
+    fn settle(&mut self, symbols: &[String]) {
+        let armed = symbols
+            .iter()
+            .all(|symbol| self.consecutive_failures.get(symbol) == Some(&0));
+        self.state = if armed {
+            HeartbeatState::Armed
+        } else {
+            HeartbeatState::Degraded
+        };
+    }
+}
+
+/// Dead man's switch for resting futures orders. Arms `countdownCancelAll` for every
+/// symbol and refreshes it every `interval` for as long as `health_check` passes.
+/// When the process dies or the health check fails the countdown runs out and the
+/// exchange cancels the open orders of the symbols.
+///
+/// # Examples
+/// ```rust,no_run
+/// use exrs::binance_f::{api::*, account::*, config::*, heartbeat::*};
+/// use std::sync::atomic::AtomicBool;
+/// use std::time::Duration;
+///
+/// # async fn run() -> exrs::binance_f::errors::Result<()> {
+/// let account: FuturesAccount = BinanceF::new_with_env(&Config::default());
+/// let mut heartbeat = CountdownHeartbeat::new(
+///     account,
+///     vec!["BTCUSDT".to_string()],
+///     Duration::from_secs(60),
+///     Duration::from_secs(15),
+///     || true,
+/// );
+/// let status = heartbeat.status();
+/// heartbeat.run(&AtomicBool::new(true)).await?;
+/// println!("{:?}", status.read().unwrap().state);
+/// # Ok(())
+/// # }
+/// ```
+pub struct CountdownHeartbeat<H> {
+    account: FuturesAccount,
+    symbols: Vec<String>,
+    countdown: Duration,
+    interval: Duration,
+    health_check: H,
+    status: Arc<RwLock<HeartbeatStatus>>,
+}
+
+impl<H> CountdownHeartbeat<H>
+where
+    H: Fn() -> bool,
+{
+    /// * `countdown`: time left to the cancellation after each refresh
+    /// * `interval`: time between refreshes, shorter than `countdown`
+    pub fn new(
+        account: FuturesAccount,
+        symbols: Vec<String>,
+        countdown: Duration,
+        interval: Duration,
+        health_check: H,
+    ) -> Self {
+        CountdownHeartbeat {
+            account,
+            symbols,
+            countdown,
+            interval,
+            health_check,
+            status: Arc::new(RwLock::new(HeartbeatStatus {
+                state: HeartbeatState::Idle,
+                last_refresh: BTreeMap::new(),
+                consecutive_failures: BTreeMap::new(),
+                last_error: None,
+            })),
+        }
+    }
+
+    /// Shared read handle on the status
+    pub fn status(&self) -> Arc<RwLock<HeartbeatStatus>> {
+        self.status.clone()
+    }
+
+    pub fn state(&self) -> HeartbeatState {
+        self.status.read().unwrap().state
+    }
+
+    /// Refresh the countdowns until `running` is cleared, then disarm them,
+    /// or until the health check fails, then leave them to expire.
+    /// Failed refreshes are retried at the next interval.
+    pub async fn run(&mut self, running: &AtomicBool) -> Result<()> {
+        if self.interval >= self.countdown {
+            return Err(Error::Msg(format!(
+                "heartbeat interval {:?} must be shorter than the countdown {:?}",
+                self.interval, self.countdown
+            )));
+        }
+        let mut ticks = interval(self.interval);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticks.tick().await;
+            if !running.load(Ordering::Relaxed) {
+                self.disarm().await;
+                return Ok(());
+            }
+            if !(self.health_check)() {
+                warn!("health check failed, countdown cancel all left to expire");
+                self.set_state(HeartbeatState::Tripped);
+                return Ok(());
+            }
+            self.refresh().await;
+        }
+    }
+
+    async fn refresh(&self) {
+        let countdown = self.countdown.as_millis() as u64;
+        for symbol in &self.symbols {
+            let result = self.account.countdown_cancel_all(symbol, countdown).await;
+            self.status
+                .write()
+                .unwrap()
+                .record(symbol, result.map(|_| ()));
+        }
+        self.status.write().unwrap().settle(&self.symbols);
+    }
+
+    async fn disarm(&self) {
+        for symbol in &self.symbols {
+            if let Err(e) = self.account.countdown_cancel_all(symbol, 0).await {
+                warn!("{} countdown cancel all disarm error: {}", symbol, e);
+                self.status.write().unwrap().last_error = Some(e.to_string());
+            }
+        }
+        self.set_state(HeartbeatState::Stopped);
+    }
+
+    fn set_state(&self, state: HeartbeatState) {
+        self.status.write().unwrap().state = state;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance_f::client::Client;
+
+    fn heartbeat(interval: u64, healthy: bool) -> CountdownHeartbeat<impl Fn() -> bool> {
+        let account = FuturesAccount {
+            client: Client::new(None, None, "http://127.0.0.1:9".into()),
+            recv_window: 5000,
+            filters: None,
+            reference_price: None,
+        };
+        CountdownHeartbeat::new(
+            account,
+            vec!["BTCUSDT".into()],
+            Duration::from_secs(60),
+            Duration::from_secs(interval),
+            move || healthy,
+        )
+    }
+
+    #[tokio::test]
+    async fn heartbeat_trips_on_failed_health_check() {
+        let mut hb = heartbeat(15, false);
+        assert_eq!(hb.state(), HeartbeatState::Idle);
+        hb.run(&AtomicBool::new(true)).await.unwrap();
+        assert_eq!(hb.state(), HeartbeatState::Tripped);
+        assert!(hb.status().read().unwrap().last_refresh.is_empty());
+
+        assert!(heartbeat(60, true)
+            .run(&AtomicBool::new(true))
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn armed_only_when_every_symbol_refreshed() {
+        let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+        let hb = heartbeat(15, true);
+        let mut status = hb.status().read().unwrap().clone();
+
+        status.record("BTCUSDT", Ok(()));
+        status.settle(&symbols);
+        assert_eq!(status.state, HeartbeatState::Degraded);
+
+        status.record("ETHUSDT", Err(Error::Msg("timeout".into())));
+        status.record("ETHUSDT", Err(Error::Msg("timeout".into())));
+        status.settle(&symbols);
+        assert_eq!(status.state, HeartbeatState::Degraded);
+        assert_eq!(status.consecutive_failures["BTCUSDT"], 0);
+        assert_eq!(status.consecutive_failures["ETHUSDT"], 2);
+
+        status.record("ETHUSDT", Ok(()));
+        status.settle(&symbols);
+        assert_eq!(status.state, HeartbeatState::Armed);
+        assert_eq!(status.consecutive_failures["ETHUSDT"], 0);
+    }
+}
@@ -6,26 +6,50 @@
 - [x] `Compressed/Aggregate Trades List`
 - [x] `Kline/Candlestick Data`
 - [x] `Mark Price`
-- [ ] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate History (MARKET_DATA)`
 - [x] `24hr Ticker Price Change Statistics`
 - [x] `Symbol Price Ticker`
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
 - [ ] `Notional and Leverage Brackets (MARKET_DATA)`
-- [ ] `Open Interest Statistics (MARKET_DATA)`
+- [x] `Open Interest Statistics (MARKET_DATA)`
+- [x] `Basis`
+- [x] `Multi-Assets Mode Asset Index`
 - [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
 - [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
 - [ ] `Long/Short Ratio (MARKET_DATA)`
 - [ ] `Taker Buy/Sell Volume (MARKET_DATA)`
 */
 
+use super::account::ContractType;
 use super::client::*;
 use super::errors::*;
 use super::rest_model::*;
 use super::util::*;
+pub use crate::binance::market::KlinePages;
+use futures::stream::{self, Stream, TryStreamExt};
 use serde_json::Value;
 
+const KLINES_MAX_LIMIT: u16 = 1500;
+const FUNDING_RATE_MAX_LIMIT: u16 = 1000;
+/// Page size limit of the `/futures/data` endpoints
+const TRADING_DATA_MAX_LIMIT: u16 = 500;
+/// `/fapi/v1/fundingRate` shares a limit of 500 requests per 5 minutes
+const FUNDING_RATE_REQUESTS_PER_MINUTE: u32 = 100;
+/// `/futures/data` endpoints are limited to 1000 requests per 5 minutes
+const TRADING_DATA_REQUESTS_PER_MINUTE: u32 = 200;
+
+/// Request weight of the kline endpoints for a given limit
+fn klines_weight(limit: u16) -> u32 {
+    match limit {
+        0..=99 => 1,
+        100..=499 => 2,
+        500..=1000 => 5,
+        _ => 10,
+    }
+}
+
 #[derive(Clone)]
 pub struct FuturesMarket {
     pub client: Client,
@@ -57,7 +81,7 @@ impl FuturesMarket {
                 "/fapi/v1/depth",
                 Some(DepthQuery {
                     symbol: symbol.into(),
-                    limit: limit,
+                    limit,
                 }),
             )
             .await
@@ -141,27 +165,26 @@ impl FuturesMarket {
     }
 
     /// Get Top Trader Position Long/Short Ratio
-    pub async fn get_trader_position_long_short_ratio<S1, S2, S3, S4, S5>(
+    pub async fn get_trader_position_long_short_ratio<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        period: S2,
-        start_time: S3,
-        end_time: S4,
-        limit: S5,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<Option<u64>>,
         S3: Into<Option<u64>>,
-        S4: Into<Option<u64>>,
-        S5: Into<u16>,
+        S4: Into<u16>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period),
             from_id: None,
             interval: None,
         };
@@ -176,27 +199,26 @@ impl FuturesMarket {
     }
 
     /// Get Long/Short Ratio
-    pub async fn get_long_short_ratio<S1, S2, S3, S4, S5>(
+    pub async fn get_long_short_ratio<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        period: S2,
-        start_time: S3,
-        end_time: S4,
-        limit: S5,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<Option<u64>>,
         S3: Into<Option<u64>>,
-        S4: Into<Option<u64>>,
-        S5: Into<u16>,
+        S4: Into<u16>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period),
             from_id: None,
             interval: None,
         };
@@ -211,27 +233,26 @@ impl FuturesMarket {
     }
 
     /// Get Taker Long/Short Ratio
-    pub async fn get_taker_long_short_ratio<S1, S2, S3, S4, S5>(
+    pub async fn get_taker_long_short_ratio<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        period: S2,
-        start_time: S3,
-        end_time: S4,
-        limit: S5,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
     ) -> Result<Vec<LongShortRatio>>
     where
         S1: Into<String>,
-        S2: Into<String>,
+        S2: Into<Option<u64>>,
         S3: Into<Option<u64>>,
-        S4: Into<Option<u64>>,
-        S5: Into<u16>,
+        S4: Into<u16>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            period: Some(period.into()),
+            period: Some(period),
             from_id: None,
             interval: None,
         };
@@ -245,116 +266,100 @@ impl FuturesMarket {
             .await
     }
 
-    /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    /// Returns up to 'limit' klines for given symbol and interval
     /// https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
-    pub async fn get_klines<S1, S2, S3, S4, S5>(
+    pub async fn get_klines<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        interval: S2,
-        limit: S3,
-        start_time: S4,
-        end_time: S5,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
     ) -> Result<Klines>
     where
         S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<u16>,
+        S2: Into<u16>,
+        S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
-        S5: Into<Option<u64>>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval),
             from_id: None,
             period: None,
         };
+        query.validate()?;
         let data: Vec<Vec<Value>> = self.client.get_d("/fapi/v1/klines", Some(query)).await?;
 
-        let klines = Klines::AllKlines(
-            data.iter()
-                .map(|row| Kline {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
+        let klines = Klines::AllKlines(data.iter().map(|row| kline(row)).collect());
         Ok(klines)
     }
 
-    /// Returns up to 'limit' blvt klines for given symbol and interval ("1m", "5m", ...)
+    /// Returns up to 'limit' blvt klines for given symbol and interval
     /// Note that the symbol is not the traditional pair but rather {symbol}{UP|DOWN}
     /// https://binance-docs.github.io/apidocs/futures/en/#blvt-nav-kline-candlestick-streams
     /// As the vector fields are undocumented on binance futures you are un your own, follow
     /// KlineSummary for an example
-    pub async fn get_blvt_klines_v<S1, S2, S3, S4, S5>(
+    pub async fn get_blvt_klines_v<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        interval: S2,
-        limit: S3,
-        start_time: S4,
-        end_time: S5,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<u16>,
+        S2: Into<u16>,
+        S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
-        S5: Into<Option<u64>>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval),
             from_id: None,
             period: None,
         };
+        query.validate()?;
         let klines = self.client.get_d("/fapi/v1/lvtKlines", Some(query)).await?;
 
         Ok(klines)
     }
 
-    /// Returns up to 'limit' mark price klines for given symbol and interval ("1m", "5m", ...)
+    /// Returns up to 'limit' mark price klines for given symbol and interval
     /// https://binance-docs.github.io/apidocs/futures/en/#mark-price-kline-candlestick-data
     /// As the vector fields are undocumented on binance futures you are un your own, follow
     /// KlineSummary for an example
-    pub async fn get_mark_price_klines_v<S1, S2, S3, S4, S5>(
+    pub async fn get_mark_price_klines_v<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        interval: S2,
-        limit: S3,
-        start_time: S4,
-        end_time: S5,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<u16>,
+        S2: Into<u16>,
+        S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
-        S5: Into<Option<u64>>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval),
             from_id: None,
             period: None,
         };
+        query.validate()?;
         let klines = self
             .client
             .get_d("/fapi/v1/markPriceKlines", Some(query))
@@ -363,34 +368,34 @@ impl FuturesMarket {
         Ok(klines)
     }
 
-    /// Returns up to 'limit' index price klines for given symbol and interval ("1m", "5m", ...)
+    /// Returns up to 'limit' index price klines for given symbol and interval
     /// https://binance-docs.github.io/apidocs/futures/en/#index-price-kline-candlestick-data
     /// As the vector fields are undocumented on binance futures you are un your own, follow
     /// KlineSummary for an example
-    pub async fn get_index_price_klines_v<S1, S2, S3, S4, S5>(
+    pub async fn get_index_price_klines_v<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        interval: S2,
-        limit: S3,
-        start_time: S4,
-        end_time: S5,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<u16>,
+        S2: Into<u16>,
+        S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
-        S5: Into<Option<u64>>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval),
             from_id: None,
             period: None,
         };
+        query.validate()?;
 
         let klines = self
             .client
@@ -400,34 +405,34 @@ impl FuturesMarket {
         Ok(klines)
     }
 
-    /// Returns up to 'limit' continuous contract klines for given symbol and interval ("1m", "5m", ...)
+    /// Returns up to 'limit' continuous contract klines for given symbol and interval
     /// https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-data
     /// As the vector fields are undocumented on binance futures you are un your own, follow
     /// KlineSummary for an example
-    pub async fn get_continuous_contract_klines_v<S1, S2, S3, S4, S5>(
+    pub async fn get_continuous_contract_klines_v<S1, S2, S3, S4>(
         &self,
         symbol: S1,
-        interval: S2,
-        limit: S3,
-        start_time: S4,
-        end_time: S5,
+        interval: KlineInterval,
+        limit: S2,
+        start_time: S3,
+        end_time: S4,
     ) -> Result<Vec<Vec<Value>>>
     where
         S1: Into<String>,
-        S2: Into<String>,
-        S3: Into<u16>,
+        S2: Into<u16>,
+        S3: Into<Option<u64>>,
         S4: Into<Option<u64>>,
-        S5: Into<Option<u64>>,
     {
         let query = HistoryQuery {
             start_time: start_time.into(),
             end_time: end_time.into(),
             limit: limit.into(),
             symbol: symbol.into(),
-            interval: Some(interval.into()),
+            interval: Some(interval),
             from_id: None,
             period: None,
         };
+        query.validate()?;
         let klines = self
             .client
             .get_d("/fapi/v1/continuousKlines", Some(query))
@@ -436,6 +441,71 @@ impl FuturesMarket {
         Ok(klines)
     }
 
+    /// Stream every kline of `symbol` opened between `start_time` and `end_time` (ms, inclusive),
+    /// fetching as many pages as needed within the request weight budget of `KlineRange`.
+    /// Use `KlineRange::set_source` and `klines_range_from` for mark price, index price
+    /// and continuous contract klines.
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_f::{api::*, market::*, rest_model::KlineInterval};
+    /// use futures::TryStreamExt;
+    /// let market: FuturesMarket = BinanceF::new(None, None);
+    /// let range = KlineRange::new("BTCUSDT", KlineInterval::Minute1, 1_640_995_200_000, 1_643_673_600_000)
+    ///     .set_source(KlineSource::MarkPrice);
+    /// let klines: Vec<_> = tokio_test::block_on(market.klines_range_from(range).try_collect()).unwrap();
+    /// ```
+    pub fn klines_range<S>(
+        &self,
+        symbol: S,
+        interval: KlineInterval,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<Kline>>
+    where
+        S: Into<String>,
+    {
+        self.klines_range_from(KlineRange::new(symbol, interval, start_time, end_time))
+    }
+
+    /// Stream the klines left in `range`.
+    /// Keep the range up to date with `KlineRange::advance` for each kline received
+    /// to resume an interrupted download from where it stopped.
+    pub fn klines_range_from(&self, range: KlineRange) -> impl Stream<Item = Result<Kline>> {
+        let market = self.clone();
+        let limiter = WeightLimiter::new(range.weight_per_minute);
+        stream::try_unfold(
+            (range, limiter, false),
+            move |(mut range, limiter, done)| {
+                let market = market.clone();
+                async move {
+                    if done || range.is_done() {
+                        return Ok(None);
+                    }
+                    if !INTERVALS.contains(&range.interval) {
+                        return Err(Error::InvalidPeriod(range.interval.to_string()));
+                    }
+                    limiter.acquire(klines_weight(range.limit)).await;
+                    let data: Vec<Vec<Value>> = market
+                        .client
+                        .get_d(range.source.endpoint(), Some(range.query()))
+                        .await?;
+                    let done = data.len() < range.limit as usize;
+                    let page = range
+                        .pages
+                        .take_page(data.iter().map(|row| kline(row)).collect(), |kline| {
+                            kline.open_time
+                        });
+                    if page.is_empty() {
+                        return Ok(None);
+                    }
+                    Ok(Some((page, (range, limiter, done))))
+                }
+            },
+        )
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
     /// https://binance-docs.github.io/apidocs/futures/en/#notional-and-leverage-brackets-user_data
     pub async fn get_notional_leverage_brackets<S>(&self, symbol: S) -> Result<SymbolBrackets>
     where
@@ -452,7 +522,7 @@ impl FuturesMarket {
 
     /// https://binance-docs.github.io/apidocs/futures/en/#composite-index-symbol-information
     /// Only for composite symbols (ex: DEFIUSDT)
-    pub async fn get_index_info<S>(&self, symbol: Option<S>) -> Result<PriceStats>
+    pub async fn get_index_info<S>(&self, symbol: Option<S>) -> Result<Vec<IndexInfo>>
     where
         S: Into<String>,
     {
@@ -524,6 +594,232 @@ impl FuturesMarket {
         self.client.get_p("/fapi/v1/allForceOrders", "").await
     }
 
+    /// Mark price and funding rate for ONE symbol
+    pub async fn get_premium_index<S>(&self, symbol: S) -> Result<PremiumIndex>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                "/fapi/v1/premiumIndex",
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Funding rate history, of all symbols when `symbol` is None.
+    /// Up to `limit` (max 1000) events from `start_time`, or the latest ones without it
+    pub async fn get_funding_rate_history<S1, S2, S3, S4>(
+        &self,
+        symbol: Option<S1>,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        let query = FundingRateQuery {
+            symbol: symbol.map(Into::into),
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        self.client.get_d("/fapi/v1/fundingRate", Some(query)).await
+    }
+
+    /// Stream the funding rate history of `symbol` over `range`
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_f::{api::*, market::*, config::*};
+    /// use futures::TryStreamExt;
+    /// let market: FuturesMarket = BinanceF::new_with_env(&Config::default());
+    /// let range = TimeRange::new(1_640_995_200_000, 1_672_531_200_000);
+    /// let rates: Vec<_> = tokio_test::block_on(market.funding_rate_range("BTCUSDT", range).try_collect()).unwrap();
+    /// ```
+    pub fn funding_rate_range<S>(
+        &self,
+        symbol: S,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<FundingRate>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        time_range(
+            range.cap_limit(FUNDING_RATE_MAX_LIMIT),
+            FUNDING_RATE_REQUESTS_PER_MINUTE,
+            None,
+            None,
+            move |start_time, end_time, limit| {
+                let market = market.clone();
+                let symbol = symbol.clone();
+                async move {
+                    market
+                        .get_funding_rate_history(Some(symbol), start_time, end_time, limit)
+                        .await
+                }
+            },
+            |rate: &FundingRate| rate.funding_time,
+            |_: &FundingRate| None,
+        )
+    }
+
+    /// Funding rate cap, floor and interval of the symbols that had them adjusted
+    pub async fn get_funding_info(&self) -> Result<Vec<FundingInfo>> {
+        self.client.get_p("/fapi/v1/fundingInfo", "").await
+    }
+
+    /// Open interest statistics, only the last 30 days are available
+    pub async fn get_open_interest_history<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<OpenInterestHistory>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<u16>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+            symbol: symbol.into(),
+            period: Some(period),
+            from_id: None,
+            interval: None,
+        };
+        query.validate()?;
+        self.client
+            .get_d("/futures/data/openInterestHist", Some(query))
+            .await
+    }
+
+    /// Stream the open interest statistics of `symbol` over `range`
+    pub fn open_interest_history_range<S>(
+        &self,
+        symbol: S,
+        period: KlineInterval,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<OpenInterestHistory>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        time_range(
+            range.cap_limit(TRADING_DATA_MAX_LIMIT),
+            TRADING_DATA_REQUESTS_PER_MINUTE,
+            Some(period),
+            None,
+            move |start_time, end_time, limit| {
+                let market = market.clone();
+                let symbol = symbol.clone();
+                async move {
+                    market
+                        .get_open_interest_history(symbol, period, start_time, end_time, limit)
+                        .await
+                }
+            },
+            |stats: &OpenInterestHistory| stats.timestamp,
+            |_: &OpenInterestHistory| None,
+        )
+    }
+
+    /// Basis of a contract against its index, only the last 30 days are available
+    pub async fn get_basis<S1, S2, S3, S4>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        period: KlineInterval,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<Basis>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<u16>,
+    {
+        if !PERIODS.contains(&period) {
+            return Err(Error::InvalidPeriod(period.to_string()));
+        }
+        let query = BasisQuery {
+            pair: pair.into(),
+            contract_type,
+            period,
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        self.client.get_d("/futures/data/basis", Some(query)).await
+    }
+
+    /// Stream the basis of a contract over `range`
+    pub fn basis_range<S>(
+        &self,
+        pair: S,
+        contract_type: ContractType,
+        period: KlineInterval,
+        range: TimeRange,
+    ) -> impl Stream<Item = Result<Basis>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let pair = pair.into();
+        time_range(
+            range.cap_limit(TRADING_DATA_MAX_LIMIT),
+            TRADING_DATA_REQUESTS_PER_MINUTE,
+            Some(period),
+            None,
+            move |start_time, end_time, limit| {
+                let market = market.clone();
+                let pair = pair.clone();
+                let contract_type = contract_type.clone();
+                async move {
+                    market
+                        .get_basis(pair, contract_type, period, start_time, end_time, limit)
+                        .await
+                }
+            },
+            |basis: &Basis| basis.timestamp,
+            |_: &Basis| None,
+        )
+    }
+
+    /// Multi-assets mode index of ONE asset symbol (ex: ADAUSD)
+    pub async fn get_asset_index<S>(&self, symbol: S) -> Result<AssetIndex>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                "/fapi/v1/assetIndex",
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Multi-assets mode index of all assets
+    pub async fn get_all_asset_indexes(&self) -> Result<Vec<AssetIndex>> {
+        self.client.get_p("/fapi/v1/assetIndex", "").await
+    }
+
     pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
     where
         S: Into<String>,
@@ -538,3 +834,492 @@ impl FuturesMarket {
             .await
     }
 }
+
+/// Price series of a kline download
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum KlineSource {
+    /// Trade klines, `/fapi/v1/klines`
+    #[default]
+    Trade,
+    /// `/fapi/v1/markPriceKlines`
+    MarkPrice,
+    /// `/fapi/v1/indexPriceKlines`, the symbol is the index pair
+    IndexPrice,
+    /// `/fapi/v1/continuousKlines`, the symbol is the pair
+    Continuous(ContractType),
+}
+
+impl KlineSource {
+    fn endpoint(&self) -> &'static str {
+        match self {
+            KlineSource::Trade => "/fapi/v1/klines",
+            KlineSource::MarkPrice => "/fapi/v1/markPriceKlines",
+            KlineSource::IndexPrice => "/fapi/v1/indexPriceKlines",
+            KlineSource::Continuous(_) => "/fapi/v1/continuousKlines",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct KlineRangeQuery {
+    symbol: Option<String>,
+    pair: Option<String>,
+    contract_type: Option<ContractType>,
+    interval: KlineInterval,
+    start_time: u64,
+    end_time: u64,
+    limit: u16,
+}
+
+/// Progress of a `klines_range` download, serializable so that it can be persisted
+/// and passed back to `klines_range_from` after an interruption
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineRange {
+    /// Symbol, or pair for index price and continuous contract klines
+    pub symbol: String,
+    pub interval: KlineInterval,
+    pub source: KlineSource,
+    #[serde(flatten)]
+    pub pages: KlinePages,
+    /// Page size, at most 1500
+    pub limit: u16,
+    /// Request weight budget of the download
+    pub weight_per_minute: u32,
+}
+
+impl KlineRange {
+    pub fn new<S>(symbol: S, interval: KlineInterval, start_time: u64, end_time: u64) -> Self
+    where
+        S: Into<String>,
+    {
+        KlineRange {
+            symbol: symbol.into(),
+            interval,
+            source: KlineSource::Trade,
+            pages: KlinePages::new(start_time, end_time),
+            limit: KLINES_MAX_LIMIT,
+            weight_per_minute: 1200,
+        }
+    }
+
+    pub fn set_source(mut self, source: KlineSource) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Pages of 1000 cost half the weight of pages of 1500
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = limit.clamp(1, KLINES_MAX_LIMIT);
+        self
+    }
+
+    pub fn set_weight_per_minute(mut self, weight_per_minute: u32) -> Self {
+        self.weight_per_minute = weight_per_minute;
+        self
+    }
+
+    /// Record the kline opened at `open_time` as received
+    pub fn advance(&mut self, open_time: i64) {
+        self.pages.advance(open_time);
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pages.is_done()
+    }
+
+    fn query(&self) -> KlineRangeQuery {
+        let (symbol, pair, contract_type) = match &self.source {
+            KlineSource::Trade | KlineSource::MarkPrice => (Some(self.symbol.clone()), None, None),
+            KlineSource::IndexPrice => (None, Some(self.symbol.clone()), None),
+            KlineSource::Continuous(contract_type) => {
+                (None, Some(self.symbol.clone()), Some(contract_type.clone()))
+            }
+        };
+        KlineRangeQuery {
+            symbol,
+            pair,
+            contract_type,
+            interval: self.interval,
+            start_time: self.pages.start_time,
+            end_time: self.pages.end_time,
+            limit: self.limit,
+        }
+    }
+}
+
+/// Progress of a time series download such as `funding_rate_range`, serializable so
+/// that it can be persisted and passed back to the same method after an interruption
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeRange {
+    /// Earliest time left to fetch, in ms
+    pub start_time: u64,
+    /// Latest time to fetch, in ms
+    pub end_time: u64,
+    /// Page size, capped by each endpoint
+    pub limit: u16,
+    /// Ids of the items received at `start_time`, series with ids are fetched again
+    /// from the time of their last item as more items may share its millisecond
+    #[serde(default)]
+    pub seen_ids: Vec<u64>,
+    /// Id of the next item of a series paged by id, such as the user trades
+    #[serde(default)]
+    pub from_id: Option<u64>,
+}
+
+impl TimeRange {
+    pub fn new(start_time: u64, end_time: u64) -> Self {
+        TimeRange {
+            start_time,
+            end_time,
+            limit: u16::MAX,
+            seen_ids: Vec::new(),
+            from_id: None,
+        }
+    }
+
+    pub fn set_limit(mut self, limit: u16) -> Self {
+        self.limit = limit.max(1);
+        self
+    }
+
+    /// Record every item up to `time` as received
+    pub fn advance(&mut self, time: u64) {
+        if time >= self.start_time {
+            self.start_time = time + 1;
+            self.seen_ids.clear();
+        }
+    }
+
+    /// Record the item `id` at `time` as received, for series whose items have an id
+    pub fn advance_item(&mut self, time: u64, id: u64) {
+        if time > self.start_time {
+            self.start_time = time;
+            self.seen_ids.clear();
+        }
+        if time == self.start_time && !self.seen_ids.contains(&id) {
+            self.seen_ids.push(id);
+        }
+        self.from_id = self.from_id.max(Some(id + 1));
+    }
+
+    fn is_seen(&self, time: u64, id: Option<u64>) -> bool {
+        time == self.start_time && id.is_some_and(|id| self.seen_ids.contains(&id))
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.start_time > self.end_time
+    }
+
+    pub(crate) fn cap_limit(mut self, max_limit: u16) -> Self {
+        self.limit = self.limit.min(max_limit);
+        self
+    }
+
+    /// End of the next page. Series with a fixed period are asked one page worth
+    /// of periods at a time, so that no item is left out whichever end the exchange fills from.
+    /// Endpoints that limit the queried range are asked at most `max_span` ms at a time
+    pub(crate) fn page_end(&self, period: Option<KlineInterval>, max_span: Option<u64>) -> u64 {
+        let end = match period {
+            Some(period) => {
+                let span = period.duration().as_millis() as u64 * self.limit as u64;
+                self.end_time.min(self.start_time + span - 1)
+            }
+            None => self.end_time,
+        };
+        match max_span {
+            Some(span) => end.min(self.start_time + span - 1),
+            None => end,
+        }
+    }
+}
+
+/// Page forward through `range` with `fetch(start_time, end_time, limit)`.
+/// After a full page, series whose items have an `id` are fetched again from the time
+/// of the last item, dropping those already received, others from the next millisecond.
+pub(crate) fn time_range<T, F, FF, K, D>(
+    range: TimeRange,
+    requests_per_minute: u32,
+    period: Option<KlineInterval>,
+    max_span: Option<u64>,
+    fetch: F,
+    time: K,
+    id: D,
+) -> impl Stream<Item = Result<T>>
+where
+    F: Fn(u64, u64, u16) -> FF,
+    FF: std::future::Future<Output = Result<Vec<T>>>,
+    K: Fn(&T) -> u64,
+    D: Fn(&T) -> Option<u64>,
+{
+    let limiter = WeightLimiter::new(requests_per_minute);
+    stream::try_unfold(
+        (range, limiter, fetch, time, id),
+        move |(mut range, limiter, fetch, time, id)| async move {
+            loop {
+                if range.is_done() {
+                    return Ok::<_, Error>(None);
+                }
+                let page_end = range.page_end(period, max_span);
+                limiter.acquire(1).await;
+                let page: Vec<T> = fetch(range.start_time, page_end, range.limit)
+                    .await?
+                    .into_iter()
+                    .filter(|item| {
+                        let time = time(item);
+                        time >= range.start_time && time <= page_end
+                    })
+                    .collect();
+                let full = page.len() == range.limit as usize;
+                let last = page.last().map(&time);
+                let page: Vec<T> = page
+                    .into_iter()
+                    .filter(|item| !range.is_seen(time(item), id(item)))
+                    .collect();
+                let ids: Option<Vec<u64>> = page.iter().map(&id).collect();
+                match (last, ids) {
+                    // a full page of items already received, more than a page share its last
+                    // millisecond and the rest of it cannot be reached by time
+                    (Some(last), _) if full && page.is_empty() => range.advance(last),
+                    (Some(_), Some(ids)) if full => {
+                        for (item, id) in page.iter().zip(ids) {
+                            range.advance_item(time(item), id);
+                        }
+                    }
+                    (Some(last), _) if full => range.advance(last),
+                    _ => range.advance(page_end),
+                }
+                if !page.is_empty() {
+                    return Ok(Some((page, (range, limiter, fetch, time, id))));
+                }
+            }
+        },
+    )
+    .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+/// Page forward through `range` by id. The first item is searched for with
+/// `fetch(None, start_time, end_time, limit)` at most `max_span` ms at a time,
+/// the next pages are fetched with `fetch(from_id, None, None, limit)`.
+pub(crate) fn id_range<T, F, FF, K, D>(
+    range: TimeRange,
+    requests_per_minute: u32,
+    max_span: Option<u64>,
+    fetch: F,
+    time: K,
+    id: D,
+) -> impl Stream<Item = Result<T>>
+where
+    F: Fn(Option<u64>, Option<u64>, Option<u64>, u16) -> FF,
+    FF: std::future::Future<Output = Result<Vec<T>>>,
+    K: Fn(&T) -> u64,
+    D: Fn(&T) -> u64,
+{
+    let limiter = WeightLimiter::new(requests_per_minute);
+    stream::try_unfold(
+        (range, limiter, fetch, time, id),
+        move |(mut range, limiter, fetch, time, id)| async move {
+            loop {
+                if range.is_done() {
+                    return Ok::<_, Error>(None);
+                }
+                limiter.acquire(1).await;
+                let by_id = range.from_id.is_some();
+                let page = match range.from_id {
+                    Some(from_id) => fetch(Some(from_id), None, None, range.limit).await?,
+                    None => {
+                        let page_end = range.page_end(None, max_span);
+                        let page = fetch(None, Some(range.start_time), Some(page_end), range.limit)
+                            .await?;
+                        if page.is_empty() {
+                            range.advance(page_end);
+                            continue;
+                        }
+                        page
+                    }
+                };
+                let caught_up = page.len() < range.limit as usize;
+                let page: Vec<T> = page
+                    .into_iter()
+                    .filter(|item| {
+                        range.from_id.is_none_or(|from_id| id(item) >= from_id)
+                            && time(item) <= range.end_time
+                    })
+                    .collect();
+                let past_end = page.len() < range.limit as usize && !caught_up;
+                for item in &page {
+                    range.advance_item(time(item), id(item));
+                }
+                if by_id && (caught_up || past_end) {
+                    range.advance(range.end_time);
+                }
+                if !page.is_empty() {
+                    return Ok(Some((page, (range, limiter, fetch, time, id))));
+                }
+            }
+        },
+    )
+    .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+fn kline(row: &[Value]) -> Kline {
+    Kline {
+        open_time: to_i64(&row[0]),
+        open: to_decimal(&row[1]),
+        high: to_decimal(&row[2]),
+        low: to_decimal(&row[3]),
+        close: to_decimal(&row[4]),
+        volume: to_decimal(&row[5]),
+        close_time: to_i64(&row[6]),
+        quote_asset_volume: to_decimal(&row[7]),
+        number_of_trades: to_i64(&row[8]),
+        taker_buy_base_asset_volume: to_decimal(&row[9]),
+        taker_buy_quote_asset_volume: to_decimal(&row[10]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn kline_range_query_per_source() {
+        let range = KlineRange::new("BTCUSDT", KlineInterval::Hour1, 0, 3_600_000)
+            .set_source(KlineSource::Continuous(ContractType::CurrentQuarter))
+            .set_limit(2000);
+        assert_eq!(
+            qs::to_string(&range.query()).unwrap(),
+            "pair=BTCUSDT&contractType=CURRENT_QUARTER&interval=1h&startTime=0&endTime=3600000&limit=1500"
+        );
+        let range = range.set_source(KlineSource::MarkPrice).set_limit(500);
+        assert_eq!(
+            qs::to_string(&range.query()).unwrap(),
+            "symbol=BTCUSDT&interval=1h&startTime=0&endTime=3600000&limit=500"
+        );
+        assert_eq!(klines_weight(range.limit), 5);
+    }
+
+    #[tokio::test]
+    async fn klines_reject_spot_only_intervals() {
+        // nothing listens on the discard port, a request would be a ReqError
+        let market = FuturesMarket {
+            client: Client::new(None, None, "http://127.0.0.1:9".into()),
+            recv_window: 5000,
+        };
+        let interval = KlineInterval::Second1;
+        let results = [
+            market
+                .get_klines("BTCUSDT", interval, 10u16, None, None)
+                .await
+                .err(),
+            market
+                .get_blvt_klines_v("BTCUP", interval, 10u16, None, None)
+                .await
+                .err(),
+            market
+                .get_mark_price_klines_v("BTCUSDT", interval, 10u16, None, None)
+                .await
+                .err(),
+            market
+                .get_index_price_klines_v("BTCUSDT", interval, 10u16, None, None)
+                .await
+                .err(),
+            market
+                .get_continuous_contract_klines_v("BTCUSDT", interval, 10u16, None, None)
+                .await
+                .err(),
+        ];
+        for result in results {
+            assert!(
+                matches!(result, Some(Error::InvalidPeriod(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn time_range_pages_by_period() {
+        let mut range = TimeRange::new(0, 10 * 3_600_000).cap_limit(TRADING_DATA_MAX_LIMIT);
+        assert_eq!(range.limit, 500);
+        assert_eq!(range.page_end(None, None), 10 * 3_600_000);
+
+        let range4 = range.clone().set_limit(4).cap_limit(TRADING_DATA_MAX_LIMIT);
+        assert_eq!(
+            range4.page_end(Some(KlineInterval::Hour1), None),
+            4 * 3_600_000 - 1
+        );
+        assert_eq!(
+            range.page_end(Some(KlineInterval::Hour1), None),
+            10 * 3_600_000
+        );
+        assert_eq!(range.page_end(None, Some(4 * 3_600_000)), 4 * 3_600_000 - 1);
+
+        range.advance(10 * 3_600_000);
+        assert!(range.is_done());
+    }
+
+    /// (time, id) items, fetched like the exchange by time window or from an id
+    const ITEMS: [(u64, u64); 5] = [(1, 1), (2, 2), (2, 3), (2, 4), (3, 5)];
+
+    #[tokio::test(start_paused = true)]
+    async fn time_range_refetches_a_page_ending_mid_millisecond() {
+        let items: Vec<(u64, u64)> = time_range(
+            TimeRange::new(0, 10).set_limit(3),
+            1200,
+            None,
+            None,
+            |start_time, end_time, limit| async move {
+                Ok(ITEMS
+                    .into_iter()
+                    .filter(|(time, _)| (start_time..=end_time).contains(time))
+                    .take(limit as usize)
+                    .collect())
+            },
+            |item: &(u64, u64)| item.0,
+            |item: &(u64, u64)| Some(item.1),
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        // the first page ends in the middle of millisecond 2
+        assert_eq!(items, ITEMS);
+
+        let mut range = TimeRange::new(0, 10);
+        range.advance_item(2, 2);
+        range.advance_item(2, 3);
+        assert_eq!((range.start_time, range.from_id), (2, Some(4)));
+        assert!(range.is_seen(2, Some(3)) && !range.is_seen(2, Some(4)));
+        range.advance(2);
+        assert!(range.seen_ids.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn id_range_pages_by_id() {
+        let items: Vec<(u64, u64)> = id_range(
+            TimeRange::new(2, 2).set_limit(2),
+            1200,
+            None,
+            |from_id, start_time, end_time, limit| async move {
+                Ok(ITEMS
+                    .into_iter()
+                    .filter(|(time, id)| match from_id {
+                        Some(from_id) => *id >= from_id,
+                        None => (start_time.unwrap()..=end_time.unwrap()).contains(time),
+                    })
+                    .take(limit as usize)
+                    .collect())
+            },
+            |item: &(u64, u64)| item.0,
+            |item: &(u64, u64)| item.1,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(items, ITEMS[1..4]);
+    }
+}
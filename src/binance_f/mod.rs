@@ -8,7 +8,7 @@
 #![allow(clippy::needless_doctest_main)]
 pub use util::{bool_to_string, bool_to_string_some};
 
-mod client;
+pub(crate) mod client;
 pub mod errors;
 pub mod util;
 
@@ -17,8 +17,12 @@ pub mod ws_model;
 
 pub mod account;
 pub mod api;
+pub mod backfill;
+pub mod book_manager;
 pub mod config;
 pub mod general;
+pub mod heartbeat;
 pub mod market;
+pub mod orderbook;
 pub mod userstream;
 pub mod websockets;
@@ -0,0 +1,505 @@
+use super::config::Config;
+use super::errors::*;
+use super::market::FuturesMarket;
+use super::rest_model::{Asks, Bids, OrderBookPartial};
+use super::websockets::{diff_book_depth_stream, FuturesWebSockets};
+use super::ws_model::DepthOrderBookEvent;
+use crate::binance::orderbook::SnapshotGate;
+pub use crate::binance::orderbook::{
+    DepthSnapshot, DepthUpdate, Fill, OrderBook, Size, UpdateStatus,
+};
+
+use log::{debug, warn};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::sleep;
+
+/// Why a `LocalBook` dropped its book and went back to a snapshot
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DesyncReason {
+    /// `pu` of an event did not match the previous `u`
+    Gap,
+    /// Best bid at or above best ask after an update
+    Crossed,
+    /// The websocket was reconnected
+    Reconnect,
+}
+
+/// Notification published to `LocalBook` subscribers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookEvent {
+    /// The book was rebuilt from a REST snapshot
+    Snapshot { last_update_id: u64 },
+    /// A diff depth event was applied
+    Update {
+        last_update_id: u64,
+        event_time: u64,
+    },
+    /// The book is out of sync and waits for a new snapshot
+    Desync { reason: DesyncReason },
+}
+
+impl DepthSnapshot for OrderBookPartial {
+    fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    fn times(&self) -> (u64, u64) {
+        (self.event_time, self.transaction_time)
+    }
+
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
+impl DepthUpdate for DepthOrderBookEvent {
+    fn first_update_id(&self) -> u64 {
+        self.first_update_id
+    }
+
+    fn final_update_id(&self) -> u64 {
+        self.final_update_id
+    }
+
+    fn previous_final_update_id(&self) -> Option<u64> {
+        Some(self.previous_final_update_id)
+    }
+
+    fn times(&self) -> (u64, u64) {
+        (self.event_time, self.transaction_time)
+    }
+
+    fn bids(&self) -> &[Bids] {
+        &self.bids
+    }
+
+    fn asks(&self) -> &[Asks] {
+        &self.asks
+    }
+}
+
+impl From<&OrderBook> for OrderBookPartial {
+    fn from(book: &OrderBook) -> Self {
+        OrderBookPartial {
+            last_update_id: book.last_update_id,
+            event_time: book.event_time,
+            transaction_time: book.transaction_time,
+            bids: book.bids_iter().collect(),
+            asks: book.asks_iter().collect(),
+        }
+    }
+}
+
+/// Snapshot and replay state of a single book, independent of the websocket
+/// and of how snapshots are fetched
+pub(crate) struct BookSync {
+    book: Arc<RwLock<OrderBook>>,
+    buffer: Vec<DepthOrderBookEvent>,
+    synced: Arc<AtomicBool>,
+    notifier: broadcast::Sender<BookEvent>,
+    snapshot_gate: SnapshotGate,
+}
+
+impl BookSync {
+    pub(crate) fn new<S>(symbol: S) -> BookSync
+    where
+        S: Into<String>,
+    {
+        let (notifier, _) = broadcast::channel(1024);
+        BookSync {
+            book: Arc::new(RwLock::new(OrderBook::new(symbol))),
+            buffer: Vec::new(),
+            synced: Arc::new(AtomicBool::new(false)),
+            notifier,
+            snapshot_gate: SnapshotGate::default(),
+        }
+    }
+
+    pub(crate) fn book(&self) -> Arc<RwLock<OrderBook>> {
+        self.book.clone()
+    }
+
+    pub(crate) fn synced(&self) -> Arc<AtomicBool> {
+        self.synced.clone()
+    }
+
+    pub(crate) fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<BookEvent> {
+        self.notifier.subscribe()
+    }
+
+    pub(crate) fn notifier(&self) -> broadcast::Sender<BookEvent> {
+        self.notifier.clone()
+    }
+
+    pub(crate) fn symbol(&self) -> String {
+        self.book.read().unwrap().symbol.clone()
+    }
+
+    fn notify(&self, event: BookEvent) {
+        // no subscribers is not an error
+        let _ = self.notifier.send(event);
+    }
+
+    pub(crate) fn desync(&mut self, reason: DesyncReason) {
+        if self.synced.swap(false, Ordering::Relaxed) {
+            self.notify(BookEvent::Desync { reason });
+        }
+        self.buffer.clear();
+        self.snapshot_gate.clear();
+    }
+
+    /// Whether the snapshot of this resync attempt may be fetched now,
+    /// at most one per `SNAPSHOT_RETRY` while the book waits
+    pub(crate) fn snapshot_due(&mut self, now: Instant) -> bool {
+        self.snapshot_gate.due(now)
+    }
+
+    /// Apply or buffer the next diff depth event.
+    /// Returns true when the book waits for a snapshot.
+    pub(crate) fn on_event(&mut self, event: DepthOrderBookEvent) -> bool {
+        if self.is_synced() {
+            let (status, crossed) = {
+                let mut book = self.book.write().unwrap();
+                let status = book.apply_update(&event);
+                (status, book.is_crossed())
+            };
+            match status {
+                UpdateStatus::Stale => return false,
+                UpdateStatus::Applied if !crossed => {
+                    self.notify(BookEvent::Update {
+                        last_update_id: event.final_update_id,
+                        event_time: event.event_time,
+                    });
+                    return false;
+                }
+                UpdateStatus::Applied => {
+                    warn!(
+                        "{} book crossed after {}, resyncing",
+                        event.symbol, event.final_update_id
+                    );
+                    self.desync(DesyncReason::Crossed);
+                    return true;
+                }
+                UpdateStatus::Gap => {
+                    warn!(
+                        "{} depth gap: pu {} does not follow {}, resyncing",
+                        event.symbol,
+                        event.previous_final_update_id,
+                        self.book.read().unwrap().last_update_id
+                    );
+                    self.desync(DesyncReason::Gap);
+                }
+            }
+        }
+
+        self.buffer.push(event);
+        true
+    }
+
+    /// Apply a snapshot and replay the buffered events on top of it.
+    /// A snapshot older than the first buffered event is discarded.
+    /// Returns whether the book is in sync afterwards.
+    pub(crate) fn sync_with(&mut self, snapshot: OrderBookPartial) -> bool {
+        if let Some(first) = self.buffer.first() {
+            if snapshot.last_update_id < first.first_update_id {
+                debug!(
+                    "{} snapshot {} older than buffered event {}, waiting",
+                    first.symbol, snapshot.last_update_id, first.first_update_id
+                );
+                return false;
+            }
+        }
+
+        self.snapshot_gate.clear();
+        let mut book = self.book.write().unwrap();
+        book.apply_snapshot(&snapshot);
+        let mut status = UpdateStatus::Applied;
+        for event in self.buffer.drain(..) {
+            status = book.apply_update(&event);
+            if status == UpdateStatus::Gap {
+                break;
+            }
+        }
+        let synced = status != UpdateStatus::Gap && !book.is_crossed();
+        let last_update_id = book.last_update_id;
+        drop(book);
+
+        self.buffer.clear();
+        if synced {
+            self.synced.store(true, Ordering::Relaxed);
+            self.notify(BookEvent::Snapshot { last_update_id });
+        } else {
+            warn!("{} snapshot replay failed, resyncing", self.symbol());
+        }
+        synced
+    }
+}
+
+/// A futures order book kept in sync with its `<symbol>@depth` stream.
+///
+/// `LocalBook` owns the websocket subscription and the `FuturesMarket` used for
+/// `get_custom_depth` snapshots. Every event is checked against the `pu` chain
+/// and the book is checked for crossed prices; on failure the book is rebuilt
+/// from a new snapshot. Readers share the book through `book()` and can follow
+/// changes with `subscribe()`.
+///
+/// # Examples
+/// ```rust,no_run
+/// use exrs::binance_f::{api::*, config::*, market::*, orderbook::*};
+/// use std::sync::atomic::AtomicBool;
+///
+/// # async fn run() -> exrs::binance_f::errors::Result<()> {
+/// let market: FuturesMarket = BinanceF::new(None, None);
+/// let mut local_book = LocalBook::new(market, Config::default(), "BTCUSDT", 1000, 100);
+/// let book = local_book.book();
+/// let mut events = local_book.subscribe();
+/// actix_rt::spawn(async move {
+///     while let Ok(BookEvent::Update { .. }) = events.recv().await {
+///         println!("{:?}", book.read().unwrap().best_bid());
+///     }
+/// });
+/// local_book.run(&AtomicBool::new(true)).await
+/// # }
+/// ```
+pub struct LocalBook {
+    market: FuturesMarket,
+    depth_limit: u16,
+    web_socket: FuturesWebSockets<DepthOrderBookEvent>,
+    receiver: mpsc::Receiver<DepthOrderBookEvent>,
+    stream: String,
+    sync: BookSync,
+}
+
+impl LocalBook {
+    /// * `depth_limit`: snapshot size passed to `get_custom_depth`
+    /// * `update_speed`: diff depth stream speed in ms, 100, 250 or 500
+    pub fn new<S>(
+        market: FuturesMarket,
+        conf: Config,
+        symbol: S,
+        depth_limit: u16,
+        update_speed: u16,
+    ) -> LocalBook
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+        let (sender, receiver) = mpsc::channel(1024);
+        LocalBook {
+            market,
+            depth_limit,
+            web_socket: FuturesWebSockets::new_with_options(sender, conf),
+            receiver,
+            stream: diff_book_depth_stream(&symbol.to_lowercase(), update_speed),
+            sync: BookSync::new(symbol),
+        }
+    }
+
+    /// Shared read handle on the book
+    pub fn book(&self) -> Arc<RwLock<OrderBook>> {
+        self.sync.book()
+    }
+
+    /// Receive a `BookEvent` for every change of the book
+    pub fn subscribe(&self) -> broadcast::Receiver<BookEvent> {
+        self.sync.subscribe()
+    }
+
+    /// Whether the book reflects the stream, false while waiting for a snapshot
+    pub fn is_synced(&self) -> bool {
+        self.sync.is_synced()
+    }
+
+    /// Connect and keep the book in sync until `running` is cleared.
+    /// Websocket errors, including stale streams, reconnect and resync the book;
+    /// failed snapshot requests are retried after a delay.
+    pub async fn run(&mut self, running: &AtomicBool) -> Result<()> {
+        while running.load(Ordering::Relaxed) {
+            if let Err(e) = self.web_socket.connect(&self.stream).await {
+                warn!("{} connect error: {}, try again...", self.stream, e);
+                sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+
+            let web_socket = &mut self.web_socket;
+            let receiver = &mut self.receiver;
+            let (market, depth_limit) = (&self.market, self.depth_limit);
+            let fetch = move |symbol: String| market.get_custom_depth(symbol, depth_limit);
+            tokio::select! {
+                result = web_socket.event_loop(running) => {
+                    if let Err(e) = result {
+                        warn!("{} event_loop error: {}, starting reconnect...", self.stream, e);
+                    }
+                }
+                _ = Self::process(&mut self.sync, receiver, fetch) => {}
+            }
+
+            // events from the old connection cannot be chained to the new one
+            while self.receiver.try_recv().is_ok() {}
+            self.sync.desync(DesyncReason::Reconnect);
+        }
+        Ok(())
+    }
+
+    /// Apply events, fetching one snapshot per resync attempt while the book waits
+    async fn process<F, Fut>(
+        sync: &mut BookSync,
+        receiver: &mut mpsc::Receiver<DepthOrderBookEvent>,
+        mut fetch: F,
+    ) where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = Result<OrderBookPartial>>,
+    {
+        while let Some(event) = receiver.recv().await {
+            if !sync.on_event(event) || !sync.snapshot_due(Instant::now()) {
+                continue;
+            }
+            match fetch(sync.symbol()).await {
+                Ok(snapshot) => {
+                    sync.sync_with(snapshot);
+                }
+                Err(e) => warn!("{} snapshot error: {}, retrying", sync.symbol(), e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn event(
+        first: u64,
+        last: u64,
+        previous: u64,
+        bids: &[(Decimal, Decimal)],
+    ) -> DepthOrderBookEvent {
+        DepthOrderBookEvent {
+            event_time: last,
+            transaction_time: last,
+            symbol: "BTCUSDT".into(),
+            first_update_id: first,
+            final_update_id: last,
+            previous_final_update_id: previous,
+            bids: bids.iter().map(|(p, q)| Bids::new(*p, *q)).collect(),
+            asks: vec![],
+        }
+    }
+
+    fn snapshot(last_update_id: u64) -> OrderBookPartial {
+        OrderBookPartial {
+            last_update_id,
+            event_time: 0,
+            transaction_time: 0,
+            bids: vec![Bids::new(dec!(100), dec!(1))],
+            asks: vec![Asks::new(dec!(101), dec!(1))],
+        }
+    }
+
+    #[test]
+    fn apply_update_follows_pu_chain() {
+        let mut book = OrderBook::new("BTCUSDT");
+        book.apply_snapshot(&snapshot(10));
+
+        assert_eq!(book.apply_update(&event(5, 9, 4, &[])), UpdateStatus::Stale);
+        assert_eq!(book.apply_update(&event(11, 12, 9, &[])), UpdateStatus::Gap);
+        assert_eq!(
+            book.apply_update(&event(8, 12, 7, &[(dec!(99), dec!(2))])),
+            UpdateStatus::Applied
+        );
+        assert_eq!(
+            book.apply_update(&event(13, 15, 12, &[])),
+            UpdateStatus::Applied
+        );
+        assert_eq!(
+            book.apply_update(&event(17, 18, 16, &[])),
+            UpdateStatus::Gap
+        );
+        assert_eq!(book.last_update_id, 15);
+        assert_eq!(book.top_bids(2).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sync_publishes_snapshot_and_detects_crossed_replay() {
+        let mut sync = BookSync::new("BTCUSDT");
+        let mut events = sync.subscribe();
+
+        assert!(sync.on_event(event(9, 11, 8, &[(dec!(100.5), dec!(1))])));
+        assert!(sync.sync_with(snapshot(10)));
+        assert_eq!(
+            events.recv().await.unwrap(),
+            BookEvent::Snapshot { last_update_id: 11 }
+        );
+
+        sync.desync(DesyncReason::Gap);
+        assert_eq!(
+            events.recv().await.unwrap(),
+            BookEvent::Desync {
+                reason: DesyncReason::Gap
+            }
+        );
+
+        assert!(sync.on_event(event(9, 11, 8, &[(dec!(102), dec!(1))])));
+        assert!(!sync.sync_with(snapshot(10)));
+        assert!(!sync.is_synced());
+    }
+    #[tokio::test]
+    async fn one_snapshot_fetch_per_resync_attempt() {
+        async fn feed(
+            sync: &mut BookSync,
+            events: &[DepthOrderBookEvent],
+            snapshot: Result<OrderBookPartial>,
+        ) -> usize {
+            let (sender, mut receiver) = mpsc::channel(16);
+            for event in events {
+                sender.send(event.clone()).await.unwrap();
+            }
+            drop(sender);
+            let mut fetches = 0;
+            LocalBook::process(sync, &mut receiver, |_| {
+                fetches += 1;
+                let snapshot = match &snapshot {
+                    Ok(snapshot) => Ok(snapshot.clone()),
+                    Err(e) => Err(Error::Msg(e.to_string())),
+                };
+                async move { snapshot }
+            })
+            .await;
+            fetches
+        }
+
+        let mut sync = BookSync::new("BTCUSDT");
+        let events: Vec<_> = (10..15).map(|i| event(i, i, i - 1, &[])).collect();
+        // a snapshot older than the buffered events waits for the retry delay
+        assert_eq!(feed(&mut sync, &events, Ok(snapshot(5))).await, 1);
+        assert!(!sync.is_synced());
+
+        // a failed request does not stop the book, the next attempt retries
+        sync.snapshot_gate.clear();
+        let error = Err(Error::Msg("snapshot error".into()));
+        assert_eq!(feed(&mut sync, &[event(15, 15, 14, &[])], error).await, 1);
+        assert!(!sync.is_synced());
+
+        sync.snapshot_gate.clear();
+        assert_eq!(
+            feed(&mut sync, &[event(16, 16, 15, &[])], Ok(snapshot(12))).await,
+            1
+        );
+        assert!(sync.is_synced());
+        assert_eq!(sync.book().read().unwrap().last_update_id, 16);
+    }
+}
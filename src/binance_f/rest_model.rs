@@ -1,29 +1,8 @@
+use super::account::{ContractType, PositionSide};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
-pub struct Asks {
-    pub price: Decimal,
-    pub qty: Decimal,
-}
-
-impl Asks {
-    pub fn new(price: Decimal, qty: Decimal) -> Asks {
-        Asks { price, qty }
-    }
-}
-
-#[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
-pub struct Bids {
-    pub price: Decimal,
-    pub qty: Decimal,
-}
-
-impl Bids {
-    pub fn new(price: Decimal, qty: Decimal) -> Bids {
-        Bids { price, qty }
-    }
-}
+pub use crate::binance::rest_model::{Asks, Bids, KlineInterval, OrderSide};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(tag = "filterType")]
@@ -86,23 +65,23 @@ pub enum Klines {
 pub struct Kline {
     pub open_time: i64,
     #[serde(with = "string_or_float")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
-    pub close: f64,
+    pub close: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
     pub close_time: i64,
     #[serde(with = "string_or_float")]
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
     pub number_of_trades: i64,
     #[serde(with = "string_or_float")]
-    pub taker_buy_base_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
     #[serde(with = "string_or_float")]
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_quote_asset_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -141,7 +120,7 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -155,13 +134,13 @@ pub enum BookTickers {
 pub struct BookTicker {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Decimal,
     pub time: u64,
 }
 
@@ -187,9 +166,9 @@ pub struct Symbol {
     pub onboard_date: u64,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub maint_margin_percent: f64,
+    pub maint_margin_percent: Decimal,
     #[serde(with = "string_or_float")]
-    pub required_margin_percent: f64,
+    pub required_margin_percent: Decimal,
     pub base_asset: String,
     pub quote_asset: String,
     pub margin_asset: String,
@@ -201,7 +180,7 @@ pub struct Symbol {
     pub underlying_sub_type: Vec<String>,
     pub settle_plan: u64,
     #[serde(with = "string_or_float")]
-    pub trigger_protect: f64,
+    pub trigger_protect: Decimal,
     pub filters: Vec<Filters>,
     pub order_types: Vec<String>,
     pub time_in_force: Vec<String>,
@@ -229,19 +208,19 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Decimal,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -261,11 +240,11 @@ pub struct Trade {
     pub id: u64,
     pub is_buyer_maker: bool,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Decimal,
     pub time: u64,
 }
 
@@ -281,9 +260,9 @@ pub struct AggTrade {
     #[serde(rename = "a")]
     pub agg_id: u64,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Decimal,
     #[serde(rename = "f")]
     pub first_id: u64,
     #[serde(rename = "l")]
@@ -305,9 +284,9 @@ pub enum MarkPrices {
 pub struct MarkPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub last_funding_rate: Decimal,
     pub next_funding_time: u64,
     pub time: u64,
 }
@@ -322,13 +301,13 @@ pub enum LiquidationOrders {
 #[serde(rename_all = "camelCase")]
 pub struct LiquidationOrder {
     #[serde(with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: String,
     pub status: String,
     pub symbol: String,
@@ -342,24 +321,24 @@ pub struct LiquidationOrder {
 pub struct Order {
     pub client_order_id: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub cum_qty: f64,
+    pub cum_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: String,
     pub reduce_only: bool,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -367,9 +346,9 @@ pub struct Order {
     pub order_type: String,
     pub orig_type: String,
     #[serde(with = "string_or_float", default = "default_activation_price")]
-    pub activation_price: f64,
+    pub activation_price: Decimal,
     #[serde(with = "string_or_float", default = "default_price_rate")]
-    pub price_rate: f64,
+    pub price_rate: Decimal,
     pub update_time: u64,
     pub working_type: String,
     pub price_protect: bool,
@@ -379,23 +358,26 @@ pub struct Order {
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
     pub client_order_id: String,
+    /// Missing from order queries
+    #[serde(default, with = "string_or_float")]
+    pub cum_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_quote: Decimal,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
-    #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -404,12 +386,16 @@ pub struct Transaction {
     pub orig_type: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Decimal>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Decimal>,
+    /// Creation time, missing from order placement responses
+    #[serde(default)]
+    pub time: u64,
     pub update_time: u64,
     pub working_type: String,
+    #[serde(default)]
     price_protect: bool,
 }
 
@@ -418,23 +404,23 @@ pub struct Transaction {
 pub struct CanceledOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Decimal,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Decimal,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Decimal,
     pub orig_type: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -442,10 +428,10 @@ pub struct CanceledOrder {
     pub type_name: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Decimal>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Decimal>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -455,24 +441,24 @@ pub struct CanceledOrder {
 #[serde(rename_all = "camelCase")]
 pub struct Position {
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     pub margin_type: String,
     #[serde(with = "string_or_bool")]
     pub is_auto_add_margin: bool,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Decimal,
     pub leverage: String,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Decimal,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     pub symbol: String,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Decimal,
     pub position_side: String,
 }
 
@@ -482,15 +468,15 @@ pub struct AccountBalance {
     pub account_alias: String,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub balance: f64,
+    pub balance: Decimal,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Decimal,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Decimal,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Decimal,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Decimal,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -501,7 +487,7 @@ pub struct AccountBalance {
 pub struct ChangeLeverageResponse {
     pub leverage: u8,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Decimal,
     pub symbol: String,
 }
 
@@ -552,6 +538,36 @@ pub enum TimeInForce {
     GTD,
 }
 
+/// Price an order from the book instead of giving a price
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PriceMatch {
+    None,
+    Opponent,
+    #[serde(rename = "OPPONENT_5")]
+    Opponent5,
+    #[serde(rename = "OPPONENT_10")]
+    Opponent10,
+    #[serde(rename = "OPPONENT_20")]
+    Opponent20,
+    Queue,
+    #[serde(rename = "QUEUE_5")]
+    Queue5,
+    #[serde(rename = "QUEUE_10")]
+    Queue10,
+    #[serde(rename = "QUEUE_20")]
+    Queue20,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradePreventionMode {
+    None,
+    ExpireTaker,
+    ExpireMaker,
+    ExpireBoth,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CancelAllOpenOrdersResponse {
     code: i16,
@@ -578,20 +594,6 @@ pub enum SideEffectType {
     Other,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum OrderSide {
-    Buy,
-    Sell,
-}
-
-/// By default, buy
-impl Default for OrderSide {
-    fn default() -> Self {
-        Self::Buy
-    }
-}
-
 /// Order types, the following restrictions apply
 /// LIMIT_MAKER are LIMIT orders that will be rejected if they would immediately match and trade as a taker.
 /// STOP_LOSS and TAKE_PROFIT will execute a MARKET order when the stopPrice is reached.
@@ -641,23 +643,23 @@ pub enum HistoricalTrades {
 pub struct ContinuousKline {
     pub open_time: u64,
     #[serde(with = "string_or_float")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
-    pub close: f64,
+    pub close: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
     pub close_time: u64,
     #[serde(with = "string_or_float")]
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
     pub number_of_trades: u64,
     #[serde(with = "string_or_float")]
-    pub taker_buy_base_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
     #[serde(with = "string_or_float")]
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_quote_asset_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -671,29 +673,29 @@ pub enum ContinuousKlines {
 pub struct IndexPriceKline {
     pub open_time: u64,
     #[serde(with = "string_or_float")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
-    pub close: f64,
+    pub close: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub volume: f64,
+    pub volume: Decimal,
 
     pub close_time: u64,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
 
     pub number_of_basic_data: u64,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub taker_buy_base_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_quote_asset_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -707,29 +709,29 @@ pub enum IndexPriceKlines {
 pub struct MarkPriceKline {
     pub open_time: u64,
     #[serde(with = "string_or_float")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
-    pub close: f64,
+    pub close: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub volume: f64,
+    pub volume: Decimal,
 
     pub close_time: u64,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
 
     pub number_of_basic_data: u64,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub taker_buy_base_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_quote_asset_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -743,16 +745,16 @@ pub enum MarkPriceKlines {
 pub struct PremiumIndex {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub index_price: f64,
+    pub index_price: Decimal,
     #[serde(with = "string_or_float_opt")]
-    pub estimated_settle_price: Option<f64>,
+    pub estimated_settle_price: Option<Decimal>,
     #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub last_funding_rate: Decimal,
     pub next_funding_time: u64,
     #[serde(with = "string_or_float")]
-    pub interest_rate: f64,
+    pub interest_rate: Decimal,
     pub time: u64,
 }
 
@@ -767,27 +769,27 @@ pub enum PremiumIndexs {
 pub struct Ticker24hr {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price_change: f64,
+    pub price_change: Decimal,
     #[serde(with = "string_or_float")]
-    pub price_change_percent: f64,
+    pub price_change_percent: Decimal,
     #[serde(with = "string_or_float")]
-    pub weighted_avg_price: f64,
+    pub weighted_avg_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Decimal,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Decimal,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -806,7 +808,7 @@ pub enum Ticker24hrs {
 pub struct TickerPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
     pub time: u64,
 }
 
@@ -826,9 +828,9 @@ pub enum OpenInterestHistorys {
 #[serde(rename_all = "camelCase")]
 pub struct TopLongShortAccountRatio {
     pub symbol: String,
-    pub long_short_ratio: f64,
-    pub long_account: f64,
-    pub short_account: f64,
+    pub long_short_ratio: Decimal,
+    pub long_account: Decimal,
+    pub short_account: Decimal,
     pub timestamp: u64,
 }
 
@@ -842,11 +844,11 @@ pub enum TopLongShortAccountRatios {
 #[serde(rename_all = "camelCase")]
 pub struct TopLongShortPositionRatio {
     pub symbol: String,
-    pub long_short_ratio: f64,
+    pub long_short_ratio: Decimal,
     #[serde(rename = "longAccount")]
-    pub long_position: f64,
+    pub long_position: Decimal,
     #[serde(rename = "shortAccount")]
-    pub short_position: f64,
+    pub short_position: Decimal,
     pub timestamp: u64,
 }
 
@@ -860,9 +862,9 @@ pub enum TopLongShortPositionRatios {
 #[serde(rename_all = "camelCase")]
 pub struct GlobalLongShortAccountRatio {
     pub symbol: String,
-    pub long_short_ratio: f64,
-    pub long_account: f64,
-    pub short_account: f64,
+    pub long_short_ratio: Decimal,
+    pub long_account: Decimal,
+    pub short_account: Decimal,
     pub timestamp: u64,
 }
 
@@ -876,11 +878,11 @@ pub enum GlobalLongShortPositionRatios {
 #[serde(rename_all = "camelCase")]
 pub struct TakerlongshortRatio {
     #[serde(with = "string_or_float")]
-    pub buy_sell_ratio: f64,
+    pub buy_sell_ratio: Decimal,
     #[serde(with = "string_or_float")]
-    pub buy_vol: f64,
+    pub buy_vol: Decimal,
     #[serde(with = "string_or_float")]
-    pub sell_vol: f64,
+    pub sell_vol: Decimal,
     pub timestamp: u64,
 }
 
@@ -895,26 +897,26 @@ pub enum TakerlongshortRatios {
 pub struct LvtKline {
     pub open_time: u64,
     #[serde(with = "string_or_float")]
-    pub open: f64,
+    pub open: Decimal,
     #[serde(with = "string_or_float")]
-    pub high: f64,
+    pub high: Decimal,
     #[serde(with = "string_or_float")]
-    pub low: f64,
+    pub low: Decimal,
     #[serde(with = "string_or_float")]
-    pub close: f64,
+    pub close: Decimal,
     #[serde(with = "string_or_float")]
-    pub real_leverage: f64,
+    pub real_leverage: Decimal,
     pub close_time: u64,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub quote_asset_volume: f64,
+    pub quote_asset_volume: Decimal,
     pub number_of_nav_update: u64,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub taker_buy_base_asset_volume: f64,
+    pub taker_buy_base_asset_volume: Decimal,
     #[serde(with = "string_or_float")]
     #[serde(skip_serializing)]
-    pub taker_buy_quote_asset_volume: f64,
+    pub taker_buy_quote_asset_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -932,22 +934,35 @@ pub struct IndexInfo {
     pub base_asset_list: Vec<BaseAsset>,
 }
 
+impl IndexInfo {
+    /// Index value from the prices of its components, None when a price is missing
+    pub fn compute<F>(&self, price: F) -> Option<Decimal>
+    where
+        F: Fn(&str) -> Option<Decimal>,
+    {
+        self.base_asset_list
+            .iter()
+            .map(|asset| price(&asset.base_asset).map(|price| price * asset.weight_in_quantity))
+            .sum()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct BaseAsset {
     pub base_asset: String,
     pub quote_asset: String,
     #[serde(with = "string_or_float")]
-    pub weight_in_quantity: f64,
+    pub weight_in_quantity: Decimal,
     #[serde(with = "string_or_float")]
-    pub weight_in_percentage: f64,
+    pub weight_in_percentage: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Decimal,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -958,16 +973,20 @@ pub(crate) struct HistoryQuery {
     pub from_id: Option<u64>,
     pub limit: u16,
     pub symbol: String,
-    pub interval: Option<String>,
-    pub period: Option<String>,
+    pub interval: Option<KlineInterval>,
+    pub period: Option<KlineInterval>,
 }
 
 impl HistoryQuery {
     pub fn validate(&self) -> super::errors::Result<()> {
-        if let Some(period) = &self.period {
-            if !PERIODS.contains(&period.as_str()) {
-                return Err(super::errors::Error::InvalidPeriod(period.clone()));
-            }
+        if let Some(period) = self.period.filter(|period| !PERIODS.contains(period)) {
+            return Err(super::errors::Error::InvalidPeriod(period.to_string()));
+        }
+        if let Some(interval) = self
+            .interval
+            .filter(|interval| !INTERVALS.contains(interval))
+        {
+            return Err(super::errors::Error::InvalidPeriod(interval.to_string()));
         }
         Ok(())
     }
@@ -979,16 +998,368 @@ pub struct FundingRate {
     pub symbol: String,
     pub funding_time: u64,
     #[serde(with = "string_or_float")]
-    pub funding_rate: f64,
+    pub funding_rate: Decimal,
+    /// Missing on the oldest funding events
+    #[serde(default, with = "string_or_float_opt")]
+    pub mark_price: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FundingRateQuery {
+    pub symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+
+/// Funding parameters of the symbols with an adjusted cap, floor or interval
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_cap: Decimal,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_floor: Decimal,
+    pub funding_interval_hours: u16,
+    #[serde(default)]
+    pub disclaimer: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BasisQuery {
+    pub pair: String,
+    pub contract_type: ContractType,
+    pub period: KlineInterval,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: u16,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: ContractType,
+    #[serde(with = "string_or_float")]
+    pub index_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub futures_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub basis: Decimal,
+    #[serde(with = "string_or_float")]
+    pub basis_rate: Decimal,
+    /// Empty for perpetual contracts
+    #[serde(default, with = "string_or_float_opt")]
+    pub annualized_basis_rate: Option<Decimal>,
+    pub timestamp: u64,
+}
+
+/// Multi-assets mode index price of an asset
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetIndex {
+    pub symbol: String,
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index: Decimal,
+    #[serde(with = "string_or_float")]
+    pub bid_buffer: Decimal,
+    #[serde(with = "string_or_float")]
+    pub ask_buffer: Decimal,
+    #[serde(with = "string_or_float")]
+    pub bid_rate: Decimal,
+    #[serde(with = "string_or_float")]
+    pub ask_rate: Decimal,
+    #[serde(with = "string_or_float")]
+    pub auto_exchange_bid_buffer: Decimal,
+    #[serde(with = "string_or_float")]
+    pub auto_exchange_ask_buffer: Decimal,
+    #[serde(with = "string_or_float")]
+    pub auto_exchange_bid_rate: Decimal,
+    #[serde(with = "string_or_float")]
+    pub auto_exchange_ask_rate: Decimal,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub amendment_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub time: u64,
+    pub amendment: Amendment,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Amendment {
+    pub price: AmendedValue,
+    pub orig_qty: AmendedValue,
+    /// Number of modifications of the order so far
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmendedValue {
+    #[serde(with = "string_or_float")]
+    pub before: Decimal,
+    #[serde(with = "string_or_float")]
+    pub after: Decimal,
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AccountHistoryQuery {
+    pub symbol: Option<String>,
+    pub order_id: Option<u64>,
+    pub from_id: Option<u64>,
+    pub income_type: Option<IncomeType>,
+    pub auto_close_type: Option<AutoCloseType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTrade {
+    pub id: u64,
+    pub symbol: String,
+    pub order_id: u64,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: Decimal,
+    #[serde(with = "string_or_float")]
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub buyer: bool,
+    pub maker: bool,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for transfers
+    pub symbol: String,
+    pub income_type: IncomeType,
+    #[serde(with = "string_or_float")]
+    pub income: Decimal,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    pub tran_id: u64,
+    pub trade_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoCloseType {
+    Liquidation,
+    Adl,
+}
+
+/// Auto-deleveraging queue position, from 0 to 4 where 4 is the first to be deleveraged
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantileValues,
+}
+
+/// One-way mode positions only have `both`, hedge mode ones `long`, `short` and `hedge`
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct AdlQuantileValues {
+    pub long: Option<u8>,
+    pub short: Option<u8>,
+    pub hedge: Option<u8>,
+    pub both: Option<u8>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission_rate: Decimal,
+    #[serde(with = "string_or_float")]
+    pub taker_commission_rate: Decimal,
+}
+
+/// Countdown after which every open order of the symbol is cancelled, 0 when disarmed
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub symbol: String,
+    /// ms
+    #[serde(with = "string_or_u64")]
+    pub countdown_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginType {
+    Isolated,
+    Crossed,
+}
+
+/// Direction of an isolated margin change, serialized as 1 or 2
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PositionMarginType {
+    Add,
+    Reduce,
+}
+
+impl Serialize for PositionMarginType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u8(match self {
+            PositionMarginType::Add => 1,
+            PositionMarginType::Reduce => 2,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PositionMarginType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(PositionMarginType::Add),
+            2 => Ok(PositionMarginType::Reduce),
+            t => Err(serde::de::Error::custom(format!(
+                "unknown position margin type {}",
+                t
+            ))),
+        }
+    }
 }
 
-pub static PERIODS: &[&str] = &["5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h", "1d"];
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PositionMarginRequest {
+    pub symbol: String,
+    pub position_side: Option<PositionSide>,
+    pub amount: Decimal,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginResponse {
+    pub amount: Decimal,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PositionMarginHistoryQuery {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub margin_type: Option<PositionMarginType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChange {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+    /// USER_ADJUST for changes made through the API
+    #[serde(default)]
+    pub delta_type: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: Decimal,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+}
+
+/// Periods of the futures trading data endpoints
+pub static PERIODS: &[KlineInterval] = &[
+    KlineInterval::Minute5,
+    KlineInterval::Minute15,
+    KlineInterval::Minute30,
+    KlineInterval::Hour1,
+    KlineInterval::Hour2,
+    KlineInterval::Hour4,
+    KlineInterval::Hour6,
+    KlineInterval::Hour12,
+    KlineInterval::Day1,
+];
+
+/// Kline intervals of futures, all but 1s
+pub static INTERVALS: &[KlineInterval] = &[
+    KlineInterval::Minute1,
+    KlineInterval::Minute3,
+    KlineInterval::Minute5,
+    KlineInterval::Minute15,
+    KlineInterval::Minute30,
+    KlineInterval::Hour1,
+    KlineInterval::Hour2,
+    KlineInterval::Hour4,
+    KlineInterval::Hour6,
+    KlineInterval::Hour8,
+    KlineInterval::Hour12,
+    KlineInterval::Day1,
+    KlineInterval::Day3,
+    KlineInterval::Week1,
+    KlineInterval::Month1,
+];
 
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(with = "string_or_float")]
-    pub open_interest: f64,
+    pub open_interest: Decimal,
     pub symbol: String,
     pub time: u64,
 }
@@ -997,9 +1368,9 @@ pub struct OpenInterest {
 pub struct OpenInterestHistory {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub sum_open_interest: f64,
+    pub sum_open_interest: Decimal,
     #[serde(with = "string_or_float")]
-    pub sum_open_interest_value: f64,
+    pub sum_open_interest_value: Decimal,
     pub timestamp: u64,
 }
 
@@ -1008,11 +1379,11 @@ pub struct OpenInterestHistory {
 pub struct LongShortRatio {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub long_account: f64,
+    pub long_account: Decimal,
     #[serde(with = "string_or_float")]
-    pub long_short_ratio: f64,
+    pub long_short_ratio: Decimal,
     #[serde(with = "string_or_float")]
-    pub short_account: f64,
+    pub short_account: Decimal,
     pub timestamp: u64,
 }
 
@@ -1023,7 +1394,7 @@ pub struct LeverageBracket {
     pub initial_leverage: u8,
     pub notional_cap: u64,
     pub notional_floor: u64,
-    pub maint_margin_ratio: f64,
+    pub maint_margin_ratio: Decimal,
     pub cum: u64,
 }
 
@@ -1041,8 +1412,40 @@ pub struct PropertyCmd {
     pub params: (String, bool),
 }
 
+pub(crate) mod string_or_u64 {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            UInt(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrU64::UInt(i) => Ok(i),
+        }
+    }
+}
+
 pub(crate) mod string_or_float {
     use std::fmt;
+    use std::str::FromStr;
 
     use serde::{de, Deserialize, Deserializer, Serializer};
 
@@ -1054,8 +1457,11 @@ pub(crate) mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    /// Parses strings and JSON numbers into any `FromStr` type, such as `Decimal` or `f64`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
     where
+        T: FromStr,
+        T::Err: fmt::Display,
         D: Deserializer<'de>,
     {
         #[derive(Deserialize)]
@@ -1067,15 +1473,16 @@ pub(crate) mod string_or_float {
 
         match StringOrFloat::deserialize(deserializer)? {
             StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrFloat::Float(i) => Ok(i),
+            StringOrFloat::Float(f) => f.to_string().parse().map_err(de::Error::custom),
         }
     }
 }
 
 pub(crate) mod string_or_float_opt {
     use std::fmt;
+    use std::str::FromStr;
 
-    use serde::{Deserializer, Serializer};
+    use serde::{de, Deserialize, Deserializer, Serializer};
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1088,18 +1495,28 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    /// Empty strings and nulls, sent for values that do not exist yet, are `None`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
     where
+        T: FromStr,
+        T::Err: fmt::Display,
         D: Deserializer<'de>,
     {
-        // #[derive(Deserialize)]
-        // #[serde(untagged)]
-        // enum StringOrFloat {
-        //     String(String),
-        //     Float(f64),
-        // }
-
-        Ok(Some(super::string_or_float::deserialize(deserializer)?))
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrFloat {
+            String(String),
+            Float(f64),
+        }
+
+        match Option::<StringOrFloat>::deserialize(deserializer)? {
+            Some(StringOrFloat::String(s)) if s.is_empty() => Ok(None),
+            Some(StringOrFloat::String(s)) => s.parse().map(Some).map_err(de::Error::custom),
+            Some(StringOrFloat::Float(f)) => {
+                f.to_string().parse().map(Some).map_err(de::Error::custom)
+            }
+            None => Ok(None),
+        }
     }
 }
 
@@ -1134,12 +1551,12 @@ pub(crate) mod string_or_bool {
     }
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Decimal {
+    Decimal::ZERO
 }
-fn default_activation_price() -> f64 {
-    0.0
+fn default_activation_price() -> Decimal {
+    Decimal::ZERO
 }
-fn default_price_rate() -> f64 {
-    0.0
+fn default_price_rate() -> Decimal {
+    Decimal::ZERO
 }
@@ -16,8 +16,8 @@ impl FuturesUserStream {
     /// Get a listen key for the stream
     /// # Examples
     /// ```rust,no_run
-    /// use binance_f::{api::*, userstream::*, config::*};
-    /// let userstream: UserStream = binance_f::new_with_env(&Config::testnet());
+    /// use exrs::binance_f::{api::*, userstream::*, config::*};
+    /// let userstream: FuturesUserStream = BinanceF::new_with_env(&Config::testnet());
     /// let start = tokio_test::block_on(userstream.start());
     /// assert!(start.is_ok(), "{:?}", start);
     /// assert!(start.unwrap().listen_key.len() > 0)
@@ -32,8 +32,8 @@ impl FuturesUserStream {
     /// Keep the connection alive, as the listen key becomes invalid after 60mn
     /// # Examples
     /// ```rust,no_run
-    /// use binance_f::{api::*, userstream::*, config::*};
-    /// let userstream: UserStream = binance_f::new_with_env(&Config::testnet());
+    /// use exrs::binance_f::{api::*, userstream::*, config::*};
+    /// let userstream: FuturesUserStream = BinanceF::new_with_env(&Config::testnet());
     /// let start = tokio_test::block_on(userstream.start());
     /// assert!(start.is_ok(), "{:?}", start);
     /// let keep_alive = tokio_test::block_on(userstream.keep_alive(&start.unwrap().listen_key));
@@ -53,8 +53,8 @@ impl FuturesUserStream {
     /// Invalidate the listen key
     /// # Examples
     /// ```rust,no_run
-    /// use binance_f::{api::*, userstream::*, config::*};
-    /// let userstream: UserStream = binance_f::new_with_env(&Config::testnet());
+    /// use exrs::binance_f::{api::*, userstream::*, config::*};
+    /// let userstream: FuturesUserStream = BinanceF::new_with_env(&Config::testnet());
     /// let start = tokio_test::block_on(userstream.start());
     /// assert!(start.is_ok(), "{:?}", start);
     /// let close = tokio_test::block_on(userstream.close(&start.unwrap().listen_key));
@@ -1,9 +1,11 @@
 use std::collections::BTreeMap;
 
 use chrono::Utc;
+use rust_decimal::Decimal;
 use serde_json::Value;
 
 use super::errors::*;
+pub use crate::binance::util::WeightLimiter;
 
 pub type HashMap<K, V> = hashbrown::HashMap<K, V, fxhash::FxBuildHasher>;
 pub type HashSet<V> = hashbrown::HashSet<V, fxhash::FxBuildHasher>;
@@ -87,6 +89,10 @@ pub fn to_f64(v: &Value) -> f64 {
     v.as_str().unwrap().parse().unwrap()
 }
 
+pub fn to_decimal(v: &Value) -> Decimal {
+    v.as_str().unwrap().parse().unwrap()
+}
+
 pub fn get_timestamp() -> Result<u64> {
     Ok(Utc::now().timestamp_millis() as u64)
 }
@@ -1,21 +1,27 @@
 use super::config::*;
 use super::errors::*;
+use super::rest_model::KlineInterval;
+pub use crate::binance::websockets::StreamKind;
 
 use awc::ws::Message;
-// use log::debug;
+use bytes::Bytes;
+use log::debug;
+use std::future::pending;
 // use log::info;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use actix_codec::Framed;
 use awc::{
     ws::{Codec, Frame},
     BoxedSocket, Client, ClientResponse,
 };
-// use bytes::Bytes;
 use futures_util::{sink::SinkExt as _, stream::StreamExt as _};
 use serde_json::from_slice;
 use tokio::sync::mpsc;
+use tokio::time::{interval_at, sleep_until, Instant, MissedTickBehavior};
 
+pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
 
 pub fn all_ticker_stream() -> &'static str {
@@ -34,7 +40,7 @@ pub fn trade_stream(symbol: &str) -> String {
     format!("{}@trade", symbol)
 }
 
-pub fn kline_stream(symbol: &str, interval: &str) -> String {
+pub fn kline_stream(symbol: &str, interval: KlineInterval) -> String {
     format!("{}@kline_{}", symbol, interval)
 }
 
@@ -54,6 +60,11 @@ pub fn mini_ticker_stream(symbol: &str) -> String {
     format!("{}@miniTicker", symbol)
 }
 
+/// Composite index symbols only (ex: defiusdt)
+pub fn composite_index_stream(symbol: &str) -> String {
+    format!("{}@compositeIndex", symbol)
+}
+
 /// # Arguments
 ///
 /// * `symbol`: the market symbol
@@ -79,6 +90,8 @@ pub struct FuturesWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug>
     pub socket: Option<(ClientResponse, Framed<BoxedSocket, Codec>)>,
     sender: mpsc::Sender<WE>,
     conf: Config,
+    idle_timeout: Option<Duration>,
+    last_message: Instant,
 }
 
 impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
@@ -95,8 +108,10 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
     pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> FuturesWebSockets<WE> {
         FuturesWebSockets {
             socket: None,
-            sender: sender,
+            sender,
             conf,
+            idle_timeout: None,
+            last_message: Instant::now(),
         }
     }
 
@@ -106,7 +121,21 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
             "{}/{}/{}",
             self.conf.futures_ws_endpoint, WS_ENDPOINT, endpoint
         );
+        self.handshake(wss, endpoint).await
+    }
+
+    /// Connect to several streams over one connection,
+    /// events are received wrapped in a `CombinedStreamEvent`
+    pub async fn connect_multiple_streams(&mut self, endpoints: &[String]) -> Result<()> {
+        let streams = endpoints.join("/");
+        let wss: String = format!(
+            "{}/{}?streams={}",
+            self.conf.futures_ws_endpoint, STREAM_ENDPOINT, streams
+        );
+        self.handshake(wss, &streams).await
+    }
 
+    async fn handshake(&mut self, wss: String, endpoint: &str) -> Result<()> {
         let client = Client::builder()
             .max_http_version(awc::http::Version::HTTP_11)
             .finish();
@@ -114,6 +143,8 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
         match client.ws(wss).connect().await {
             Ok(answer) => {
                 self.socket = Some(answer);
+                self.idle_timeout = StreamKind::idle_timeout(endpoint, &self.conf.ws_idle_timeouts);
+                self.last_message = Instant::now();
                 Ok(())
             }
             Err(e) => Err(Error::Msg(format!("Error during handshake {}", e))),
@@ -134,41 +165,81 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
         &self.socket
     }
 
+    /// Override the idle timeout picked from the stream type on `connect`
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) {
+        self.idle_timeout = idle_timeout;
+    }
+
+    /// Time elapsed since the last data message was received from the server
+    pub fn idle_time(&self) -> Duration {
+        self.last_message.elapsed()
+    }
+
+    /// Forward every message to the sender until `running` is cleared.
+    /// Sends a ping frame every `ws_ping_interval` and fails with `Error::Stale`
+    /// when no message arrives within the idle timeout of the stream.
     pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        let ping_interval = self.conf.ws_ping_interval;
+        let mut ping = ping_interval.map(|period| {
+            let mut ping = interval_at(Instant::now() + period, period);
+            ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ping
+        });
+
         while running.load(Ordering::Relaxed) {
             if let Some((_, ref mut socket)) = self.socket {
-                let message = socket.next().await;
-                match message {
-                    Some(message) => {
-                        let message = message?;
-                        // let local_timestamp = std::time::SystemTime::now()
-                        //     .duration_since(std::time::UNIX_EPOCH)
-                        //     .expect("Time went backwards")
-                        //     .as_millis() as u64;
-
-                        // info!("event_loop message - {} {:?}", local_timestamp, message);
+                let stale_at = self.idle_timeout.map(|timeout| self.last_message + timeout);
+
+                tokio::select! {
+                    message = socket.next() => {
                         match message {
-                            Frame::Text(msg) => {
-                                if msg.is_empty() {
-                                    return Ok(());
-                                }
-                                let event: WE = from_slice(&msg)?;
-                                if let Err(e) = self.sender.send(event).await {
-                                    return Err(Error::Msg(format!("{:?}", e)));
+                            Some(message) => {
+                                let message = message?;
+                                debug!("event_loop message - {:?}", message);
+                                match message {
+                                    Frame::Text(msg) => {
+                                        if msg.is_empty() {
+                                            return Ok(());
+                                        }
+                                        self.last_message = Instant::now();
+                                        let event: WE = from_slice(&msg)?;
+
+                                        if let Err(e) = self.sender.send(event).await {
+                                            return Err(Error::Msg(format!("{:?}", e)));
+                                        }
+                                    }
+                                    Frame::Ping(msg) => {
+                                        debug!("swap ping msg: {:?}", msg);
+                                        socket.send(Message::Pong(msg)).await?;
+                                    }
+                                    Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {}
+                                    Frame::Close(e) => {
+                                        return Err(Error::Msg(format!("Disconnected {:?}", e)));
+                                    }
                                 }
                             }
-                            Frame::Ping(msg) => {
-                                println!("swap ping msg: {:?}", msg);
-                                socket.send(Message::Pong(msg)).await?;
-                            }
-                            Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {}
-                            Frame::Close(e) => {
-                                return Err(Error::Msg(format!("Disconnected {:?}", e)));
+                            None => {
+                                return Err(Error::Msg(
+                                    "Option::unwrap()` on a `None` value.".to_string(),
+                                ))
                             }
                         }
                     }
-                    None => {
-                        return Err(Error::Msg(format!("Option::unwrap()` on a `None` value.")))
+                    _ = async {
+                        match ping.as_mut() {
+                            Some(ping) => ping.tick().await,
+                            None => pending().await,
+                        }
+                    } => {
+                        socket.send(Message::Ping(Bytes::new())).await?;
+                    }
+                    _ = async {
+                        match stale_at {
+                            Some(deadline) => sleep_until(deadline).await,
+                            None => pending().await,
+                        }
+                    } => {
+                        return Err(Error::Stale(self.last_message.elapsed()));
                     }
                 }
             }
@@ -176,3 +247,28 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::websockets::test::silent_server;
+
+    #[actix_rt::test]
+    async fn silent_stream_is_pinged_then_stale() {
+        let (endpoint, mut pings) = silent_server().await;
+        let conf = Config::default()
+            .set_futures_ws_endpoint(endpoint)
+            .set_ws_ping_interval(Some(Duration::from_secs(10)));
+        let (sender, _receiver) = mpsc::channel::<serde_json::Value>(16);
+        let mut web_socket = FuturesWebSockets::new_with_options(sender, conf);
+        web_socket.connect("btcusdt@aggTrade").await.unwrap();
+        web_socket.set_idle_timeout(Some(Duration::from_secs(25)));
+
+        tokio::time::pause();
+        let result = web_socket.event_loop(&AtomicBool::new(true)).await;
+        assert!(matches!(result, Err(Error::Stale(_))), "{:?}", result);
+        pings.recv().await.unwrap();
+        pings.recv().await.unwrap();
+        assert!(pings.try_recv().is_err());
+    }
+}
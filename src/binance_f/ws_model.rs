@@ -1,6 +1,25 @@
-use super::rest_model::{string_or_float, Asks, Bids};
+use super::rest_model::{string_or_float, string_or_float_opt, Asks, Bids, KlineInterval};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CombinedStreamEvent<T> {
+    stream: String,
+    pub data: T,
+}
+
+impl<T> CombinedStreamEvent<T> {
+    /// Returns (stream_name, channel)
+    pub fn parse_stream(&self) -> (String, String) {
+        let mut parsed = self.stream.clone();
+        if let Some(0) = parsed.find('!') {
+            parsed.remove(0);
+        }
+        let split = parsed.split_once('@').unwrap_or((&parsed, ""));
+        (split.0.to_string(), split.1.to_string())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "e")]
 pub enum FuturesWebsocketEvent {
@@ -31,9 +50,11 @@ pub enum FuturesWebsocketEvent {
     #[serde(alias = "depthUpdate")]
     DepthOrderBook(Box<DepthOrderBookEvent>),
 
+    #[serde(alias = "compositeIndex")]
+    CompositeIndex(Box<CompositeIndexEvent>),
+
     // todo nav
     // todo nav_kline
-    // todo composite_index
     // todo margin_call
     #[serde(alias = "ACCOUNT_UPDATE")]
     AccountUpdate(Box<AccountUpdateEvent>),
@@ -51,22 +72,27 @@ pub struct Order {
     pub order_list_id: i64,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
-    pub orig_qty: String,
-    pub executed_qty: String,
-    pub cummulative_quote_qty: String,
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Decimal,
     pub status: String,
     pub time_in_force: String,
     #[serde(rename = "type")]
     pub type_name: String,
     pub side: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
-    pub iceberg_qty: String,
+    pub stop_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub iceberg_qty: Decimal,
     pub time: u64,
     pub update_time: u64,
     pub is_working: bool,
-    pub orig_quote_order_qty: String,
+    #[serde(with = "string_or_float")]
+    pub orig_quote_order_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -88,13 +114,13 @@ pub struct UserDataStream {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Decimal,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -153,11 +179,11 @@ pub struct Balance {
     #[serde(rename = "a")]
     pub asset: String,
     #[serde(rename = "wb", with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Decimal,
     #[serde(rename = "cw", with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Decimal,
     #[serde(rename = "bc", with = "string_or_float")]
-    pub balances_change: f64,
+    pub balances_change: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -166,17 +192,17 @@ pub struct Position {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "pa", with = "string_or_float")]
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     #[serde(rename = "ep", with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     #[serde(rename = "cr", with = "string_or_float")]
-    pub accumulated_realized: f64,
+    pub accumulated_realized: Decimal,
     #[serde(rename = "up", with = "string_or_float")]
-    pub unrealized_pnl: f64,
+    pub unrealized_pnl: Decimal,
     #[serde(rename = "mt")]
     pub margin_type: String,
     #[serde(rename = "iw", with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Decimal,
     #[serde(rename = "ps")]
     pub position_side: String,
     #[serde(skip, rename = "ma")]
@@ -206,13 +232,13 @@ pub struct OrderTradeUpdate {
     #[serde(rename = "f")]
     pub time_in_force: String,
     #[serde(rename = "q", with = "string_or_float")]
-    pub original_quantity: f64,
+    pub original_quantity: Decimal,
     #[serde(rename = "p", with = "string_or_float")]
-    pub original_price: f64,
+    pub original_price: Decimal,
     #[serde(rename = "ap", with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Decimal,
     #[serde(rename = "sp", with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Decimal,
     #[serde(rename = "x")]
     pub execution_type: String,
     #[serde(rename = "X")]
@@ -220,23 +246,23 @@ pub struct OrderTradeUpdate {
     #[serde(rename = "i")]
     pub order_id: u64,
     #[serde(rename = "l", with = "string_or_float")]
-    pub order_last_filled_quantity: f64,
+    pub order_last_filled_quantity: Decimal,
     #[serde(rename = "z", with = "string_or_float")]
-    pub order_filled_accumulated_quantity: f64,
+    pub order_filled_accumulated_quantity: Decimal,
     #[serde(rename = "L", with = "string_or_float")]
-    pub last_filled_price: f64,
+    pub last_filled_price: Decimal,
     #[serde(rename = "N")]
     pub commission_asset: Option<String>,
     #[serde(skip, rename = "n", with = "string_or_float_opt")]
-    pub commission: Option<f64>,
+    pub commission: Option<Decimal>,
     #[serde(rename = "T")]
     pub order_trade_time: u64,
     #[serde(rename = "t")]
     pub trade_id: u64,
     #[serde(rename = "b", with = "string_or_float")]
-    pub bid_notinal: f64,
+    pub bid_notinal: Decimal,
     #[serde(rename = "a", with = "string_or_float")]
-    pub ask_notinal: f64,
+    pub ask_notinal: Decimal,
     #[serde(rename = "m")]
     pub is_maker: bool,
     #[serde(rename = "R")]
@@ -250,18 +276,18 @@ pub struct OrderTradeUpdate {
     #[serde(skip, rename = "cp")]
     pub close_all_post_condition_order: Option<bool>,
     #[serde(skip, rename = "AP", with = "string_or_float_opt")]
-    pub activation_price: Option<f64>,
+    pub activation_price: Option<Decimal>,
     #[serde(skip, rename = "cr", with = "string_or_float_opt")]
-    pub callback_rate: Option<f64>,
+    pub callback_rate: Option<Decimal>,
     #[serde(rename = "rp", with = "string_or_float")]
-    pub realized_profit: f64,
+    pub realized_profit: Decimal,
     // undoced
     #[serde(rename = "pP")]
     pub p_p: bool,
     #[serde(with = "string_or_float")]
-    pub si: f64,
+    pub si: Decimal,
     #[serde(with = "string_or_float")]
-    pub ss: f64,
+    pub ss: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -273,10 +299,10 @@ pub struct AggrTradesEvent {
     pub symbol: String,
     #[serde(rename = "a")]
     pub aggregated_trade_id: u64,
-    #[serde(rename = "p")]
-    pub price: String,
-    #[serde(rename = "q")]
-    pub qty: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Decimal,
     #[serde(rename = "f")]
     pub first_break_trade_id: u64,
     #[serde(rename = "l")]
@@ -296,20 +322,65 @@ pub struct AggrTradesEvent {
 pub struct MarkPriceEvent {
     #[serde(rename = "E")]
     pub event_time: u64,
-    #[serde(rename = "P")]
-    pub estimate_settle_price: String,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub estimate_settle_price: Decimal,
     #[serde(rename = "T")]
     pub next_funding_time: u64,
-    #[serde(rename = "i")]
-    pub index_price: Option<String>,
-    #[serde(rename = "p")]
-    pub mark_price: String,
-    #[serde(rename = "r")]
-    pub funding_rate: String,
+    #[serde(default, rename = "i", with = "string_or_float_opt")]
+    pub index_price: Option<Decimal>,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub mark_price: Decimal,
+    /// None for delivery contracts, which have no funding
+    #[serde(default, rename = "r", with = "string_or_float_opt")]
+    pub funding_rate: Option<Decimal>,
     #[serde(rename = "s")]
     pub symbol: String,
 }
 
+// https://binance-docs.github.io/apidocs/futures/en/#composite-index-symbol-information-streams
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CompositeIndexEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(rename = "C")]
+    pub component: String,
+    #[serde(rename = "c")]
+    pub composition: Vec<IndexComponent>,
+}
+
+impl CompositeIndexEvent {
+    /// Index value from the component prices of the event
+    pub fn compute(&self) -> Decimal {
+        self.composition
+            .iter()
+            .map(|component| component.weight_in_quantity * component.index_price)
+            .sum()
+    }
+
+    /// Published price minus the recomputed value
+    pub fn tracking_error(&self) -> Decimal {
+        self.price - self.compute()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexComponent {
+    #[serde(rename = "b")]
+    pub base_asset: String,
+    #[serde(rename = "q")]
+    pub quote_asset: String,
+    #[serde(rename = "w", with = "string_or_float")]
+    pub weight_in_quantity: Decimal,
+    #[serde(rename = "W", with = "string_or_float")]
+    pub weight_in_percentage: Decimal,
+    #[serde(rename = "i", with = "string_or_float")]
+    pub index_price: Decimal,
+}
+
 // Object({"E": Number(1626118018407), "e": String("forceOrder"), "o": Object({"S": String("SELL"), "T": Number(1626118018404), "X": String("FILLED"), "ap": String("33028.07"), "f": String("IOC"), "l": String("0.010"), "o": String("LIMIT"), "p": String("32896.00"), "q": String("0.010"), "s": String("BTCUSDT"), "z": String("0.010")})})
 // https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -332,18 +403,18 @@ pub struct LiquidationOrder {
     pub order_type: String,
     #[serde(rename = "f")]
     pub time_in_force: String,
-    #[serde(rename = "q")]
-    pub original_quantity: String,
-    #[serde(rename = "p")]
-    pub price: String,
-    #[serde(rename = "ap")]
-    pub average_price: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub original_quantity: Decimal,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(rename = "ap", with = "string_or_float")]
+    pub average_price: Decimal,
     #[serde(rename = "X")]
     pub order_status: String,
-    #[serde(rename = "l")]
-    pub order_last_filled_quantity: String,
-    #[serde(rename = "z")]
-    pub order_filled_accumulated_quantity: String,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub order_last_filled_quantity: Decimal,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub order_filled_accumulated_quantity: Decimal,
     #[serde(rename = "T")]
     pub order_trade_time: u64,
 }
@@ -360,13 +431,13 @@ pub struct BookTickerEvent {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "b", with = "string_or_float")]
-    pub best_bid: f64,
+    pub best_bid: Decimal,
     #[serde(rename = "B", with = "string_or_float")]
-    pub best_bid_qty: f64,
+    pub best_bid_qty: Decimal,
     #[serde(rename = "a", with = "string_or_float")]
-    pub best_ask: f64,
+    pub best_ask: Decimal,
     #[serde(rename = "A", with = "string_or_float")]
-    pub best_ask_qty: f64,
+    pub best_ask_qty: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -376,26 +447,26 @@ pub struct DayTickerEvent {
     pub event_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "p")]
-    pub price_change: String,
-    #[serde(rename = "P")]
-    pub price_change_percent: String,
-    #[serde(rename = "w")]
-    pub average_price: String,
-    #[serde(rename = "c")]
-    pub current_close: String,
-    #[serde(rename = "Q")]
-    pub current_close_qty: String,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "w", with = "string_or_float")]
+    pub average_price: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub current_close: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub current_close_qty: Decimal,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
     #[serde(rename = "O")]
     pub open_time: u64,
     #[serde(rename = "C")]
@@ -415,18 +486,18 @@ pub struct MiniTickerEvent {
     pub event_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "c")]
-    pub close: String,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Decimal,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -464,31 +535,31 @@ pub struct Kline {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "i")]
-    pub interval: String,
+    pub interval: KlineInterval,
     #[serde(rename = "f")]
     pub first_trade_id: i32,
     #[serde(rename = "L")]
     pub last_trade_id: i32,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "c")]
-    pub close: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
     #[serde(rename = "n")]
     pub number_of_trades: i32,
     #[serde(rename = "x")]
     pub is_final_bar: bool,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
-    #[serde(rename = "V")]
-    pub active_buy_volume: String,
-    #[serde(rename = "Q")]
-    pub active_volume_buy_quote: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub active_buy_volume: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub active_volume_buy_quote: Decimal,
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
 }
@@ -501,31 +572,31 @@ pub struct ContinuousKline {
     #[serde(rename = "T")]
     pub end_time: i64,
     #[serde(rename = "i")]
-    pub interval: String,
+    pub interval: KlineInterval,
     #[serde(rename = "f")]
     pub first_trade_id: i64,
     #[serde(rename = "L")]
     pub last_trade_id: i64,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "c")]
-    pub close: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: Decimal,
     #[serde(rename = "n")]
     pub number_of_trades: i64,
     #[serde(rename = "x")]
     pub is_final_bar: bool,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
-    #[serde(rename = "V")]
-    pub active_buy_volume: String,
-    #[serde(rename = "Q")]
-    pub active_volume_buy_quote: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: Decimal,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub active_buy_volume: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub active_volume_buy_quote: Decimal,
     #[serde(skip, rename = "B")]
     pub ignore_me: String,
 }
@@ -535,6 +606,8 @@ pub struct ContinuousKline {
 pub struct DepthOrderBookEvent {
     #[serde(rename = "E")]
     pub event_time: u64,
+    #[serde(rename = "T", default)]
+    pub transaction_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "U")]
@@ -548,3 +621,24 @@ pub struct DepthOrderBookEvent {
     #[serde(rename = "a")]
     pub asks: Vec<Asks>,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn composite_index_event_recomputes_price() {
+        let event: FuturesWebsocketEvent = serde_json::from_str(
+            r#"{"e":"compositeIndex","E":1602310596000,"s":"DEFIUSDT","p":"60.0","C":"baseAsset",
+            "c":[{"b":"BAL","q":"USDT","w":"2.0","W":"0.5","i":"15.0"},
+                 {"b":"BAND","q":"USDT","w":"3.0","W":"0.5","i":"10.0"}]}"#,
+        )
+        .unwrap();
+        let FuturesWebsocketEvent::CompositeIndex(event) = event else {
+            panic!("not a composite index event: {:?}", event);
+        };
+        assert_eq!(event.compute(), dec!(60));
+        assert_eq!(event.tracking_error(), dec!(0));
+    }
+}
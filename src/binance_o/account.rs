@@ -0,0 +1,206 @@
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::Client;
+use rust_decimal::Decimal;
+
+static EAPI_ORDER: &str = "/eapi/v1/order";
+static EAPI_OPEN_ORDERS: &str = "/eapi/v1/openOrders";
+static EAPI_ALL_OPEN_ORDERS: &str = "/eapi/v1/allOpenOrders";
+static EAPI_ALL_OPEN_ORDERS_BY_UNDERLYING: &str = "/eapi/v1/allOpenOrdersByUnderlying";
+static EAPI_POSITION: &str = "/eapi/v1/position";
+
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// An option order, build it with `OptionOrder::limit` and the setters
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionOrder {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OptionOrderType,
+    pub quantity: Decimal,
+    pub price: Option<Decimal>,
+    pub time_in_force: Option<OptionTimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub client_order_id: Option<String>,
+    pub is_mmp: Option<bool>,
+}
+
+impl OptionOrder {
+    /// A GTC limit order of `quantity` contracts
+    pub fn limit<S>(symbol: S, side: OrderSide, quantity: Decimal, price: Decimal) -> Self
+    where
+        S: Into<String>,
+    {
+        OptionOrder {
+            symbol: symbol.into(),
+            side,
+            order_type: OptionOrderType::Limit,
+            quantity,
+            price: Some(price),
+            time_in_force: Some(OptionTimeInForce::Gtc),
+            reduce_only: None,
+            post_only: None,
+            client_order_id: None,
+            is_mmp: None,
+        }
+    }
+
+    pub fn set_time_in_force(mut self, time_in_force: OptionTimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn set_reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    pub fn set_post_only(mut self, post_only: bool) -> Self {
+        self.post_only = Some(post_only);
+        self
+    }
+
+    pub fn set_client_order_id<S: Into<String>>(mut self, client_order_id: S) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Subject the order to market maker protection
+    pub fn set_mmp(mut self, is_mmp: bool) -> Self {
+        self.is_mmp = Some(is_mmp);
+        self
+    }
+}
+
+/// Either `order_id` or `client_order_id` must be set
+#[derive(Default, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionOrderCancellation {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub client_order_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SymbolQuery {
+    symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UnderlyingQuery {
+    underlying: String,
+}
+
+impl OptionsAccount {
+    /// Place an option order
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_o::{api::*, account::*, config::*, rest_model::OrderSide};
+    /// use rust_decimal_macros::dec;
+    /// let account: OptionsAccount = BinanceO::new_with_env(&Config::default());
+    /// let order = OptionOrder::limit("BTC-240628-60000-C", OrderSide::Buy, dec!(0.1), dec!(5)).set_post_only(true);
+    /// let placed = tokio_test::block_on(account.place_order(order));
+    /// ```
+    pub async fn place_order(&self, order: OptionOrder) -> Result<Order> {
+        self.client
+            .post_signed_p(EAPI_ORDER, order, self.recv_window)
+            .await
+    }
+
+    pub async fn cancel_order(&self, cancellation: OptionOrderCancellation) -> Result<Order> {
+        self.client
+            .delete_signed_p(EAPI_ORDER, cancellation, self.recv_window)
+            .await
+    }
+
+    /// Cancel every open order of an option
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p::<Empty, _>(
+                EAPI_ALL_OPEN_ORDERS,
+                PairQuery {
+                    symbol: symbol.into(),
+                },
+                self.recv_window,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Cancel every open order on the options of an underlying (ex: BTCUSDT)
+    pub async fn cancel_all_open_orders_by_underlying<S>(&self, underlying: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p::<Empty, _>(
+                EAPI_ALL_OPEN_ORDERS_BY_UNDERLYING,
+                UnderlyingQuery {
+                    underlying: underlying.into(),
+                },
+                self.recv_window,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Open orders, of all options when `symbol` is None
+    pub async fn get_all_open_orders<S>(&self, symbol: Option<S>) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                EAPI_OPEN_ORDERS,
+                Some(SymbolQuery {
+                    symbol: symbol.map(Into::into),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Positions, of all options when `symbol` is None
+    pub async fn position_information<S>(&self, symbol: Option<S>) -> Result<Vec<Position>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                EAPI_POSITION,
+                Some(SymbolQuery {
+                    symbol: symbol.map(Into::into),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn limit_order_sends_exact_decimals() {
+        let order = OptionOrder::limit("BTC-240628-60000-C", OrderSide::Sell, dec!(0.1), dec!(0.3))
+            .set_post_only(true);
+        assert_eq!(
+            serde_qs::to_string(&order).unwrap(),
+            "symbol=BTC-240628-60000-C&side=SELL&type=LIMIT&quantity=0.1&price=0.3&timeInForce=GTC&postOnly=true"
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::binance_f::config::{Config as FuturesConfig, IdleTimeouts};
+use std::time::Duration;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+
+    pub recv_window: u64,
+    /// Interval at which the websocket client sends its own ping frames
+    pub ws_ping_interval: Option<Duration>,
+    /// How long a stream may go without a message before it is considered stale
+    pub ws_idle_timeouts: IdleTimeouts,
+}
+
+impl Default for Config {
+    /// Configure binance with default production endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_o::config::Config;
+    /// let config = Config::default();
+    /// ```
+    fn default() -> Config {
+        Config {
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://nbstream.binance.com/eoptions".into(),
+
+            recv_window: 5000,
+            ws_ping_interval: Some(Duration::from_secs(30)),
+            ws_idle_timeouts: IdleTimeouts::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Configure binance with all testnet endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_o::config::Config;
+    /// let config = Config::testnet();
+    /// ```
+    pub fn testnet() -> Config {
+        Config::default()
+            .set_options_rest_api_endpoint("https://testnet.binanceops.com")
+            .set_options_ws_endpoint("wss://testnetws.binanceops.com")
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Set the client ping interval, `None` only answers server pings
+    pub fn set_ws_ping_interval(mut self, ws_ping_interval: Option<Duration>) -> Self {
+        self.ws_ping_interval = ws_ping_interval;
+        self
+    }
+
+    pub fn set_ws_idle_timeouts(mut self, ws_idle_timeouts: IdleTimeouts) -> Self {
+        self.ws_idle_timeouts = ws_idle_timeouts;
+        self
+    }
+
+    /// Websocket settings in the shape expected by `FuturesWebSockets`
+    pub(crate) fn ws_config(&self) -> FuturesConfig {
+        FuturesConfig::default()
+            .set_futures_ws_endpoint(self.options_ws_endpoint.clone())
+            .set_recv_window(self.recv_window)
+            .set_ws_ping_interval(self.ws_ping_interval)
+            .set_ws_idle_timeouts(self.ws_idle_timeouts.clone())
+    }
+}
@@ -0,0 +1,42 @@
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+#[derive(Clone)]
+pub struct OptionsGeneral {
+    pub client: Client,
+}
+
+impl OptionsGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client.get("/eapi/v1/ping", "").await?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get_p("/eapi/v1/time", "").await
+    }
+
+    // Obtain exchange information
+    // - Underlyings, and the strike, expiry and trading rules of every option
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get_p("/eapi/v1/exchangeInfo", "").await
+    }
+
+    // Get option information, `symbol` such as BTC-240628-60000-C
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<OptionSymbolInfo>
+    where
+        S: Into<String>,
+    {
+        let symbol_string = symbol.into();
+        let upper_symbol = symbol_string.to_uppercase();
+
+        let info = self.exchange_info().await?;
+        info.option_symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+            .ok_or(Error::UnknownSymbol(symbol_string))
+    }
+}
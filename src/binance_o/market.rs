@@ -0,0 +1,153 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Recent Trades List`
+- [x] `Recent Block Trades List`
+- [x] `Option Mark Price`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Symbol Price Ticker`
+- [x] `Open Interest`
+*/
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[derive(Debug, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SymbolQuery {
+    symbol: Option<String>,
+    limit: Option<u16>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UnderlyingQuery {
+    underlying: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OpenInterestQuery {
+    underlying_asset: String,
+    expiration: String,
+}
+
+impl OptionsMarket {
+    /// Order book of an option: 10, 20, 50, 100, 500 or 1000 levels
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_o::{api::*, market::*, config::*};
+    /// let market: OptionsMarket = BinanceO::new_with_env(&Config::default());
+    /// let book = tokio_test::block_on(market.get_depth("BTC-240628-60000-C", 10));
+    /// assert!(book.is_ok(), "{:?}", book);
+    /// ```
+    pub async fn get_depth<S>(&self, symbol: S, limit: u16) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let query = SymbolQuery {
+            symbol: Some(symbol.into()),
+            limit: Some(limit),
+        };
+        self.client.get_d("/eapi/v1/depth", Some(query)).await
+    }
+
+    /// Recent trades of an option, up to 500
+    pub async fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let query = SymbolQuery {
+            symbol: Some(symbol.into()),
+            limit: limit.into(),
+        };
+        self.client.get_d("/eapi/v1/trades", Some(query)).await
+    }
+
+    /// Recent block trades, of all options when `symbol` is None
+    pub async fn get_block_trades<S1, S2>(
+        &self,
+        symbol: Option<S1>,
+        limit: S2,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let query = SymbolQuery {
+            symbol: symbol.map(Into::into),
+            limit: limit.into(),
+        };
+        self.client.get_d("/eapi/v1/blockTrades", Some(query)).await
+    }
+
+    /// Mark price, implied volatility and greeks, of all options when `symbol` is None
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_o::{api::*, market::*, config::*};
+    /// let market: OptionsMarket = BinanceO::new_with_env(&Config::default());
+    /// let marks = tokio_test::block_on(market.get_mark_price(None::<String>));
+    /// assert!(marks.is_ok(), "{:?}", marks);
+    /// ```
+    pub async fn get_mark_price<S>(&self, symbol: Option<S>) -> Result<Vec<MarkPrice>>
+    where
+        S: Into<String>,
+    {
+        let query = SymbolQuery {
+            symbol: symbol.map(Into::into),
+            limit: None,
+        };
+        self.client.get_d("/eapi/v1/mark", Some(query)).await
+    }
+
+    /// 24hr ticker price change statistics, of all options when `symbol` is None
+    pub async fn get_ticker<S>(&self, symbol: Option<S>) -> Result<Vec<Ticker>>
+    where
+        S: Into<String>,
+    {
+        let query = SymbolQuery {
+            symbol: symbol.map(Into::into),
+            limit: None,
+        };
+        self.client.get_d("/eapi/v1/ticker", Some(query)).await
+    }
+
+    /// Spot index price of an underlying (ex: BTCUSDT)
+    pub async fn get_index_price<S>(&self, underlying: S) -> Result<IndexPrice>
+    where
+        S: Into<String>,
+    {
+        let query = UnderlyingQuery {
+            underlying: underlying.into(),
+        };
+        self.client.get_d("/eapi/v1/index", Some(query)).await
+    }
+
+    /// Open interest of every option of `underlying_asset` (ex: BTC) expiring on `expiration` (ex: 240628),
+    /// see `OptionSymbol::expiration`
+    pub async fn get_open_interest<S1, S2>(
+        &self,
+        underlying_asset: S1,
+        expiration: S2,
+    ) -> Result<Vec<OpenInterest>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let query = OpenInterestQuery {
+            underlying_asset: underlying_asset.into(),
+            expiration: expiration.into(),
+        };
+        self.client
+            .get_d("/eapi/v1/openInterest", Some(query))
+            .await
+    }
+}
@@ -0,0 +1,22 @@
+/*!
+European options, `eapi.binance.com` and `nbstream.binance.com/eoptions`
+
+Options are identified by symbols such as BTC-240628-60000-C, see
+`rest_model::OptionSymbol` to get the underlying, expiry, strike and type out of them.
+Prices and premiums are in the quote asset (USDT).
+
+The REST client, errors and websocket event loop are those of `binance_f`.
+*/
+
+pub use crate::binance_f::errors;
+
+pub mod rest_model;
+pub mod ws_model;
+
+pub mod account;
+pub mod api;
+pub mod config;
+pub mod general;
+pub mod market;
+pub mod userstream;
+pub mod websockets;
@@ -0,0 +1,386 @@
+use super::errors::Error;
+use crate::binance_f::rest_model::{string_or_float, string_or_float_opt};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+
+pub use crate::binance_f::rest_model::{
+    Asks, Bids, Empty, Filters, OrderSide, PairQuery, RateLimit, ServerTime, Success,
+    UserDataStream,
+};
+
+/// Options settle at 08:00 UTC on their expiry date
+const EXPIRY_HOUR: u32 = 8;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbolInfo>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+/// An underlying with listed options
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    /// Index of the options (ex: BTCUSDT)
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbolInfo {
+    pub symbol: String,
+    pub side: OptionKind,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Decimal,
+    pub underlying: String,
+    /// Quantity of the underlying per contract
+    pub unit: u32,
+    #[serde(with = "string_or_float")]
+    pub maker_fee: Decimal,
+    #[serde(with = "string_or_float")]
+    pub taker_fee: Decimal,
+    #[serde(with = "string_or_float")]
+    pub min_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Decimal,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: Decimal,
+    #[serde(with = "string_or_float")]
+    pub min_initial_margin: Decimal,
+    #[serde(with = "string_or_float")]
+    pub min_maintenance_margin: Decimal,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub quote_asset: String,
+    /// Expiry time in ms
+    pub expiry_date: u64,
+    pub filters: Vec<Filters>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OptionKind {
+    Call,
+    Put,
+}
+
+/// The parts of an option symbol such as BTC-240628-60000-C
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionSymbol {
+    /// Base asset of the underlying (ex: BTC)
+    pub underlying: String,
+    pub expiry: NaiveDate,
+    pub strike: Decimal,
+    pub kind: OptionKind,
+}
+
+impl OptionSymbol {
+    /// Expiry time in ms
+    pub fn expiry_time(&self) -> i64 {
+        self.expiry
+            .and_hms_opt(EXPIRY_HOUR, 0, 0)
+            .expect("valid expiry hour")
+            .and_utc()
+            .timestamp_millis()
+    }
+
+    /// Expiry as written in symbols and in the open interest query (ex: 240628)
+    pub fn expiration(&self) -> String {
+        self.expiry.format("%y%m%d").to_string()
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidOptionSymbol(s.to_string());
+        let mut parts = s.split('-');
+        let (Some(underlying), Some(expiry), Some(strike), Some(kind), None) = (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) else {
+            return Err(invalid());
+        };
+        let kind = match kind {
+            "C" => OptionKind::Call,
+            "P" => OptionKind::Put,
+            _ => return Err(invalid()),
+        };
+        if underlying.is_empty() {
+            return Err(invalid());
+        }
+        Ok(OptionSymbol {
+            underlying: underlying.to_string(),
+            expiry: NaiveDate::parse_from_str(expiry, "%y%m%d").map_err(|_| invalid())?,
+            strike: strike.parse().map_err(|_| invalid())?,
+            kind,
+        })
+    }
+}
+
+impl fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            OptionKind::Call => "C",
+            OptionKind::Put => "P",
+        };
+        write!(
+            f,
+            "{}-{}-{}-{}",
+            self.underlying,
+            self.expiration(),
+            self.strike,
+            kind
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+/// A recent or block trade
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub trade_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: Decimal,
+    /// 1 when the taker bought, -1 when the taker sold
+    pub side: i8,
+    pub time: u64,
+}
+
+/// Mark price and greeks of an option
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Decimal,
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: Decimal,
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: Decimal,
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: Decimal,
+    #[serde(with = "string_or_float")]
+    pub delta: Decimal,
+    #[serde(with = "string_or_float")]
+    pub theta: Decimal,
+    #[serde(with = "string_or_float")]
+    pub gamma: Decimal,
+    #[serde(with = "string_or_float")]
+    pub vega: Decimal,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: Decimal,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: Decimal,
+    #[serde(default, with = "string_or_float_opt")]
+    pub risk_free_interest: Option<Decimal>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(with = "string_or_float")]
+    pub last_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(with = "string_or_float")]
+    pub amount: Decimal,
+    #[serde(with = "string_or_float")]
+    pub bid_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub ask_price: Decimal,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_trade_id: u64,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Decimal,
+    /// Underlying index price at expiry, estimated before
+    #[serde(with = "string_or_float")]
+    pub exercise_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    /// Contracts
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: Decimal,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_usd: Decimal,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionOrderType {
+    Limit,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionTimeInForce {
+    Gtc,
+    Ioc,
+    Fok,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub quantity: Decimal,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub fee: Decimal,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OptionOrderType,
+    pub time_in_force: OptionTimeInForce,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Decimal,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub option_side: OptionKind,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    /// LONG or SHORT
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub quantity: Decimal,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: Decimal,
+    #[serde(with = "string_or_float")]
+    pub mark_value: Decimal,
+    #[serde(with = "string_or_float")]
+    pub ror: Decimal,
+    #[serde(with = "string_or_float")]
+    pub unrealized_p_n_l: Decimal,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Decimal,
+    #[serde(with = "string_or_float")]
+    pub position_cost: Decimal,
+    pub expiry_date: u64,
+    pub price_scale: u32,
+    pub quantity_scale: u32,
+    pub option_side: OptionKind,
+    pub quote_asset: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn option_symbol_round_trip() {
+        let symbol: OptionSymbol = "BTC-240628-60000-C".parse().unwrap();
+        assert_eq!(symbol.underlying, "BTC");
+        assert_eq!(symbol.expiry, NaiveDate::from_ymd_opt(2024, 6, 28).unwrap());
+        assert_eq!(symbol.strike, dec!(60000));
+        assert_eq!(symbol.kind, OptionKind::Call);
+        assert_eq!(symbol.expiry_time(), 1_719_561_600_000);
+        assert_eq!(symbol.to_string(), "BTC-240628-60000-C");
+
+        let symbol: OptionSymbol = "DOGE-240628-0.15-P".parse().unwrap();
+        assert_eq!(symbol.strike, dec!(0.15));
+        assert_eq!(symbol.to_string(), "DOGE-240628-0.15-P");
+
+        for invalid in [
+            "BTC-240628-60000",
+            "BTC-241328-60000-C",
+            "BTC-240628-60000-X",
+            "-240628-1-C",
+        ] {
+            assert!(matches!(
+                invalid.parse::<OptionSymbol>(),
+                Err(Error::InvalidOptionSymbol(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::config::Config;
+use super::errors::*;
+use crate::binance_f::websockets::FuturesWebSockets;
+
+use actix_codec::Framed;
+use awc::ws::Codec;
+use awc::{BoxedSocket, ClientResponse};
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+pub use crate::binance_f::websockets::StreamKind;
+
+/// Trades of an option (ex: BTC-240628-60000-C), or of every option of an underlying (ex: BTC)
+pub fn trade_stream(symbol: &str) -> String {
+    format!("{}@trade", symbol)
+}
+
+/// 24hr statistics and greeks of an option
+pub fn ticker_stream(symbol: &str) -> String {
+    format!("{}@ticker", symbol)
+}
+
+/// 24hr statistics of every option of an underlying (ex: BTC) expiring on `expiration` (ex: 240628)
+pub fn expiration_ticker_stream(underlying_asset: &str, expiration: &str) -> String {
+    format!("{}@ticker@{}", underlying_asset, expiration)
+}
+
+/// Mark price of every option of an underlying (ex: BTCUSDT)
+pub fn mark_price_stream(underlying: &str) -> String {
+    format!("{}@markPrice", underlying)
+}
+
+/// Spot index price of an underlying (ex: BTCUSDT)
+pub fn index_stream(underlying: &str) -> String {
+    format!("{}@index", underlying)
+}
+
+/// Open interest of every option of an underlying (ex: BTC) expiring on `expiration` (ex: 240628)
+pub fn open_interest_stream(underlying_asset: &str, expiration: &str) -> String {
+    format!("{}@openInterest@{}", underlying_asset, expiration)
+}
+
+/// # Arguments
+///
+/// * `symbol`: the option symbol
+/// * `levels`: 10, 20, 50 or 100
+/// * `update_speed`: 100 or 1000
+pub fn depth_stream(symbol: &str, levels: u16, update_speed: u16) -> String {
+    format!("{}@depth{}@{}ms", symbol, levels, update_speed)
+}
+
+/// Websockets of `nbstream.binance.com/eoptions`, the event loop is the one of `FuturesWebSockets`
+pub struct OptionsWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
+    inner: FuturesWebSockets<WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> OptionsWebSockets<WE> {
+    /// New websocket holder with default configuration
+    pub fn new(sender: mpsc::Sender<WE>) -> OptionsWebSockets<WE> {
+        Self::new_with_options(sender, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> OptionsWebSockets<WE> {
+        OptionsWebSockets {
+            inner: FuturesWebSockets::new_with_options(sender, conf.ws_config()),
+        }
+    }
+
+    /// Connect to a websocket endpoint
+    pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
+        self.inner.connect(endpoint).await
+    }
+
+    /// Connect to several streams over one connection,
+    /// events are received wrapped in a `CombinedStreamEvent`
+    pub async fn connect_multiple_streams(&mut self, endpoints: &[String]) -> Result<()> {
+        self.inner.connect_multiple_streams(endpoints).await
+    }
+
+    /// Disconnect from the endpoint
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.inner.disconnect().await
+    }
+
+    pub fn socket(&self) -> &Option<(ClientResponse, Framed<BoxedSocket, Codec>)> {
+        self.inner.socket()
+    }
+
+    /// Override the idle timeout picked from the stream type on `connect`
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) {
+        self.inner.set_idle_timeout(idle_timeout);
+    }
+
+    /// Time elapsed since the last data message was received from the server
+    pub fn idle_time(&self) -> Duration {
+        self.inner.idle_time()
+    }
+
+    /// Forward every message to the sender until `running` is cleared,
+    /// see `FuturesWebSockets::event_loop`
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        self.inner.event_loop(running).await
+    }
+}
@@ -0,0 +1,230 @@
+use super::rest_model::{Asks, Bids};
+use crate::binance_f::rest_model::string_or_float;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+pub use crate::binance_f::ws_model::CombinedStreamEvent;
+
+/// Option streams push single events, except markPrice and openInterest
+/// which push every option of an underlying at once
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionsWebsocketEvent {
+    Batch(Vec<OptionsEvent>),
+    Single(OptionsEvent),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum OptionsEvent {
+    #[serde(alias = "trade")]
+    Trade(Box<TradeEvent>),
+
+    #[serde(alias = "24hrTicker")]
+    Ticker(Box<TickerEvent>),
+
+    #[serde(alias = "markPrice")]
+    MarkPrice(Box<MarkPriceEvent>),
+
+    #[serde(alias = "depth")]
+    Depth(Box<DepthEvent>),
+
+    #[serde(alias = "index")]
+    Index(Box<IndexEvent>),
+
+    #[serde(alias = "openInterest")]
+    OpenInterest(Box<OpenInterestEvent>),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t", with = "string_or_int")]
+    pub trade_id: i64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Decimal,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: Decimal,
+    #[serde(rename = "b")]
+    pub buy_order_id: u64,
+    #[serde(rename = "a")]
+    pub sell_order_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    /// 1 when the taker bought, -1 when the taker sold
+    #[serde(rename = "S", with = "string_or_int")]
+    pub side: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: Decimal,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: Decimal,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: Decimal,
+    #[serde(rename = "V", with = "string_or_float")]
+    pub volume: Decimal,
+    #[serde(rename = "A", with = "string_or_float")]
+    pub amount: Decimal,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: Decimal,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub last_qty: Decimal,
+    #[serde(rename = "n")]
+    pub trade_count: u64,
+    #[serde(rename = "bo", with = "string_or_float")]
+    pub best_bid: Decimal,
+    #[serde(rename = "ao", with = "string_or_float")]
+    pub best_ask: Decimal,
+    #[serde(rename = "bq", with = "string_or_float")]
+    pub best_bid_qty: Decimal,
+    #[serde(rename = "aq", with = "string_or_float")]
+    pub best_ask_qty: Decimal,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_iv: Decimal,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_iv: Decimal,
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: Decimal,
+    #[serde(rename = "t", with = "string_or_float")]
+    pub theta: Decimal,
+    #[serde(rename = "g", with = "string_or_float")]
+    pub gamma: Decimal,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub vega: Decimal,
+    #[serde(rename = "vo", with = "string_or_float")]
+    pub mark_iv: Decimal,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: Decimal,
+    #[serde(rename = "eep", with = "string_or_float")]
+    pub estimated_exercise_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarkPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DepthEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "pu", default)]
+    pub previous_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<Bids>,
+    #[serde(rename = "a")]
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Underlying (ex: BTCUSDT)
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub index_price: Decimal,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OpenInterestEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Contracts
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open_interest: Decimal,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub open_interest_usd: Decimal,
+}
+
+pub(crate) mod string_or_int {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<i64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrInt {
+            String(String),
+            Int(i64),
+        }
+
+        match StringOrInt::deserialize(deserializer)? {
+            StringOrInt::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrInt::Int(i) => Ok(i),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn parses_single_and_batch_events() {
+        let event: OptionsWebsocketEvent = serde_json::from_str(
+            r#"{"e":"trade","E":1591677941092,"s":"BTC-200630-9000-P","t":"315","p":"4.0",
+            "q":"-0.9","b":4611780118,"a":4611780119,"T":1591677567872,"S":"-1","X":"TRADE"}"#,
+        )
+        .unwrap();
+        let OptionsWebsocketEvent::Single(OptionsEvent::Trade(trade)) = event else {
+            panic!("not a trade: {:?}", event);
+        };
+        assert_eq!(trade.trade_id, 315);
+        assert_eq!(trade.side, -1);
+
+        let event: OptionsWebsocketEvent = serde_json::from_str(
+            r#"[{"e":"markPrice","E":1663684594227,"s":"ETH-220930-1500-C","mp":"30.3"},
+            {"e":"markPrice","E":1663684594228,"s":"ETH-220930-1500-P","mp":"2.1"}]"#,
+        )
+        .unwrap();
+        let OptionsWebsocketEvent::Batch(marks) = event else {
+            panic!("not a batch: {:?}", event);
+        };
+        assert_eq!(marks.len(), 2);
+        assert!(matches!(&marks[1], OptionsEvent::MarkPrice(mark) if mark.mark_price == dec!(2.1)));
+    }
+}
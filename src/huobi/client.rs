@@ -1,15 +1,15 @@
 use base64;
+use hmac_sha256::HMAC;
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
 use reqwest::Response;
 use reqwest::StatusCode;
-use hmac_sha256::HMAC;
 use serde::de;
 use serde_json::from_str;
 use std::time::Duration;
 
 use crate::huobi::errors::error_messages;
 use crate::huobi::errors::*;
-use crate::huobi::util::{build_request_p};
+use crate::huobi::util::build_request_p;
 use serde::de::DeserializeOwned;
 
 #[derive(Clone)]
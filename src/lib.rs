@@ -5,6 +5,10 @@ extern crate serde;
 extern crate serde_qs as qs;
 
 pub mod binance;
+pub mod binance_d;
 pub mod binance_f;
-// pub mod huobi;
-// pub mod okex_v5;
+pub mod binance_o;
+#[cfg(feature = "huobi")]
+pub mod huobi;
+#[cfg(feature = "okex_v5")]
+pub mod okex_v5;
@@ -1,4 +1,3 @@
-
 use super::client::Client;
 use super::errors::*;
 use super::rest_model::*;
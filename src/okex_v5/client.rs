@@ -1,11 +1,11 @@
 use std::time::Duration;
 
 use chrono::prelude::*;
+use hmac_sha256::HMAC;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
 use reqwest::Method;
 use reqwest::Response;
 use reqwest::StatusCode;
-use hmac_sha256::HMAC;
 use serde::de;
 use serde::de::DeserializeOwned;
 use serde_json::from_str;
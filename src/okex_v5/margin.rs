@@ -1,4 +1,3 @@
-
 use super::client::*;
 
 #[derive(Clone)]
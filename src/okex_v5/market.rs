@@ -1,7 +1,5 @@
-
 use super::client::*;
 
-
 #[derive(Clone)]
 pub struct Market {
     pub client: Client,
@@ -1,7 +1,5 @@
-
 use super::client::*;
 
-
 static USER_DATA_STREAM: &str = "/api/v3/userDataStream";
 
 #[derive(Clone)]